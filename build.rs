@@ -0,0 +1,53 @@
+//! Build script: packs the fallback monospace face and the shader library that ship inside the binary.
+
+use std::path::{Path, PathBuf};
+
+/// Font embedded by `nerdfont::quic` when no Nerd Font can be loaded
+const EMBEDDED_FONT: &str = "assets/fonts/FiraMono-Medium.ttf";
+
+/// Shader library embedded by `shader_library` as the last search location
+const SHADER_DIR: &str = "wgsl";
+
+fn main() {
+    println!("cargo:rerun-if-changed={EMBEDDED_FONT}");
+    println!("cargo:rerun-if-changed={SHADER_DIR}");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let out_dir = PathBuf::from(std::env::var_os("OUT_DIR").expect("OUT_DIR"));
+
+    #[cfg(feature = "zstd")]
+    {
+        let font = std::fs::read(EMBEDDED_FONT).expect("read embedded font");
+        let packed = zstd::encode_all(&font[..], 19).expect("compress embedded font");
+        std::fs::write(out_dir.join("embedded_mono.zst"), packed).expect("write embedded font");
+    }
+
+    let mut shaders = Vec::new();
+    collect_shaders(Path::new(SHADER_DIR), &mut shaders);
+    shaders.sort();
+
+    let mut table = String::from("&[\n");
+    for path in &shaders {
+        let name = path.strip_prefix(SHADER_DIR).unwrap().to_string_lossy().replace('\\', "/");
+        table.push_str(&format!(
+            "    ({name:?}, include_str!(concat!(env!(\"CARGO_MANIFEST_DIR\"), {:?}))),\n",
+            format!("/{SHADER_DIR}/{name}")
+        ));
+    }
+    table.push_str("]\n");
+    std::fs::write(out_dir.join("embedded_shaders.rs"), table).expect("write embedded shader table");
+}
+
+/// Every `.wgsl` and `.wgsl.json` file below `dir`
+fn collect_shaders(dir: &Path, shaders: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = path.to_string_lossy();
+        if path.is_dir() {
+            collect_shaders(&path, shaders);
+        } else if name.ends_with(".wgsl") || name.ends_with(".wgsl.json") {
+            shaders.push(path);
+        }
+    }
+}
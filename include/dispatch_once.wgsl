@@ -1,200 +0,0 @@
-#storage canvas array<f32>
-
-fn grad(z: vec2<i32>) -> vec2<f32> {
-    var n = z.x + z.y * 11111;
-    n = (n << 13u)^n;
-    n = (n * (n * n * 15731 + 789221) + 1376312589) >> 16u;
-    return vec2<f32>(cos(float(n)), sin(float(n)));
-}
-
-fn noise(p: vec2<f32>) -> f32 {
-    let i = vec2<i32>(p);
-    let f = fract(p);
-	let u = f * f * (3.0 - 2.0 * f);
-    return mix( mix(dot(grad(i + vec2<i32>(0,0)), f - vec2(0.0,0.0)),
-                    dot(grad(i + vec2<i32>(1,0)), f - vec2(1.0,0.0)), u.x),
-                mix(dot(grad(i + vec2<i32>(0,1)), f - vec2(0.0,1.0)),
-                    dot(grad(i + vec2<i32>(1,1)), f - vec2(1.0,1.0)), u.x), u.y);
-}
-
-fn sRGBtoLinear(c: f32) -> f32 {
-    return mix(c / 12.92, pow((c + 0.055) / (1.055), 2.4), step(0.04045, c));
-}
-
-fn lenna(fc: vec2<f32>, res: vec2<f32>) -> f32 {
-	let v_0_0 = 2.0 * fc.x / res.x - 1.0;
-	let v_0_1 = 2.0 * fc.y / res.y - 1.0;
-	let v_1_0 = sin(-15.749390 * v_0_0 + 2.467443 * v_0_1 + 0.642972);
-	let v_1_1 = sin(-8.764448 * v_0_0 + 8.972171 * v_0_1 - 0.246504);
-	let v_1_2 = sin(2.079661 * v_0_0 - 0.565238 * v_0_1 + 0.016377);
-	let v_1_3 = sin(-4.528710 * v_0_0 + 11.748545 * v_0_1 + 0.074486);
-	let v_1_4 = sin(9.896638 * v_0_0 + 7.860395 * v_0_1 + 0.926652);
-	let v_1_5 = sin(-10.150239 * v_0_0 + 10.332283 * v_0_1 + 0.449958);
-	let v_1_6 = sin(6.384732 * v_0_0 + 0.263559 * v_0_1 - 0.339646);
-	let v_1_7 = sin(-5.242582 * v_0_0 - 14.390223 * v_0_1 - 0.593600);
-	let v_1_8 = sin(-12.397568 * v_0_0 - 4.610657 * v_0_1 - 0.252255);
-	let v_1_9 = sin(-10.366361 * v_0_0 - 11.147682 * v_0_1 + 0.190910);
-	let v_1_10 = sin(14.244532 * v_0_0 - 1.578685 * v_0_1 - 0.219945);
-	let v_1_11 = sin(-12.053640 * v_0_0 - 13.701488 * v_0_1 - 0.669969);
-	let v_1_12 = sin(-14.218564 * v_0_0 - 3.153181 * v_0_1 - 0.301633);
-	let v_1_13 = sin(0.828735 * v_0_0 + 1.137885 * v_0_1 + 0.147247);
-	let v_1_14 = sin(3.314677 * v_0_0 + 2.571989 * v_0_1 - 0.257351);
-	let v_1_15 = sin(-9.440269 * v_0_0 + 5.666999 * v_0_1 + 0.469530);
-	let v_1_16 = sin(-1.699901 * v_0_0 - 4.921369 * v_0_1 + 0.061349);
-	let v_1_17 = sin(-13.737357 * v_0_0 + 3.157089 * v_0_1 + 0.167369);
-	let v_1_18 = sin(8.368551 * v_0_0 + 8.838763 * v_0_1 + 0.330037);
-	let v_1_19 = sin(0.916331 * v_0_0 - 6.649802 * v_0_1 + 0.646310);
-	let v_1_20 = sin(13.024671 * v_0_0 + 5.573820 * v_0_1 + 0.071979);
-	let v_1_21 = sin(13.883341 * v_0_0 + 12.520011 * v_0_1 - 0.498547);
-	let v_1_22 = sin(0.552199 * v_0_0 - 11.150436 * v_0_1 - 0.345762);
-	let v_1_23 = sin(-1.491720 * v_0_0 - 7.268486 * v_0_1 - 0.108306);
-	let v_1_24 = sin(11.672986 * v_0_0 - 8.112849 * v_0_1 + 0.179875);
-	let v_1_25 = sin(7.334301 * v_0_0 + 10.062238 * v_0_1 - 0.175000);
-	let v_1_26 = sin(15.619444 * v_0_0 + 14.586774 * v_0_1 + 0.397141);
-	let v_1_27 = sin(4.485784 * v_0_0 - 3.369560 * v_0_1 - 0.194340);
-	let v_1_28 = sin(-7.059183 * v_0_0 - 6.053284 * v_0_1 + 0.179697);
-	let v_1_29 = sin(10.597829 * v_0_0 - 13.960537 * v_0_1 - 0.103465);
-	let v_1_30 = sin(-4.128477 * v_0_0 - 11.817846 * v_0_1 - 0.054170);
-	let v_1_31 = sin(6.165703 * v_0_0 - 13.486117 * v_0_1 + 0.186833);
-	let v_1_32 = sin(-14.692471 * v_0_0 + 12.268010 * v_0_1 + 0.292376);
-	let v_1_33 = sin(-6.251513 * v_0_0 - 6.756928 * v_0_1 - 0.037965);
-	let v_1_34 = sin(3.518636 * v_0_0 + 5.374871 * v_0_1 + 0.262203);
-	let v_1_35 = sin(10.684721 * v_0_0 + 6.133939 * v_0_1 - 0.081342);
-	let v_1_36 = sin(-0.602145 * v_0_0 - 9.013171 * v_0_1 + 0.278911);
-	let v_1_37 = sin(7.346097 * v_0_0 - 1.136418 * v_0_1 + 0.604018);
-	let v_1_38 = sin(-2.373563 * v_0_0 + 13.776805 * v_0_1 - 0.056559);
-	let v_1_39 = sin(7.269861 * v_0_0 - 10.788553 * v_0_1 - 0.034897);
-	let v_1_40 = sin(3.086473 * v_0_0 - 3.390917 * v_0_1 + 0.614137);
-	let v_1_41 = sin(7.541430 * v_0_0 + 2.775830 * v_0_1 - 0.482546);
-	let v_1_42 = sin(2.191930 * v_0_0 - 3.989583 * v_0_1 - 0.454749);
-	let v_1_43 = sin(-11.042057 * v_0_0 - 8.130956 * v_0_1 + 0.448241);
-	let v_1_44 = sin(-2.719099 * v_0_0 + 9.383417 * v_0_1 + 0.015181);
-	let v_1_45 = sin(1.175184 * v_0_0 + 14.192170 * v_0_1 - 0.171184);
-	let v_1_46 = sin(7.084381 * v_0_0 - 4.544268 * v_0_1 - 0.649499);
-	let v_1_47 = sin(-10.298572 * v_0_0 + 4.544588 * v_0_1 - 0.705458);
-	let v_2_0 = sin(-0.065482 * v_1_0 - 0.485541 * v_1_1 - 0.282011 * v_1_2 - 0.033789 * v_1_3 - 0.104469 * v_1_4 + 0.355055 * v_1_5 - 0.108360 * v_1_6 + 0.139673 * v_1_7 - 0.061030 * v_1_8 - 0.367185 * v_1_9 + 0.289661 * v_1_10 + 0.081991 * v_1_11 + 1.089167 * v_1_12 + 1.364067 * v_1_13 - 0.083250 * v_1_14 + 0.922163 * v_1_15 + 0.343875 * v_1_16 - 0.085565 * v_1_17 - 0.468821 * v_1_18 + 0.275525 * v_1_19 + 0.270840 * v_1_20 + 0.158315 * v_1_21 - 0.037194 * v_1_22 - 0.128225 * v_1_23 - 0.408752 * v_1_24 - 0.129172 * v_1_25 + 0.066759 * v_1_26 + 0.375691 * v_1_27 + 0.219501 * v_1_28 - 0.029511 * v_1_29 - 0.038434 * v_1_30 - 0.057808 * v_1_31 + 0.196591 * v_1_32 - 0.053747 * v_1_33 - 0.090249 * v_1_34 + 0.034427 * v_1_35 - 0.252116 * v_1_36 + 0.929282 * v_1_37 - 0.118457 * v_1_38 + 0.251970 * v_1_39 + 0.215242 * v_1_40 + 0.019846 * v_1_41 + 0.236909 * v_1_42 - 0.190732 * v_1_43 + 0.025062 * v_1_44 - 0.234045 * v_1_45 + 0.047061 * v_1_46 - 0.314656 * v_1_47 + 0.133179);
-	let v_2_1 = sin(-0.243781 * v_1_0 - 0.485275 * v_1_1 + 0.030238 * v_1_2 + 0.481586 * v_1_3 - 0.388494 * v_1_4 + 0.711691 * v_1_5 - 0.808955 * v_1_6 - 0.102940 * v_1_7 - 0.625539 * v_1_8 - 0.005834 * v_1_9 + 0.051530 * v_1_10 + 0.129800 * v_1_11 - 1.313116 * v_1_12 + 0.424066 * v_1_13 - 0.091396 * v_1_14 - 0.139008 * v_1_15 - 0.029123 * v_1_16 + 1.065114 * v_1_17 + 0.261688 * v_1_18 - 0.070311 * v_1_19 - 0.278773 * v_1_20 - 0.303570 * v_1_21 + 0.016473 * v_1_22 - 0.927586 * v_1_23 - 0.581223 * v_1_24 - 0.284578 * v_1_25 - 0.045697 * v_1_26 + 0.206580 * v_1_27 - 0.038907 * v_1_28 - 0.634797 * v_1_29 + 0.005725 * v_1_30 + 0.002758 * v_1_31 + 0.643740 * v_1_32 - 0.567981 * v_1_33 + 0.213010 * v_1_34 + 0.574765 * v_1_35 - 0.345832 * v_1_36 - 0.705120 * v_1_37 - 0.605076 * v_1_38 - 0.637799 * v_1_39 + 0.136577 * v_1_40 + 0.496751 * v_1_41 + 1.047688 * v_1_42 - 0.144254 * v_1_43 + 0.790090 * v_1_44 - 0.024628 * v_1_45 + 0.214587 * v_1_46 + 0.424814 * v_1_47 + 0.053577);
-	let v_2_2 = sin(0.261282 * v_1_0 - 0.040384 * v_1_1 + 0.756232 * v_1_2 - 0.233554 * v_1_3 + 0.138241 * v_1_4 - 0.212729 * v_1_5 + 0.013198 * v_1_6 + 0.252980 * v_1_7 - 0.217172 * v_1_8 - 0.035718 * v_1_9 - 0.871267 * v_1_10 - 0.101065 * v_1_11 + 0.043691 * v_1_12 + 0.509285 * v_1_13 + 0.388802 * v_1_14 + 0.136715 * v_1_15 + 0.126780 * v_1_16 - 0.322012 * v_1_17 - 0.329437 * v_1_18 - 0.228591 * v_1_19 - 0.287443 * v_1_20 + 0.202141 * v_1_21 - 0.187505 * v_1_22 + 0.341014 * v_1_23 - 0.521987 * v_1_24 - 0.065612 * v_1_25 - 0.086088 * v_1_26 - 0.237491 * v_1_27 + 0.348716 * v_1_28 - 0.272090 * v_1_29 + 0.097524 * v_1_30 + 0.021353 * v_1_31 - 0.275169 * v_1_32 + 0.329974 * v_1_33 + 0.088159 * v_1_34 + 0.141247 * v_1_35 + 0.171108 * v_1_36 + 0.313542 * v_1_37 + 0.166780 * v_1_38 + 0.618090 * v_1_39 + 0.019327 * v_1_40 + 0.317309 * v_1_41 + 0.589071 * v_1_42 + 0.262586 * v_1_43 - 0.302786 * v_1_44 - 0.086138 * v_1_45 - 0.182733 * v_1_46 - 0.038340 * v_1_47 + 0.029004);
-	let v_2_3 = sin(0.523252 * v_1_0 + 0.107384 * v_1_1 + 0.327781 * v_1_2 - 0.076413 * v_1_3 + 0.440611 * v_1_4 - 0.785551 * v_1_5 + 0.574956 * v_1_6 + 0.219944 * v_1_7 - 0.240581 * v_1_8 + 0.286866 * v_1_9 - 1.483904 * v_1_10 + 0.135453 * v_1_11 + 0.801191 * v_1_12 + 0.314990 * v_1_13 + 0.400898 * v_1_14 - 0.085023 * v_1_15 + 0.003784 * v_1_16 - 1.142911 * v_1_17 - 0.389492 * v_1_18 + 0.228838 * v_1_19 - 0.546678 * v_1_20 + 0.045068 * v_1_21 + 0.029305 * v_1_22 - 0.358098 * v_1_23 - 0.380731 * v_1_24 + 0.197180 * v_1_25 - 0.507613 * v_1_26 - 0.429272 * v_1_27 - 0.678066 * v_1_28 + 0.073406 * v_1_29 - 0.120085 * v_1_30 + 0.099233 * v_1_31 + 1.168502 * v_1_32 - 0.078105 * v_1_33 - 0.461475 * v_1_34 - 0.368327 * v_1_35 + 0.574204 * v_1_36 - 0.569624 * v_1_37 + 0.194489 * v_1_38 + 0.645453 * v_1_39 + 0.288659 * v_1_40 - 0.552237 * v_1_41 + 0.988756 * v_1_42 - 0.715570 * v_1_43 - 0.479663 * v_1_44 - 0.219703 * v_1_45 + 0.222237 * v_1_46 - 0.701252 * v_1_47 + 0.013962);
-	let v_2_4 = sin(1.009539 * v_1_0 - 0.890739 * v_1_1 + 0.130483 * v_1_2 + 0.392527 * v_1_3 + 0.926352 * v_1_4 - 0.781537 * v_1_5 + 0.082838 * v_1_6 + 0.205554 * v_1_7 + 0.170102 * v_1_8 + 0.236811 * v_1_9 + 0.050157 * v_1_10 - 0.306443 * v_1_11 - 0.383757 * v_1_12 + 0.230556 * v_1_13 - 0.469003 * v_1_14 - 0.107211 * v_1_15 + 0.497177 * v_1_16 - 0.109261 * v_1_17 + 0.527491 * v_1_18 + 0.020104 * v_1_19 - 0.323089 * v_1_20 - 0.243583 * v_1_21 + 0.393569 * v_1_22 + 0.585943 * v_1_23 + 0.411503 * v_1_24 - 0.094103 * v_1_25 + 0.201898 * v_1_26 + 0.115527 * v_1_27 - 0.258112 * v_1_28 - 0.356221 * v_1_29 - 0.009961 * v_1_30 + 0.309046 * v_1_31 + 0.722463 * v_1_32 - 0.432392 * v_1_33 + 0.028823 * v_1_34 - 0.203498 * v_1_35 - 0.291320 * v_1_36 - 0.091262 * v_1_37 - 0.312483 * v_1_38 - 0.561505 * v_1_39 + 0.760351 * v_1_40 - 0.013419 * v_1_41 + 0.740596 * v_1_42 + 0.034939 * v_1_43 + 0.263984 * v_1_44 - 0.495019 * v_1_45 - 0.343489 * v_1_46 - 0.516831 * v_1_47 + 0.347113);
-	let v_2_5 = sin(-0.420654 * v_1_0 + 0.159252 * v_1_1 - 0.404101 * v_1_2 - 0.031743 * v_1_3 - 0.371783 * v_1_4 + 0.112538 * v_1_5 - 0.204502 * v_1_6 + 0.086386 * v_1_7 - 0.462020 * v_1_8 - 0.030599 * v_1_9 - 0.591690 * v_1_10 - 0.033343 * v_1_11 + 0.000614 * v_1_12 + 0.503653 * v_1_13 + 0.250502 * v_1_14 + 0.111668 * v_1_15 + 1.183839 * v_1_16 - 0.011763 * v_1_17 + 0.056834 * v_1_18 - 0.689230 * v_1_19 - 0.152907 * v_1_20 - 0.008814 * v_1_21 - 0.183631 * v_1_22 - 0.786173 * v_1_23 - 0.632439 * v_1_24 + 0.056131 * v_1_25 + 0.311545 * v_1_26 - 0.110195 * v_1_27 - 0.006212 * v_1_28 - 0.227251 * v_1_29 - 0.075126 * v_1_30 + 0.004017 * v_1_31 - 0.593923 * v_1_32 + 0.114781 * v_1_33 + 0.445595 * v_1_34 - 0.610677 * v_1_35 - 0.117124 * v_1_36 + 0.237041 * v_1_37 - 0.107714 * v_1_38 + 0.234535 * v_1_39 + 0.009986 * v_1_40 + 0.193452 * v_1_41 - 0.116010 * v_1_42 + 0.216591 * v_1_43 + 0.139846 * v_1_44 - 0.290340 * v_1_45 - 0.257458 * v_1_46 + 0.266675 * v_1_47 - 0.147822);
-	let v_2_6 = sin(0.228909 * v_1_0 - 0.962752 * v_1_1 - 0.232604 * v_1_2 - 0.146776 * v_1_3 - 0.458682 * v_1_4 + 0.345960 * v_1_5 - 0.069072 * v_1_6 + 0.106306 * v_1_7 - 0.150677 * v_1_8 + 0.068976 * v_1_9 - 0.993672 * v_1_10 + 0.174936 * v_1_11 + 0.579028 * v_1_12 - 0.187122 * v_1_13 - 0.100288 * v_1_14 - 0.058413 * v_1_15 + 0.004840 * v_1_16 + 0.466656 * v_1_17 + 0.119247 * v_1_18 - 0.504548 * v_1_19 + 0.097782 * v_1_20 - 0.107179 * v_1_21 - 0.103314 * v_1_22 - 0.332677 * v_1_23 + 0.775045 * v_1_24 - 0.272679 * v_1_25 - 0.118795 * v_1_26 + 0.303363 * v_1_27 - 0.100159 * v_1_28 - 0.210916 * v_1_29 - 0.234506 * v_1_30 - 0.095094 * v_1_31 + 0.093863 * v_1_32 - 0.145171 * v_1_33 + 0.123557 * v_1_34 + 0.154133 * v_1_35 + 0.146986 * v_1_36 + 0.447134 * v_1_37 + 0.230364 * v_1_38 - 0.149987 * v_1_39 + 0.568647 * v_1_40 + 0.372384 * v_1_41 + 0.185303 * v_1_42 - 0.158191 * v_1_43 - 0.450801 * v_1_44 + 0.218564 * v_1_45 - 0.276979 * v_1_46 - 0.864656 * v_1_47 + 0.130528);
-	let v_2_7 = sin(0.021612 * v_1_0 - 0.231572 * v_1_1 - 0.536386 * v_1_2 + 0.009402 * v_1_3 - 0.116061 * v_1_4 + 0.546017 * v_1_5 - 0.527510 * v_1_6 + 0.188509 * v_1_7 - 0.065978 * v_1_8 - 0.032867 * v_1_9 + 0.301749 * v_1_10 + 0.033793 * v_1_11 - 0.087999 * v_1_12 + 0.306703 * v_1_13 - 0.193041 * v_1_14 + 0.061533 * v_1_15 - 0.074861 * v_1_16 - 0.102696 * v_1_17 - 0.006094 * v_1_18 - 0.541364 * v_1_19 - 0.067838 * v_1_20 - 0.003208 * v_1_21 - 0.069382 * v_1_22 + 0.321490 * v_1_23 - 0.256434 * v_1_24 + 0.005616 * v_1_25 + 0.056547 * v_1_26 + 0.388649 * v_1_27 - 0.474492 * v_1_28 + 0.113620 * v_1_29 - 0.209007 * v_1_30 + 0.129837 * v_1_31 + 0.339257 * v_1_32 + 0.205263 * v_1_33 - 0.245178 * v_1_34 + 0.271884 * v_1_35 - 0.306944 * v_1_36 - 1.374015 * v_1_37 - 0.038813 * v_1_38 - 0.092031 * v_1_39 + 0.304206 * v_1_40 + 0.258406 * v_1_41 - 0.019382 * v_1_42 - 0.060594 * v_1_43 - 0.000571 * v_1_44 + 0.083137 * v_1_45 - 0.238484 * v_1_46 + 0.116943 * v_1_47 + 0.431592);
-	let v_2_8 = sin(0.396450 * v_1_0 + 0.537073 * v_1_1 + 0.294232 * v_1_2 - 0.023671 * v_1_3 + 0.510590 * v_1_4 - 0.029868 * v_1_5 + 0.004290 * v_1_6 + 0.107144 * v_1_7 - 0.234365 * v_1_8 + 0.221091 * v_1_9 + 0.941722 * v_1_10 + 0.101638 * v_1_11 - 1.126694 * v_1_12 + 1.167606 * v_1_13 - 0.045161 * v_1_14 + 0.290086 * v_1_15 + 0.225150 * v_1_16 - 0.014848 * v_1_17 + 0.140963 * v_1_18 + 0.287455 * v_1_19 - 0.326119 * v_1_20 - 0.022743 * v_1_21 - 0.233708 * v_1_22 - 0.200597 * v_1_23 - 0.756206 * v_1_24 - 0.197502 * v_1_25 - 0.072886 * v_1_26 + 0.004959 * v_1_27 - 0.190616 * v_1_28 + 0.154404 * v_1_29 - 0.143276 * v_1_30 + 0.061623 * v_1_31 - 0.084775 * v_1_32 + 0.101350 * v_1_33 + 0.400096 * v_1_34 - 0.272893 * v_1_35 + 0.082562 * v_1_36 + 0.352673 * v_1_37 + 0.187678 * v_1_38 + 0.178734 * v_1_39 + 0.171225 * v_1_40 - 0.627018 * v_1_41 + 0.487755 * v_1_42 - 0.513288 * v_1_43 - 0.148841 * v_1_44 - 0.024473 * v_1_45 - 0.443729 * v_1_46 - 0.738749 * v_1_47 - 0.291837);
-	let v_2_9 = sin(0.628888 * v_1_0 + 0.042532 * v_1_1 + 0.451979 * v_1_2 + 0.202601 * v_1_3 + 0.515466 * v_1_4 + 0.043483 * v_1_5 - 0.451942 * v_1_6 - 0.294645 * v_1_7 + 0.284067 * v_1_8 + 0.159547 * v_1_9 - 0.251330 * v_1_10 + 0.545351 * v_1_11 - 0.367912 * v_1_12 + 1.022595 * v_1_13 + 0.102960 * v_1_14 + 0.269959 * v_1_15 + 0.227489 * v_1_16 - 0.828204 * v_1_17 + 0.598228 * v_1_18 + 0.320837 * v_1_19 - 0.147786 * v_1_20 - 0.028352 * v_1_21 - 0.488365 * v_1_22 + 0.581397 * v_1_23 - 0.505583 * v_1_24 - 0.000289 * v_1_25 - 0.107637 * v_1_26 - 0.568084 * v_1_27 - 0.158838 * v_1_28 - 0.471650 * v_1_29 - 0.191957 * v_1_30 - 0.350344 * v_1_31 + 0.113408 * v_1_32 + 0.287771 * v_1_33 + 0.178218 * v_1_34 - 0.040935 * v_1_35 + 0.378300 * v_1_36 + 0.636143 * v_1_37 + 0.187650 * v_1_38 - 0.122078 * v_1_39 - 0.463819 * v_1_40 - 0.587365 * v_1_41 - 0.093414 * v_1_42 + 0.209803 * v_1_43 + 0.024453 * v_1_44 + 0.162616 * v_1_45 - 0.163691 * v_1_46 - 1.050220 * v_1_47 - 0.081950);
-	let v_2_10 = sin(-0.921215 * v_1_0 - 0.486208 * v_1_1 + 0.077377 * v_1_2 + 0.125184 * v_1_3 + 0.265732 * v_1_4 - 0.252463 * v_1_5 - 0.335388 * v_1_6 + 0.243923 * v_1_7 - 0.142093 * v_1_8 + 0.178225 * v_1_9 - 0.254689 * v_1_10 - 0.159924 * v_1_11 - 0.081185 * v_1_12 - 0.876980 * v_1_13 - 0.014954 * v_1_14 - 0.591578 * v_1_15 + 0.287947 * v_1_16 - 0.598271 * v_1_17 - 0.414463 * v_1_18 + 0.103354 * v_1_19 + 0.421875 * v_1_20 + 0.159911 * v_1_21 - 0.088968 * v_1_22 - 0.449890 * v_1_23 + 0.679430 * v_1_24 - 0.104755 * v_1_25 + 0.277422 * v_1_26 - 0.043298 * v_1_27 + 0.987785 * v_1_28 + 0.336177 * v_1_29 + 0.005546 * v_1_30 + 0.105312 * v_1_31 + 0.142711 * v_1_32 + 0.006165 * v_1_33 + 0.174699 * v_1_34 + 0.562172 * v_1_35 + 0.099169 * v_1_36 - 0.413454 * v_1_37 + 0.294608 * v_1_38 + 0.201657 * v_1_39 - 0.034989 * v_1_40 + 0.283553 * v_1_41 - 0.028059 * v_1_42 + 0.625677 * v_1_43 - 0.295963 * v_1_44 - 0.223687 * v_1_45 + 0.335361 * v_1_46 + 0.662039 * v_1_47 + 0.124643);
-	let v_2_11 = sin(-0.792102 * v_1_0 - 0.112863 * v_1_1 - 0.799639 * v_1_2 - 0.670916 * v_1_3 - 0.244496 * v_1_4 + 0.006166 * v_1_5 + 0.121469 * v_1_6 + 0.360932 * v_1_7 - 0.743174 * v_1_8 - 0.160950 * v_1_9 + 0.954147 * v_1_10 + 0.258041 * v_1_11 + 0.410461 * v_1_12 + 0.087804 * v_1_13 + 0.488679 * v_1_14 - 0.442463 * v_1_15 + 0.235732 * v_1_16 + 0.565931 * v_1_17 - 0.516639 * v_1_18 - 0.157687 * v_1_19 - 0.463768 * v_1_20 + 0.114950 * v_1_21 + 0.051910 * v_1_22 - 0.200667 * v_1_23 + 0.120435 * v_1_24 + 0.094475 * v_1_25 + 0.460711 * v_1_26 + 0.297428 * v_1_27 - 0.159573 * v_1_28 + 0.075658 * v_1_29 - 0.377757 * v_1_30 - 0.113816 * v_1_31 + 0.386345 * v_1_32 + 0.155144 * v_1_33 - 0.205374 * v_1_34 - 0.067153 * v_1_35 - 0.006757 * v_1_36 - 0.152862 * v_1_37 + 0.379470 * v_1_38 + 0.055118 * v_1_39 + 0.044956 * v_1_40 + 0.698693 * v_1_41 - 0.360601 * v_1_42 + 0.648954 * v_1_43 - 0.181765 * v_1_44 + 0.052130 * v_1_45 - 0.446568 * v_1_46 - 0.493152 * v_1_47 + 0.228292);
-	let v_2_12 = sin(0.120703 * v_1_0 - 0.163453 * v_1_1 - 0.102261 * v_1_2 + 0.115391 * v_1_3 + 0.044434 * v_1_4 + 0.025282 * v_1_5 - 0.073635 * v_1_6 - 0.283883 * v_1_7 - 0.320301 * v_1_8 - 0.056614 * v_1_9 + 0.032003 * v_1_10 + 0.199567 * v_1_11 + 0.010077 * v_1_12 + 0.789952 * v_1_13 + 0.016258 * v_1_14 - 0.016895 * v_1_15 + 0.097705 * v_1_16 + 1.029623 * v_1_17 + 0.118757 * v_1_18 - 0.090904 * v_1_19 - 0.066853 * v_1_20 + 0.174507 * v_1_21 - 0.429309 * v_1_22 + 0.111212 * v_1_23 + 0.565507 * v_1_24 + 0.055110 * v_1_25 + 0.185681 * v_1_26 - 0.066495 * v_1_27 - 1.028828 * v_1_28 - 0.174441 * v_1_29 + 0.023197 * v_1_30 - 0.245048 * v_1_31 - 0.509236 * v_1_32 + 0.070561 * v_1_33 - 0.362213 * v_1_34 + 0.363135 * v_1_35 + 0.212430 * v_1_36 - 0.092021 * v_1_37 + 0.289035 * v_1_38 - 0.666146 * v_1_39 + 0.481131 * v_1_40 - 0.097973 * v_1_41 + 0.258126 * v_1_42 - 0.369518 * v_1_43 - 0.008344 * v_1_44 + 0.111707 * v_1_45 - 0.319237 * v_1_46 - 0.275398 * v_1_47 + 0.348788);
-	let v_2_13 = sin(-0.955026 * v_1_0 - 0.051588 * v_1_1 - 0.432949 * v_1_2 + 0.026348 * v_1_3 + 0.288373 * v_1_4 - 0.272735 * v_1_5 + 0.265116 * v_1_6 - 0.151294 * v_1_7 - 0.820178 * v_1_8 - 0.224609 * v_1_9 + 0.420041 * v_1_10 - 0.044821 * v_1_11 - 0.371753 * v_1_12 - 0.735677 * v_1_13 + 0.172698 * v_1_14 - 0.407175 * v_1_15 - 0.278941 * v_1_16 + 0.328935 * v_1_17 - 0.093539 * v_1_18 - 0.424080 * v_1_19 - 0.087137 * v_1_20 - 0.231445 * v_1_21 + 0.103437 * v_1_22 + 0.196380 * v_1_23 + 0.508795 * v_1_24 - 0.096379 * v_1_25 + 0.013457 * v_1_26 + 0.694139 * v_1_27 + 0.328259 * v_1_28 + 0.273517 * v_1_29 + 0.476734 * v_1_30 + 0.133802 * v_1_31 - 0.613256 * v_1_32 - 0.065969 * v_1_33 - 0.208927 * v_1_34 + 0.458196 * v_1_35 + 0.169570 * v_1_36 + 0.596721 * v_1_37 + 0.117368 * v_1_38 - 0.085388 * v_1_39 + 0.191379 * v_1_40 + 1.000012 * v_1_41 + 0.213054 * v_1_42 + 0.289329 * v_1_43 + 0.466528 * v_1_44 + 0.178217 * v_1_45 + 0.035715 * v_1_46 - 0.041321 * v_1_47 + 0.098101);
-	let v_2_14 = sin(0.465661 * v_1_0 - 0.490508 * v_1_1 + 0.598659 * v_1_2 - 0.435354 * v_1_3 - 0.289527 * v_1_4 - 0.604629 * v_1_5 - 0.100750 * v_1_6 - 0.269079 * v_1_7 + 0.503227 * v_1_8 + 0.009803 * v_1_9 - 1.446452 * v_1_10 + 0.062504 * v_1_11 - 0.003302 * v_1_12 + 0.077491 * v_1_13 + 0.074025 * v_1_14 + 0.403815 * v_1_15 - 0.252024 * v_1_16 - 0.381423 * v_1_17 - 0.222900 * v_1_18 - 0.287231 * v_1_19 + 0.126465 * v_1_20 - 0.228581 * v_1_21 + 0.180378 * v_1_22 - 0.057970 * v_1_23 - 0.031085 * v_1_24 - 0.138320 * v_1_25 - 0.030868 * v_1_26 + 0.113910 * v_1_27 - 0.310370 * v_1_28 + 0.355517 * v_1_29 - 0.073121 * v_1_30 + 0.354696 * v_1_31 - 0.043630 * v_1_32 + 0.381754 * v_1_33 - 0.193051 * v_1_34 - 0.055826 * v_1_35 - 0.226129 * v_1_36 - 0.493185 * v_1_37 + 0.068057 * v_1_38 + 0.496635 * v_1_39 - 0.232153 * v_1_40 + 0.149254 * v_1_41 + 0.185118 * v_1_42 - 0.786842 * v_1_43 - 0.005589 * v_1_44 + 0.136762 * v_1_45 - 0.761729 * v_1_46 + 0.053566 * v_1_47 + 0.184695);
-	let v_2_15 = sin(0.490389 * v_1_0 + 0.643516 * v_1_1 + 0.727528 * v_1_2 + 0.419497 * v_1_3 + 0.203948 * v_1_4 + 0.595659 * v_1_5 - 0.013993 * v_1_6 - 0.154276 * v_1_7 - 0.269396 * v_1_8 + 0.128579 * v_1_9 - 0.158593 * v_1_10 + 0.341583 * v_1_11 - 0.642159 * v_1_12 - 0.430662 * v_1_13 + 0.490592 * v_1_14 + 0.056263 * v_1_15 + 0.703720 * v_1_16 + 0.166868 * v_1_17 - 0.110995 * v_1_18 + 0.143408 * v_1_19 + 0.200933 * v_1_20 + 0.398712 * v_1_21 + 0.117195 * v_1_22 - 0.381320 * v_1_23 - 0.540924 * v_1_24 + 0.083102 * v_1_25 + 0.217456 * v_1_26 - 0.453737 * v_1_27 + 0.036607 * v_1_28 + 0.210064 * v_1_29 - 0.084107 * v_1_30 + 0.569143 * v_1_31 + 1.064044 * v_1_32 + 0.113958 * v_1_33 + 0.476422 * v_1_34 - 0.205325 * v_1_35 - 0.300572 * v_1_36 + 0.463829 * v_1_37 - 0.299308 * v_1_38 + 0.024790 * v_1_39 + 0.225571 * v_1_40 - 0.466490 * v_1_41 + 0.233098 * v_1_42 + 0.159885 * v_1_43 + 0.228507 * v_1_44 + 0.213786 * v_1_45 - 0.047344 * v_1_46 - 0.476736 * v_1_47 + 0.447607);
-	let v_2_16 = sin(0.226065 * v_1_0 - 0.464152 * v_1_1 - 0.548968 * v_1_2 + 0.183509 * v_1_3 - 0.022675 * v_1_4 + 0.362456 * v_1_5 + 0.048007 * v_1_6 - 0.056509 * v_1_7 - 0.036773 * v_1_8 - 0.332735 * v_1_9 + 0.315884 * v_1_10 + 0.024865 * v_1_11 + 0.072350 * v_1_12 - 0.491434 * v_1_13 + 0.218353 * v_1_14 - 0.378855 * v_1_15 - 0.279496 * v_1_16 - 0.149269 * v_1_17 - 0.302915 * v_1_18 + 0.315690 * v_1_19 + 1.201468 * v_1_20 + 0.235721 * v_1_21 + 0.293040 * v_1_22 + 0.194221 * v_1_23 + 0.605394 * v_1_24 + 0.131936 * v_1_25 + 0.062954 * v_1_26 + 0.173914 * v_1_27 - 0.328001 * v_1_28 - 0.481479 * v_1_29 - 0.107964 * v_1_30 - 0.092091 * v_1_31 + 1.076622 * v_1_32 + 1.109377 * v_1_33 + 0.586683 * v_1_34 + 0.197635 * v_1_35 + 0.129032 * v_1_36 - 0.426267 * v_1_37 + 0.107963 * v_1_38 - 0.411521 * v_1_39 + 0.337163 * v_1_40 - 0.771956 * v_1_41 + 0.290939 * v_1_42 - 0.021533 * v_1_43 - 0.554348 * v_1_44 + 0.035170 * v_1_45 - 0.515000 * v_1_46 - 0.036225 * v_1_47 + 0.470617);
-	let v_2_17 = sin(1.090096 * v_1_0 - 0.581113 * v_1_1 + 0.563149 * v_1_2 + 0.163119 * v_1_3 + 0.455463 * v_1_4 - 0.003035 * v_1_5 - 0.275970 * v_1_6 + 0.161739 * v_1_7 + 0.321289 * v_1_8 - 0.302085 * v_1_9 - 0.416098 * v_1_10 - 0.064625 * v_1_11 + 0.324302 * v_1_12 - 1.471813 * v_1_13 - 0.058245 * v_1_14 - 0.177870 * v_1_15 + 1.178965 * v_1_16 + 0.539031 * v_1_17 - 0.490255 * v_1_18 + 0.104189 * v_1_19 - 0.398464 * v_1_20 + 0.113117 * v_1_21 - 0.158756 * v_1_22 + 0.087700 * v_1_23 + 0.611055 * v_1_24 + 0.637815 * v_1_25 - 0.328814 * v_1_26 - 0.500496 * v_1_27 - 0.763558 * v_1_28 - 0.148692 * v_1_29 + 0.141707 * v_1_30 + 0.250422 * v_1_31 + 0.020762 * v_1_32 - 0.276117 * v_1_33 + 0.191493 * v_1_34 + 0.378450 * v_1_35 + 0.247901 * v_1_36 + 0.311520 * v_1_37 - 0.139010 * v_1_38 - 0.000044 * v_1_39 + 0.120448 * v_1_40 + 0.398374 * v_1_41 + 0.057271 * v_1_42 + 0.308107 * v_1_43 - 0.139959 * v_1_44 + 0.218747 * v_1_45 + 0.436301 * v_1_46 - 1.060995 * v_1_47 + 0.074922);
-	let v_2_18 = sin(0.517105 * v_1_0 - 0.486687 * v_1_1 + 0.170834 * v_1_2 - 0.070206 * v_1_3 + 0.212347 * v_1_4 + 0.084106 * v_1_5 + 0.141521 * v_1_6 - 0.072371 * v_1_7 - 0.323366 * v_1_8 - 0.002015 * v_1_9 - 0.588215 * v_1_10 + 0.045148 * v_1_11 - 0.089888 * v_1_12 + 0.620090 * v_1_13 - 0.109640 * v_1_14 - 0.388047 * v_1_15 - 0.475723 * v_1_16 - 0.223685 * v_1_17 + 0.027230 * v_1_18 - 0.176504 * v_1_19 - 0.449999 * v_1_20 - 0.192002 * v_1_21 + 0.487254 * v_1_22 - 0.237294 * v_1_23 - 0.743998 * v_1_24 + 0.202171 * v_1_25 + 0.248979 * v_1_26 + 0.216326 * v_1_27 + 0.466506 * v_1_28 - 0.229890 * v_1_29 - 0.180534 * v_1_30 - 0.127965 * v_1_31 + 0.000491 * v_1_32 - 0.653314 * v_1_33 + 0.062927 * v_1_34 - 0.500731 * v_1_35 - 0.684068 * v_1_36 + 0.432098 * v_1_37 - 0.330444 * v_1_38 - 0.197587 * v_1_39 + 0.356932 * v_1_40 - 0.450409 * v_1_41 + 0.548736 * v_1_42 - 0.093107 * v_1_43 + 0.074989 * v_1_44 + 0.091096 * v_1_45 - 0.468496 * v_1_46 - 0.860631 * v_1_47 + 0.271375);
-	let v_2_19 = sin(-0.121527 * v_1_0 + 0.006890 * v_1_1 + 0.430714 * v_1_2 + 0.063642 * v_1_3 - 0.162154 * v_1_4 + 0.101171 * v_1_5 - 0.308212 * v_1_6 - 0.151646 * v_1_7 + 0.298833 * v_1_8 + 0.008714 * v_1_9 + 0.126952 * v_1_10 + 0.071216 * v_1_11 + 0.033123 * v_1_12 + 0.975383 * v_1_13 + 0.592540 * v_1_14 + 0.642680 * v_1_15 - 0.247403 * v_1_16 + 0.299115 * v_1_17 - 0.315312 * v_1_18 + 0.601131 * v_1_19 + 0.615430 * v_1_20 + 0.079993 * v_1_21 - 0.047997 * v_1_22 + 0.236585 * v_1_23 - 0.553341 * v_1_24 - 0.215887 * v_1_25 + 0.243024 * v_1_26 - 0.262716 * v_1_27 + 0.194788 * v_1_28 - 0.390725 * v_1_29 + 0.198156 * v_1_30 + 0.041805 * v_1_31 + 0.300592 * v_1_32 - 0.237342 * v_1_33 - 0.466455 * v_1_34 + 0.356856 * v_1_35 - 0.507444 * v_1_36 + 0.248020 * v_1_37 + 0.281680 * v_1_38 + 0.125214 * v_1_39 - 0.440793 * v_1_40 + 0.523755 * v_1_41 - 0.253834 * v_1_42 + 0.146252 * v_1_43 + 0.448543 * v_1_44 + 0.350596 * v_1_45 - 0.048735 * v_1_46 + 0.454477 * v_1_47 - 0.723260);
-	let v_2_20 = sin(0.299482 * v_1_0 - 0.853992 * v_1_1 + 0.150054 * v_1_2 - 0.040608 * v_1_3 - 0.355083 * v_1_4 - 0.462150 * v_1_5 + 0.359507 * v_1_6 + 0.366630 * v_1_7 - 0.783839 * v_1_8 + 0.137833 * v_1_9 + 0.137501 * v_1_10 - 0.356697 * v_1_11 + 0.568220 * v_1_12 + 0.319016 * v_1_13 + 0.393319 * v_1_14 - 0.258951 * v_1_15 + 0.362645 * v_1_16 + 0.695950 * v_1_17 + 0.186544 * v_1_18 - 0.185056 * v_1_19 + 0.061343 * v_1_20 + 0.129717 * v_1_21 - 0.718779 * v_1_22 - 0.814654 * v_1_23 - 0.248687 * v_1_24 + 0.515177 * v_1_25 + 0.446662 * v_1_26 - 0.174804 * v_1_27 + 0.301267 * v_1_28 + 0.694308 * v_1_29 - 0.176498 * v_1_30 - 0.266416 * v_1_31 + 0.180438 * v_1_32 + 0.085197 * v_1_33 - 0.181435 * v_1_34 - 0.148944 * v_1_35 + 0.019852 * v_1_36 - 0.357469 * v_1_37 + 0.282083 * v_1_38 - 0.015793 * v_1_39 - 0.039288 * v_1_40 - 0.168655 * v_1_41 + 0.570005 * v_1_42 + 0.481382 * v_1_43 + 0.817282 * v_1_44 - 0.140358 * v_1_45 + 0.247235 * v_1_46 - 0.121940 * v_1_47 + 0.281060);
-	let v_2_21 = sin(-0.620298 * v_1_0 + 0.412467 * v_1_1 - 0.091209 * v_1_2 - 0.644219 * v_1_3 - 0.272220 * v_1_4 - 0.471176 * v_1_5 - 0.049610 * v_1_6 - 0.195211 * v_1_7 + 0.217563 * v_1_8 - 0.242275 * v_1_9 + 0.326659 * v_1_10 - 0.278128 * v_1_11 + 0.710358 * v_1_12 - 0.689592 * v_1_13 - 0.030801 * v_1_14 + 0.645385 * v_1_15 + 0.088973 * v_1_16 + 0.503382 * v_1_17 - 0.355530 * v_1_18 + 0.015117 * v_1_19 + 0.070629 * v_1_20 + 0.362865 * v_1_21 + 0.309386 * v_1_22 + 0.101212 * v_1_23 + 0.681897 * v_1_24 + 0.532936 * v_1_25 + 0.023473 * v_1_26 + 0.072443 * v_1_27 - 0.150128 * v_1_28 + 0.024546 * v_1_29 - 0.122352 * v_1_30 - 0.283787 * v_1_31 - 0.523425 * v_1_32 - 0.086021 * v_1_33 - 0.351849 * v_1_34 - 0.058355 * v_1_35 - 0.273237 * v_1_36 + 1.562192 * v_1_37 + 0.038809 * v_1_38 + 0.102344 * v_1_39 - 0.041836 * v_1_40 + 0.621281 * v_1_41 + 0.184144 * v_1_42 - 0.093246 * v_1_43 + 0.118714 * v_1_44 + 0.161072 * v_1_45 + 0.294903 * v_1_46 - 0.135885 * v_1_47 + 0.347060);
-	let v_2_22 = sin(0.051335 * v_1_0 - 0.326711 * v_1_1 - 0.223439 * v_1_2 - 0.140766 * v_1_3 + 0.617033 * v_1_4 - 0.007011 * v_1_5 - 0.241739 * v_1_6 - 0.092541 * v_1_7 - 0.125161 * v_1_8 - 0.083994 * v_1_9 + 0.321904 * v_1_10 - 0.147467 * v_1_11 - 0.214837 * v_1_12 - 0.712204 * v_1_13 + 0.014395 * v_1_14 - 0.788289 * v_1_15 + 0.148996 * v_1_16 + 0.914792 * v_1_17 - 0.162338 * v_1_18 + 0.202246 * v_1_19 - 0.120634 * v_1_20 + 0.272041 * v_1_21 + 0.146400 * v_1_22 - 0.116175 * v_1_23 + 0.120516 * v_1_24 + 0.285305 * v_1_25 + 0.320731 * v_1_26 - 0.394528 * v_1_27 - 0.132539 * v_1_28 - 0.141674 * v_1_29 + 0.164027 * v_1_30 - 0.155925 * v_1_31 - 0.291961 * v_1_32 + 0.324748 * v_1_33 - 0.470695 * v_1_34 - 0.178628 * v_1_35 - 0.322031 * v_1_36 - 0.621648 * v_1_37 - 0.442719 * v_1_38 + 0.062315 * v_1_39 + 0.255603 * v_1_40 + 0.290865 * v_1_41 + 0.339261 * v_1_42 + 0.089589 * v_1_43 + 0.210690 * v_1_44 - 0.032373 * v_1_45 + 0.672377 * v_1_46 + 0.112730 * v_1_47 - 0.021447);
-	let v_2_23 = sin(0.538883 * v_1_0 + 0.724938 * v_1_1 + 0.122225 * v_1_2 + 0.000477 * v_1_3 + 0.500296 * v_1_4 - 0.434093 * v_1_5 + 0.321689 * v_1_6 + 0.331012 * v_1_7 + 0.469767 * v_1_8 + 0.131898 * v_1_9 + 0.787867 * v_1_10 + 0.047232 * v_1_11 - 0.684513 * v_1_12 + 0.499856 * v_1_13 + 0.706974 * v_1_14 - 0.079418 * v_1_15 - 0.405981 * v_1_16 + 0.316957 * v_1_17 - 0.425640 * v_1_18 + 0.365335 * v_1_19 - 0.579610 * v_1_20 + 0.125251 * v_1_21 - 0.379771 * v_1_22 + 0.063855 * v_1_23 - 0.643638 * v_1_24 - 0.102211 * v_1_25 - 0.235407 * v_1_26 - 0.409211 * v_1_27 + 0.006760 * v_1_28 - 0.193037 * v_1_29 + 0.255193 * v_1_30 + 0.150068 * v_1_31 + 0.331068 * v_1_32 - 0.152804 * v_1_33 - 0.056761 * v_1_34 + 0.089402 * v_1_35 - 0.072200 * v_1_36 + 0.271572 * v_1_37 - 0.506215 * v_1_38 - 0.085135 * v_1_39 - 0.989326 * v_1_40 - 0.595057 * v_1_41 - 0.071169 * v_1_42 + 0.123257 * v_1_43 + 0.155037 * v_1_44 - 0.411145 * v_1_45 - 0.898655 * v_1_46 - 0.689536 * v_1_47 - 0.665092);
-	let v_2_24 = sin(-0.306966 * v_1_0 - 0.580276 * v_1_1 - 0.576194 * v_1_2 + 0.130195 * v_1_3 - 0.063913 * v_1_4 + 0.369353 * v_1_5 - 0.879054 * v_1_6 + 0.049832 * v_1_7 - 0.144330 * v_1_8 - 0.020873 * v_1_9 + 0.041291 * v_1_10 - 0.106893 * v_1_11 - 0.206894 * v_1_12 - 0.296719 * v_1_13 - 0.522570 * v_1_14 - 0.168097 * v_1_15 + 0.264081 * v_1_16 + 0.321700 * v_1_17 - 0.013486 * v_1_18 + 0.063613 * v_1_19 - 0.582263 * v_1_20 - 0.208949 * v_1_21 - 0.106337 * v_1_22 - 0.325397 * v_1_23 + 0.370379 * v_1_24 + 0.503389 * v_1_25 + 0.232471 * v_1_26 + 0.282138 * v_1_27 - 0.275855 * v_1_28 - 0.253103 * v_1_29 - 0.124035 * v_1_30 - 0.179523 * v_1_31 - 0.316148 * v_1_32 + 0.488152 * v_1_33 + 0.233270 * v_1_34 - 0.199017 * v_1_35 + 0.170693 * v_1_36 - 0.282176 * v_1_37 - 0.128684 * v_1_38 - 0.585734 * v_1_39 - 0.019644 * v_1_40 + 0.209255 * v_1_41 - 0.016755 * v_1_42 - 0.632787 * v_1_43 + 0.325115 * v_1_44 + 0.113616 * v_1_45 + 0.913445 * v_1_46 + 0.883279 * v_1_47 - 0.114134);
-	let v_2_25 = sin(0.955702 * v_1_0 + 0.084390 * v_1_1 + 0.758439 * v_1_2 - 0.540947 * v_1_3 + 0.224003 * v_1_4 - 0.356072 * v_1_5 - 0.445557 * v_1_6 - 0.155567 * v_1_7 + 0.030557 * v_1_8 - 0.527375 * v_1_9 - 0.898238 * v_1_10 - 0.152370 * v_1_11 - 0.433947 * v_1_12 + 0.160359 * v_1_13 + 0.300817 * v_1_14 + 0.305911 * v_1_15 + 0.617392 * v_1_16 - 0.212488 * v_1_17 - 0.071661 * v_1_18 + 0.057238 * v_1_19 + 0.058223 * v_1_20 + 0.064825 * v_1_21 + 0.258969 * v_1_22 + 0.107303 * v_1_23 - 0.181537 * v_1_24 - 0.034716 * v_1_25 + 0.222675 * v_1_26 - 0.056759 * v_1_27 + 0.648033 * v_1_28 + 0.107814 * v_1_29 + 0.060169 * v_1_30 + 0.018762 * v_1_31 - 0.101532 * v_1_32 - 0.165030 * v_1_33 + 0.157734 * v_1_34 - 0.141075 * v_1_35 + 0.362138 * v_1_36 + 0.226996 * v_1_37 - 0.169289 * v_1_38 + 0.590095 * v_1_39 + 0.105386 * v_1_40 - 0.994318 * v_1_41 - 0.355687 * v_1_42 - 0.086864 * v_1_43 + 0.218116 * v_1_44 - 0.029160 * v_1_45 + 0.231911 * v_1_46 - 0.911764 * v_1_47 - 0.183014);
-	let v_2_26 = sin(0.386710 * v_1_0 + 0.257857 * v_1_1 - 0.060713 * v_1_2 - 0.025225 * v_1_3 + 0.710780 * v_1_4 + 0.695195 * v_1_5 + 1.295398 * v_1_6 - 0.246703 * v_1_7 - 0.539690 * v_1_8 - 0.103381 * v_1_9 - 0.507921 * v_1_10 + 0.308868 * v_1_11 + 0.333093 * v_1_12 - 0.795218 * v_1_13 + 0.173966 * v_1_14 - 0.244464 * v_1_15 - 0.135273 * v_1_16 - 0.988797 * v_1_17 - 0.983994 * v_1_18 - 0.246565 * v_1_19 - 0.085464 * v_1_20 - 0.080052 * v_1_21 + 0.073045 * v_1_22 + 0.194410 * v_1_23 - 0.266440 * v_1_24 - 0.029650 * v_1_25 - 0.526736 * v_1_26 - 0.372449 * v_1_27 - 0.176393 * v_1_28 - 0.381930 * v_1_29 + 0.480782 * v_1_30 - 0.139868 * v_1_31 + 0.586109 * v_1_32 + 0.439524 * v_1_33 + 0.445288 * v_1_34 - 0.912100 * v_1_35 + 0.079869 * v_1_36 + 0.610250 * v_1_37 - 0.008131 * v_1_38 + 0.361915 * v_1_39 + 0.150319 * v_1_40 + 0.892324 * v_1_41 - 0.771445 * v_1_42 + 0.514634 * v_1_43 - 0.455508 * v_1_44 - 0.016116 * v_1_45 - 0.819300 * v_1_46 - 0.341983 * v_1_47 - 0.034942);
-	let v_2_27 = sin(-0.144402 * v_1_0 + 0.111095 * v_1_1 + 0.136385 * v_1_2 + 0.336001 * v_1_3 - 0.184870 * v_1_4 + 0.034638 * v_1_5 - 0.101059 * v_1_6 - 0.027697 * v_1_7 + 0.703250 * v_1_8 + 0.246896 * v_1_9 - 0.343426 * v_1_10 + 0.256680 * v_1_11 + 0.289209 * v_1_12 - 0.348907 * v_1_13 + 0.678041 * v_1_14 + 0.709680 * v_1_15 - 0.331780 * v_1_16 + 0.225414 * v_1_17 - 0.139691 * v_1_18 - 0.337358 * v_1_19 + 0.120131 * v_1_20 - 0.213365 * v_1_21 - 0.122891 * v_1_22 + 0.141087 * v_1_23 - 0.303501 * v_1_24 - 0.340760 * v_1_25 - 0.152856 * v_1_26 + 0.347940 * v_1_27 + 0.246247 * v_1_28 + 0.211004 * v_1_29 - 0.237408 * v_1_30 + 0.191602 * v_1_31 + 0.388930 * v_1_32 + 0.148191 * v_1_33 + 0.099678 * v_1_34 - 0.157573 * v_1_35 + 0.289282 * v_1_36 + 0.746439 * v_1_37 - 0.125660 * v_1_38 + 0.321271 * v_1_39 + 0.064278 * v_1_40 - 0.248809 * v_1_41 + 0.674499 * v_1_42 - 0.183960 * v_1_43 - 0.407737 * v_1_44 + 0.240042 * v_1_45 + 0.266400 * v_1_46 + 0.608327 * v_1_47 - 0.623182);
-	let v_2_28 = sin(-0.711018 * v_1_0 + 0.096232 * v_1_1 - 0.035354 * v_1_2 - 0.054901 * v_1_3 - 0.323302 * v_1_4 - 0.577577 * v_1_5 - 0.299249 * v_1_6 - 0.040877 * v_1_7 + 0.449168 * v_1_8 + 0.229947 * v_1_9 + 0.498703 * v_1_10 + 0.097014 * v_1_11 - 0.533256 * v_1_12 + 0.604337 * v_1_13 - 0.772991 * v_1_14 - 0.048242 * v_1_15 - 0.013933 * v_1_16 + 0.203827 * v_1_17 + 0.253214 * v_1_18 - 0.376096 * v_1_19 - 0.156620 * v_1_20 + 0.052997 * v_1_21 + 0.049813 * v_1_22 + 0.496822 * v_1_23 - 0.442988 * v_1_24 + 0.051792 * v_1_25 - 0.296234 * v_1_26 - 0.126263 * v_1_27 + 0.086989 * v_1_28 + 0.292037 * v_1_29 + 0.309831 * v_1_30 - 0.020999 * v_1_31 - 0.376968 * v_1_32 + 0.128325 * v_1_33 + 0.110589 * v_1_34 - 0.451098 * v_1_35 + 0.308573 * v_1_36 + 1.144194 * v_1_37 + 0.147072 * v_1_38 - 0.105621 * v_1_39 - 0.398447 * v_1_40 - 0.121707 * v_1_41 - 0.344550 * v_1_42 + 0.059260 * v_1_43 + 0.407485 * v_1_44 - 0.073341 * v_1_45 - 0.715525 * v_1_46 - 0.106023 * v_1_47 - 0.194321);
-	let v_2_29 = sin(-0.243367 * v_1_0 + 1.033448 * v_1_1 - 0.685879 * v_1_2 + 0.356412 * v_1_3 - 0.585216 * v_1_4 - 0.146256 * v_1_5 - 0.017181 * v_1_6 - 0.115534 * v_1_7 + 0.619376 * v_1_8 + 0.095690 * v_1_9 + 0.211547 * v_1_10 + 0.363354 * v_1_11 + 0.234344 * v_1_12 - 0.344796 * v_1_13 + 0.298242 * v_1_14 + 0.187283 * v_1_15 + 0.040262 * v_1_16 + 0.384297 * v_1_17 + 0.425192 * v_1_18 - 0.224370 * v_1_19 + 0.641980 * v_1_20 + 0.226824 * v_1_21 - 0.146023 * v_1_22 - 0.416441 * v_1_23 - 0.605028 * v_1_24 - 0.481994 * v_1_25 - 0.029909 * v_1_26 + 0.637359 * v_1_27 - 0.488586 * v_1_28 - 0.036350 * v_1_29 - 0.387300 * v_1_30 - 0.318861 * v_1_31 - 0.018250 * v_1_32 + 0.254172 * v_1_33 + 0.082451 * v_1_34 + 1.087813 * v_1_35 - 0.413226 * v_1_36 - 0.240006 * v_1_37 + 0.113487 * v_1_38 + 0.546292 * v_1_39 + 0.245160 * v_1_40 + 0.165276 * v_1_41 - 0.062537 * v_1_42 - 0.461104 * v_1_43 + 0.298382 * v_1_44 + 0.260350 * v_1_45 - 0.517698 * v_1_46 + 0.165674 * v_1_47 + 0.385663);
-	let v_2_30 = sin(0.409166 * v_1_0 + 0.511140 * v_1_1 + 0.123470 * v_1_2 + 0.062425 * v_1_3 - 0.047861 * v_1_4 - 0.756337 * v_1_5 - 0.200242 * v_1_6 - 0.052623 * v_1_7 + 0.611240 * v_1_8 + 0.000057 * v_1_9 - 0.370010 * v_1_10 - 0.224762 * v_1_11 - 0.077310 * v_1_12 + 0.649532 * v_1_13 + 0.039648 * v_1_14 + 0.229773 * v_1_15 + 0.018872 * v_1_16 + 0.157944 * v_1_17 - 0.081548 * v_1_18 - 0.159586 * v_1_19 - 0.403646 * v_1_20 - 0.241805 * v_1_21 - 0.040583 * v_1_22 - 0.092146 * v_1_23 - 1.409113 * v_1_24 - 0.137593 * v_1_25 - 0.048837 * v_1_26 + 0.161259 * v_1_27 + 0.234208 * v_1_28 - 0.453069 * v_1_29 + 0.215137 * v_1_30 - 0.123811 * v_1_31 - 0.816159 * v_1_32 + 0.098581 * v_1_33 - 0.690694 * v_1_34 - 0.125415 * v_1_35 - 0.907504 * v_1_36 + 1.088080 * v_1_37 - 0.770761 * v_1_38 + 0.149478 * v_1_39 + 0.213150 * v_1_40 + 0.560148 * v_1_41 + 0.021494 * v_1_42 - 0.255689 * v_1_43 - 0.349597 * v_1_44 - 0.114478 * v_1_45 - 0.369980 * v_1_46 + 0.099955 * v_1_47 - 0.574284);
-	let v_2_31 = sin(0.414860 * v_1_0 - 0.198183 * v_1_1 - 0.155468 * v_1_2 - 0.365716 * v_1_3 + 0.520077 * v_1_4 + 0.192850 * v_1_5 - 0.904142 * v_1_6 + 0.189568 * v_1_7 - 0.164386 * v_1_8 - 0.112701 * v_1_9 - 0.811083 * v_1_10 + 0.085913 * v_1_11 + 0.154734 * v_1_12 - 0.020787 * v_1_13 + 0.042277 * v_1_14 - 0.138611 * v_1_15 - 0.108552 * v_1_16 - 0.867271 * v_1_17 - 0.354054 * v_1_18 - 0.277918 * v_1_19 + 0.230211 * v_1_20 - 0.069052 * v_1_21 - 0.351682 * v_1_22 - 0.163030 * v_1_23 + 0.216979 * v_1_24 + 0.506103 * v_1_25 - 0.013422 * v_1_26 - 0.057449 * v_1_27 - 0.216637 * v_1_28 - 0.122231 * v_1_29 + 0.104281 * v_1_30 - 0.151243 * v_1_31 + 0.423485 * v_1_32 + 0.206758 * v_1_33 - 0.110177 * v_1_34 + 0.044941 * v_1_35 + 0.206876 * v_1_36 - 0.826773 * v_1_37 + 0.093450 * v_1_38 - 0.285839 * v_1_39 + 0.386410 * v_1_40 + 0.114642 * v_1_41 + 0.499851 * v_1_42 + 0.101400 * v_1_43 + 0.348960 * v_1_44 - 0.091233 * v_1_45 - 0.354534 * v_1_46 + 1.143631 * v_1_47 + 0.446650);
-	let v_3_0 = sin(0.419343 * v_2_0 - 0.180995 * v_2_1 - 0.348860 * v_2_2 - 0.499762 * v_2_3 + 0.165314 * v_2_4 - 0.581679 * v_2_5 + 0.550976 * v_2_6 - 0.369865 * v_2_7 + 0.474755 * v_2_8 - 0.173036 * v_2_9 - 0.122767 * v_2_10 - 0.240638 * v_2_11 - 0.057468 * v_2_12 + 0.088673 * v_2_13 - 0.184934 * v_2_14 - 0.530499 * v_2_15 + 0.029395 * v_2_16 + 0.294490 * v_2_17 - 0.655315 * v_2_18 + 0.108175 * v_2_19 + 0.478198 * v_2_20 + 0.269589 * v_2_21 + 0.106838 * v_2_22 + 0.507234 * v_2_23 + 0.322299 * v_2_24 - 0.105919 * v_2_25 + 0.276028 * v_2_26 - 0.142932 * v_2_27 + 0.710633 * v_2_28 - 0.159841 * v_2_29 - 0.444504 * v_2_30 - 0.102176 * v_2_31 + 0.243438);
-	let v_3_1 = sin(-0.284624 * v_2_0 + 0.122204 * v_2_1 - 0.422339 * v_2_2 - 0.141390 * v_2_3 - 0.045735 * v_2_4 - 0.216401 * v_2_5 - 0.091434 * v_2_6 - 0.137256 * v_2_7 + 0.733314 * v_2_8 + 0.168031 * v_2_9 - 0.792967 * v_2_10 - 0.508382 * v_2_11 + 0.582709 * v_2_12 - 0.544917 * v_2_13 + 0.520553 * v_2_14 + 0.334707 * v_2_15 - 0.206330 * v_2_16 - 0.218564 * v_2_17 + 0.166662 * v_2_18 + 0.523366 * v_2_19 + 0.123468 * v_2_20 - 0.231390 * v_2_21 - 0.584914 * v_2_22 + 0.638686 * v_2_23 + 0.097592 * v_2_24 + 0.699331 * v_2_25 - 0.407041 * v_2_26 - 0.264926 * v_2_27 - 0.054630 * v_2_28 - 0.167902 * v_2_29 - 0.084709 * v_2_30 - 0.438787 * v_2_31 + 0.047286);
-	let v_3_2 = sin(-0.117856 * v_2_0 - 0.411409 * v_2_1 - 0.082031 * v_2_2 - 0.111335 * v_2_3 + 0.220065 * v_2_4 + 0.523577 * v_2_5 + 0.214151 * v_2_6 - 0.284820 * v_2_7 + 0.396782 * v_2_8 + 0.421182 * v_2_9 - 0.182553 * v_2_10 - 0.157752 * v_2_11 + 0.133821 * v_2_12 - 0.742024 * v_2_13 - 0.561280 * v_2_14 - 0.238965 * v_2_15 - 0.517743 * v_2_16 + 0.127921 * v_2_17 + 0.052001 * v_2_18 - 0.092063 * v_2_19 + 0.155208 * v_2_20 - 0.621063 * v_2_21 - 0.141819 * v_2_22 - 0.105139 * v_2_23 + 0.730201 * v_2_24 + 0.307664 * v_2_25 - 0.329316 * v_2_26 - 0.767905 * v_2_27 + 0.573497 * v_2_28 - 0.602804 * v_2_29 + 0.146844 * v_2_30 + 0.575887 * v_2_31 - 0.324195);
-	let v_3_3 = sin(-0.395592 * v_2_0 + 0.323637 * v_2_1 - 0.019219 * v_2_2 - 0.366807 * v_2_3 + 0.214709 * v_2_4 - 0.322014 * v_2_5 + 0.111386 * v_2_6 - 0.512860 * v_2_7 + 1.058261 * v_2_8 - 0.252709 * v_2_9 - 0.032305 * v_2_10 - 0.059746 * v_2_11 - 0.080386 * v_2_12 - 0.661623 * v_2_13 + 0.353003 * v_2_14 - 0.070178 * v_2_15 - 0.073101 * v_2_16 - 1.115642 * v_2_17 + 0.805982 * v_2_18 - 0.765041 * v_2_19 - 1.166326 * v_2_20 - 0.217324 * v_2_21 - 0.136342 * v_2_22 - 0.342845 * v_2_23 - 0.316224 * v_2_24 + 0.142209 * v_2_25 - 0.142545 * v_2_26 + 0.010292 * v_2_27 - 0.299245 * v_2_28 + 0.726128 * v_2_29 + 0.502249 * v_2_30 + 0.340246 * v_2_31 - 0.228216);
-	let v_3_4 = sin(0.004950 * v_2_0 - 0.540136 * v_2_1 - 0.196743 * v_2_2 + 0.352281 * v_2_3 + 0.174591 * v_2_4 - 0.279408 * v_2_5 + 0.707928 * v_2_6 + 0.467299 * v_2_7 - 0.447292 * v_2_8 - 0.200458 * v_2_9 - 0.454746 * v_2_10 + 0.093388 * v_2_11 - 0.227959 * v_2_12 - 0.547105 * v_2_13 - 0.313064 * v_2_14 - 0.197024 * v_2_15 + 0.337188 * v_2_16 + 0.271806 * v_2_17 - 0.002411 * v_2_18 - 0.130877 * v_2_19 + 0.132004 * v_2_20 - 0.361912 * v_2_21 - 0.018936 * v_2_22 - 0.411652 * v_2_23 - 0.088128 * v_2_24 - 0.171949 * v_2_25 + 0.377893 * v_2_26 - 0.194033 * v_2_27 + 0.266305 * v_2_28 - 0.287929 * v_2_29 - 0.248534 * v_2_30 - 0.195159 * v_2_31 + 0.657732);
-	let v_3_5 = sin(-0.450874 * v_2_0 - 0.154820 * v_2_1 - 0.299321 * v_2_2 - 0.226072 * v_2_3 + 0.011347 * v_2_4 + 0.444032 * v_2_5 + 0.381569 * v_2_6 + 0.847599 * v_2_7 - 1.047123 * v_2_8 - 0.490194 * v_2_9 + 0.091847 * v_2_10 - 0.258238 * v_2_11 - 0.381851 * v_2_12 + 0.159466 * v_2_13 - 0.356054 * v_2_14 + 0.183761 * v_2_15 + 0.117626 * v_2_16 - 0.157838 * v_2_17 - 0.123889 * v_2_18 + 0.223790 * v_2_19 - 0.098572 * v_2_20 + 0.147982 * v_2_21 + 0.281463 * v_2_22 + 0.434303 * v_2_23 + 0.008671 * v_2_24 - 0.222881 * v_2_25 + 0.201821 * v_2_26 + 0.140123 * v_2_27 - 0.418945 * v_2_28 - 0.024680 * v_2_29 + 0.104250 * v_2_30 - 0.018077 * v_2_31 + 0.445635);
-	let v_3_6 = sin(0.020599 * v_2_0 - 0.255950 * v_2_1 - 0.207563 * v_2_2 + 0.168768 * v_2_3 + 0.019528 * v_2_4 - 0.233202 * v_2_5 - 0.421950 * v_2_6 - 0.863581 * v_2_7 - 0.280418 * v_2_8 - 0.297092 * v_2_9 + 0.051802 * v_2_10 - 0.630503 * v_2_11 - 0.228119 * v_2_12 + 0.114627 * v_2_13 - 0.365159 * v_2_14 + 0.158513 * v_2_15 + 0.025123 * v_2_16 + 0.289106 * v_2_17 + 0.149643 * v_2_18 + 0.311642 * v_2_19 + 0.402054 * v_2_20 - 0.168600 * v_2_21 + 0.158613 * v_2_22 + 0.262741 * v_2_23 - 0.358739 * v_2_24 + 0.018218 * v_2_25 - 0.144341 * v_2_26 + 0.013236 * v_2_27 + 0.238816 * v_2_28 - 0.064521 * v_2_29 + 0.110528 * v_2_30 - 0.886633 * v_2_31 - 0.532430);
-	let v_3_7 = sin(0.008253 * v_2_0 + 0.731619 * v_2_1 + 0.066767 * v_2_2 - 0.371861 * v_2_3 + 0.259314 * v_2_4 + 0.556775 * v_2_5 - 0.033394 * v_2_6 - 0.694277 * v_2_7 + 0.341844 * v_2_8 + 0.486928 * v_2_9 - 0.926173 * v_2_10 + 0.263433 * v_2_11 + 0.399322 * v_2_12 + 0.150198 * v_2_13 + 0.479281 * v_2_14 + 0.342291 * v_2_15 - 0.326876 * v_2_16 - 0.349476 * v_2_17 + 0.503330 * v_2_18 + 0.447121 * v_2_19 - 0.236050 * v_2_20 - 0.180505 * v_2_21 - 0.065219 * v_2_22 - 0.201771 * v_2_23 - 0.381377 * v_2_24 + 0.015775 * v_2_25 + 0.065299 * v_2_26 - 0.313352 * v_2_27 + 0.401061 * v_2_28 + 0.236905 * v_2_29 + 0.445426 * v_2_30 + 0.039570 * v_2_31 + 0.468252);
-	let v_3_8 = sin(-0.017280 * v_2_0 + 0.005500 * v_2_1 - 0.754492 * v_2_2 - 0.913154 * v_2_3 - 0.184771 * v_2_4 + 0.346212 * v_2_5 - 0.090725 * v_2_6 + 0.875362 * v_2_7 - 0.118320 * v_2_8 - 0.622019 * v_2_9 + 0.289129 * v_2_10 + 0.858533 * v_2_11 - 0.470889 * v_2_12 - 0.429420 * v_2_13 - 0.751058 * v_2_14 - 0.421350 * v_2_15 + 0.163358 * v_2_16 - 0.023441 * v_2_17 - 0.733554 * v_2_18 + 0.579344 * v_2_19 + 0.003823 * v_2_20 - 0.139042 * v_2_21 - 0.409475 * v_2_22 - 0.151988 * v_2_23 + 0.221369 * v_2_24 - 0.200570 * v_2_25 + 0.338875 * v_2_26 - 0.364492 * v_2_27 - 0.024603 * v_2_28 + 0.099281 * v_2_29 - 0.390984 * v_2_30 + 0.199058 * v_2_31 + 0.585388);
-	let v_3_9 = sin(-0.253413 * v_2_0 + 0.440742 * v_2_1 - 0.227451 * v_2_2 - 0.159104 * v_2_3 + 0.028624 * v_2_4 - 0.292648 * v_2_5 + 0.791640 * v_2_6 + 0.124755 * v_2_7 + 0.236467 * v_2_8 + 0.619227 * v_2_9 - 0.252266 * v_2_10 + 0.447911 * v_2_11 + 0.477929 * v_2_12 + 0.302575 * v_2_13 - 0.369925 * v_2_14 + 0.126080 * v_2_15 + 0.193522 * v_2_16 + 0.044504 * v_2_17 + 0.343685 * v_2_18 - 0.380721 * v_2_19 - 0.135336 * v_2_20 + 0.178445 * v_2_21 + 0.308897 * v_2_22 + 0.235088 * v_2_23 - 0.026809 * v_2_24 + 1.216625 * v_2_25 + 0.724433 * v_2_26 - 0.785474 * v_2_27 - 0.339317 * v_2_28 + 0.338365 * v_2_29 - 0.542174 * v_2_30 + 0.001182 * v_2_31 + 0.311049);
-	let v_3_10 = sin(0.767128 * v_2_0 + 0.433564 * v_2_1 + 0.052036 * v_2_2 + 0.041426 * v_2_3 + 0.141328 * v_2_4 + 0.319995 * v_2_5 + 0.006505 * v_2_6 - 0.665160 * v_2_7 + 0.436020 * v_2_8 - 0.704350 * v_2_9 - 0.278276 * v_2_10 - 0.601230 * v_2_11 + 0.209847 * v_2_12 - 0.066848 * v_2_13 - 0.263159 * v_2_14 - 0.144441 * v_2_15 - 0.114737 * v_2_16 - 0.171489 * v_2_17 - 0.004731 * v_2_18 + 0.683405 * v_2_19 + 0.267067 * v_2_20 + 0.338086 * v_2_21 - 0.281379 * v_2_22 - 0.482243 * v_2_23 + 0.672144 * v_2_24 + 0.427815 * v_2_25 - 0.407783 * v_2_26 - 0.064841 * v_2_27 + 0.400627 * v_2_28 - 0.076581 * v_2_29 + 0.942430 * v_2_30 + 0.490500 * v_2_31 - 0.573303);
-	let v_3_11 = sin(-0.730058 * v_2_0 - 0.121022 * v_2_1 - 0.291577 * v_2_2 - 0.051080 * v_2_3 + 0.156400 * v_2_4 - 0.147482 * v_2_5 - 0.237700 * v_2_6 + 0.025328 * v_2_7 + 0.426541 * v_2_8 + 0.454470 * v_2_9 + 0.461810 * v_2_10 - 0.166397 * v_2_11 - 0.431057 * v_2_12 - 0.802793 * v_2_13 - 0.446824 * v_2_14 + 0.485705 * v_2_15 - 0.201034 * v_2_16 - 0.434357 * v_2_17 - 0.610217 * v_2_18 + 0.977376 * v_2_19 + 0.060064 * v_2_20 - 0.812288 * v_2_21 - 0.230022 * v_2_22 - 0.091011 * v_2_23 + 0.257675 * v_2_24 + 0.396474 * v_2_25 - 0.489460 * v_2_26 - 1.051303 * v_2_27 + 0.426814 * v_2_28 + 0.120645 * v_2_29 - 0.081898 * v_2_30 + 0.264349 * v_2_31 - 0.331729);
-	let v_3_12 = sin(0.045065 * v_2_0 - 0.020796 * v_2_1 - 0.122411 * v_2_2 - 0.043813 * v_2_3 + 0.314211 * v_2_4 - 0.579685 * v_2_5 - 0.320322 * v_2_6 + 0.825006 * v_2_7 + 0.411928 * v_2_8 + 0.363976 * v_2_9 - 0.201113 * v_2_10 + 0.001676 * v_2_11 + 0.367642 * v_2_12 - 0.185221 * v_2_13 - 0.115608 * v_2_14 - 0.236459 * v_2_15 + 0.121819 * v_2_16 + 0.573184 * v_2_17 + 0.394424 * v_2_18 + 0.296299 * v_2_19 + 0.398953 * v_2_20 + 0.357116 * v_2_21 + 0.573200 * v_2_22 + 0.644782 * v_2_23 + 0.389347 * v_2_24 - 0.192336 * v_2_25 - 0.590175 * v_2_26 - 0.369760 * v_2_27 - 0.195195 * v_2_28 + 0.442650 * v_2_29 - 0.029619 * v_2_30 - 0.058527 * v_2_31 + 0.472890);
-	let v_3_13 = sin(0.309769 * v_2_0 - 0.108387 * v_2_1 - 0.247405 * v_2_2 + 0.733535 * v_2_3 - 0.098257 * v_2_4 - 0.662393 * v_2_5 - 0.686126 * v_2_6 - 0.374786 * v_2_7 + 0.344526 * v_2_8 + 0.087827 * v_2_9 - 0.654320 * v_2_10 - 0.231422 * v_2_11 - 0.544009 * v_2_12 + 0.177697 * v_2_13 + 0.434159 * v_2_14 + 0.654131 * v_2_15 - 0.220776 * v_2_16 + 0.223519 * v_2_17 + 0.050054 * v_2_18 + 0.231892 * v_2_19 + 0.249928 * v_2_20 + 0.196131 * v_2_21 - 0.924979 * v_2_22 - 0.213621 * v_2_23 - 0.354279 * v_2_24 - 0.316963 * v_2_25 + 0.366139 * v_2_26 - 0.541294 * v_2_27 - 0.679203 * v_2_28 + 1.011294 * v_2_29 + 0.346346 * v_2_30 + 0.242406 * v_2_31 + 0.434947);
-	let v_3_14 = sin(0.485733 * v_2_0 + 0.526913 * v_2_1 + 0.603220 * v_2_2 - 0.349697 * v_2_3 - 0.042449 * v_2_4 + 0.182035 * v_2_5 - 0.118077 * v_2_6 - 0.696199 * v_2_7 + 0.428862 * v_2_8 - 0.557133 * v_2_9 + 0.214431 * v_2_10 - 0.480658 * v_2_11 + 0.071827 * v_2_12 - 0.043954 * v_2_13 + 0.324960 * v_2_14 + 0.067793 * v_2_15 + 0.792028 * v_2_16 + 0.332001 * v_2_17 - 0.114279 * v_2_18 - 0.515261 * v_2_19 + 0.283312 * v_2_20 + 0.206503 * v_2_21 - 0.171548 * v_2_22 + 0.647482 * v_2_23 - 0.183086 * v_2_24 + 0.130745 * v_2_25 + 0.359698 * v_2_26 + 0.068249 * v_2_27 + 0.292754 * v_2_28 + 0.059203 * v_2_29 - 0.345403 * v_2_30 - 0.457954 * v_2_31 - 0.259189);
-	let v_3_15 = sin(0.359545 * v_2_0 - 0.435604 * v_2_1 - 0.397670 * v_2_2 + 0.142738 * v_2_3 + 0.262507 * v_2_4 - 0.266791 * v_2_5 + 0.213777 * v_2_6 + 0.588001 * v_2_7 - 0.027863 * v_2_8 - 0.221356 * v_2_9 + 0.188568 * v_2_10 - 0.582455 * v_2_11 - 0.260862 * v_2_12 + 0.229531 * v_2_13 - 0.761041 * v_2_14 - 0.797490 * v_2_15 + 0.076597 * v_2_16 + 0.385924 * v_2_17 - 0.079165 * v_2_18 - 0.238093 * v_2_19 + 0.065375 * v_2_20 + 0.737687 * v_2_21 + 0.476925 * v_2_22 - 0.126271 * v_2_23 + 0.916433 * v_2_24 - 0.129165 * v_2_25 + 0.172746 * v_2_26 + 0.262414 * v_2_27 + 0.252040 * v_2_28 + 0.450114 * v_2_29 + 0.012148 * v_2_30 - 0.029092 * v_2_31 - 0.194557);
-	let v_3_16 = sin(-1.081073 * v_2_0 - 0.008847 * v_2_1 - 0.210760 * v_2_2 + 0.298422 * v_2_3 - 0.167610 * v_2_4 - 0.291355 * v_2_5 + 0.155649 * v_2_6 - 0.156663 * v_2_7 - 0.144740 * v_2_8 - 0.548725 * v_2_9 + 0.740967 * v_2_10 + 0.011208 * v_2_11 + 0.258178 * v_2_12 - 0.026154 * v_2_13 - 0.101719 * v_2_14 - 0.228342 * v_2_15 + 0.266566 * v_2_16 - 0.393092 * v_2_17 - 0.038808 * v_2_18 + 0.028380 * v_2_19 - 0.075603 * v_2_20 + 0.395569 * v_2_21 + 0.241320 * v_2_22 - 0.094977 * v_2_23 - 0.084711 * v_2_24 - 0.469195 * v_2_25 - 0.026164 * v_2_26 + 0.213760 * v_2_27 - 0.363660 * v_2_28 + 0.194445 * v_2_29 - 0.150736 * v_2_30 + 0.297245 * v_2_31 + 0.181003);
-	let v_3_17 = sin(0.115413 * v_2_0 - 0.189137 * v_2_1 - 0.175010 * v_2_2 + 0.085753 * v_2_3 + 0.588341 * v_2_4 - 0.030309 * v_2_5 - 0.095822 * v_2_6 + 0.535074 * v_2_7 - 0.123544 * v_2_8 + 0.190065 * v_2_9 - 0.011106 * v_2_10 + 0.016162 * v_2_11 - 0.356060 * v_2_12 + 0.156113 * v_2_13 + 0.084980 * v_2_14 - 0.616575 * v_2_15 - 0.444279 * v_2_16 + 0.837467 * v_2_17 + 0.351958 * v_2_18 - 0.353317 * v_2_19 + 0.267236 * v_2_20 + 0.198088 * v_2_21 - 0.273679 * v_2_22 - 0.433636 * v_2_23 - 0.145892 * v_2_24 - 0.044753 * v_2_25 + 0.343776 * v_2_26 - 0.004241 * v_2_27 - 0.427393 * v_2_28 + 0.644556 * v_2_29 - 1.034175 * v_2_30 + 0.816787 * v_2_31 + 0.620701);
-	let v_3_18 = sin(0.353411 * v_2_0 - 0.489099 * v_2_1 - 0.075717 * v_2_2 + 0.196641 * v_2_3 + 0.214886 * v_2_4 + 0.660370 * v_2_5 - 0.798508 * v_2_6 - 0.948326 * v_2_7 - 0.657120 * v_2_8 + 0.102097 * v_2_9 + 0.635018 * v_2_10 + 0.217123 * v_2_11 - 0.416963 * v_2_12 - 0.029125 * v_2_13 - 0.026258 * v_2_14 + 0.093366 * v_2_15 + 0.237232 * v_2_16 + 0.357646 * v_2_17 - 0.366788 * v_2_18 + 0.110694 * v_2_19 - 0.145975 * v_2_20 + 0.086406 * v_2_21 - 0.146264 * v_2_22 - 0.081264 * v_2_23 - 0.674534 * v_2_24 + 0.176181 * v_2_25 + 0.562959 * v_2_26 - 0.408426 * v_2_27 - 0.063762 * v_2_28 + 0.129348 * v_2_29 - 0.213856 * v_2_30 - 0.562355 * v_2_31 - 0.519685);
-	let v_3_19 = sin(0.332107 * v_2_0 + 0.179051 * v_2_1 + 0.174031 * v_2_2 - 0.073370 * v_2_3 - 0.157280 * v_2_4 + 0.186029 * v_2_5 - 0.446353 * v_2_6 - 0.078281 * v_2_7 + 0.328914 * v_2_8 + 0.054893 * v_2_9 - 0.355016 * v_2_10 - 0.397975 * v_2_11 + 0.506994 * v_2_12 + 0.321748 * v_2_13 + 0.225668 * v_2_14 + 0.214165 * v_2_15 - 0.366780 * v_2_16 + 0.048684 * v_2_17 - 0.452238 * v_2_18 - 0.036128 * v_2_19 + 0.080990 * v_2_20 + 0.092326 * v_2_21 - 0.146047 * v_2_22 + 0.370745 * v_2_23 - 0.132701 * v_2_24 + 0.190835 * v_2_25 - 0.247913 * v_2_26 - 0.159316 * v_2_27 + 0.324172 * v_2_28 - 0.192757 * v_2_29 - 0.118149 * v_2_30 - 0.133869 * v_2_31 - 0.077717);
-	let v_3_20 = sin(0.959353 * v_2_0 + 0.012101 * v_2_1 - 0.006413 * v_2_2 - 0.081394 * v_2_3 + 0.657641 * v_2_4 - 0.571903 * v_2_5 - 0.000075 * v_2_6 + 1.256809 * v_2_7 + 0.161313 * v_2_8 + 0.335496 * v_2_9 - 0.461744 * v_2_10 - 0.198302 * v_2_11 + 0.078227 * v_2_12 - 0.524459 * v_2_13 - 0.180726 * v_2_14 + 0.043400 * v_2_15 - 0.907210 * v_2_16 + 0.149120 * v_2_17 + 0.582920 * v_2_18 + 0.382157 * v_2_19 + 0.102969 * v_2_20 + 0.111453 * v_2_21 + 0.545552 * v_2_22 - 0.099340 * v_2_23 + 1.002356 * v_2_24 + 0.295599 * v_2_25 - 0.019641 * v_2_26 - 0.395541 * v_2_27 + 0.167210 * v_2_28 + 0.669861 * v_2_29 + 0.693004 * v_2_30 + 0.475783 * v_2_31 + 0.091554);
-	let v_3_21 = sin(0.344685 * v_2_0 + 0.055276 * v_2_1 + 0.346127 * v_2_2 - 0.341521 * v_2_3 + 0.055072 * v_2_4 + 0.326164 * v_2_5 + 0.162627 * v_2_6 - 0.770449 * v_2_7 + 0.643234 * v_2_8 + 0.017378 * v_2_9 - 0.380880 * v_2_10 - 0.458067 * v_2_11 - 0.211399 * v_2_12 - 0.130619 * v_2_13 + 0.283551 * v_2_14 - 0.146782 * v_2_15 - 0.200334 * v_2_16 + 0.110239 * v_2_17 + 0.318451 * v_2_18 + 0.161081 * v_2_19 + 0.319882 * v_2_20 + 0.471908 * v_2_21 - 0.378874 * v_2_22 + 0.351466 * v_2_23 - 0.466186 * v_2_24 - 0.068455 * v_2_25 - 0.296654 * v_2_26 + 0.393144 * v_2_27 + 0.603238 * v_2_28 + 0.266878 * v_2_29 + 0.424633 * v_2_30 - 0.234124 * v_2_31 - 0.844988);
-	let v_3_22 = sin(0.491009 * v_2_0 + 0.326662 * v_2_1 - 0.032446 * v_2_2 + 0.194322 * v_2_3 + 0.367914 * v_2_4 - 0.018149 * v_2_5 - 0.010972 * v_2_6 + 0.950345 * v_2_7 - 0.335783 * v_2_8 - 0.246394 * v_2_9 + 0.047342 * v_2_10 + 0.104142 * v_2_11 + 0.325227 * v_2_12 - 0.266331 * v_2_13 + 0.229278 * v_2_14 - 0.427173 * v_2_15 - 0.022016 * v_2_16 + 0.451048 * v_2_17 - 0.021538 * v_2_18 - 0.462361 * v_2_19 + 0.103743 * v_2_20 + 0.135996 * v_2_21 - 0.625942 * v_2_22 + 0.160017 * v_2_23 - 0.475692 * v_2_24 - 0.588250 * v_2_25 + 0.026736 * v_2_26 + 0.471211 * v_2_27 - 0.260672 * v_2_28 + 0.284947 * v_2_29 + 0.149391 * v_2_30 + 0.681991 * v_2_31 - 0.083768);
-	let v_3_23 = sin(-0.016097 * v_2_0 + 0.153236 * v_2_1 - 0.041591 * v_2_2 - 0.056026 * v_2_3 - 0.079883 * v_2_4 + 0.541057 * v_2_5 + 0.379674 * v_2_6 + 0.159008 * v_2_7 - 0.621189 * v_2_8 + 0.415271 * v_2_9 + 0.054196 * v_2_10 - 0.340073 * v_2_11 - 0.426246 * v_2_12 + 0.049746 * v_2_13 - 0.064359 * v_2_14 - 0.086460 * v_2_15 - 0.400663 * v_2_16 - 0.436214 * v_2_17 - 0.625837 * v_2_18 - 0.498444 * v_2_19 + 0.435685 * v_2_20 - 0.363980 * v_2_21 + 0.405971 * v_2_22 - 0.161583 * v_2_23 - 0.129228 * v_2_24 + 0.174188 * v_2_25 + 0.246531 * v_2_26 - 0.050831 * v_2_27 - 0.071423 * v_2_28 - 0.096554 * v_2_29 - 0.163815 * v_2_30 + 0.779553 * v_2_31 + 0.401157);
-	let v_3_24 = sin(-0.293514 * v_2_0 - 0.071204 * v_2_1 + 0.029916 * v_2_2 + 0.046141 * v_2_3 - 0.034564 * v_2_4 - 0.611550 * v_2_5 + 0.034492 * v_2_6 + 0.038967 * v_2_7 + 0.184676 * v_2_8 + 0.113116 * v_2_9 + 0.464309 * v_2_10 - 0.322090 * v_2_11 - 0.632420 * v_2_12 - 0.346284 * v_2_13 + 0.020796 * v_2_14 - 0.345139 * v_2_15 + 0.135106 * v_2_16 + 0.026149 * v_2_17 - 0.604673 * v_2_18 - 0.316005 * v_2_19 - 0.288835 * v_2_20 - 0.082304 * v_2_21 - 0.110014 * v_2_22 - 0.128268 * v_2_23 + 0.342484 * v_2_24 + 0.218775 * v_2_25 - 0.581967 * v_2_26 + 0.411731 * v_2_27 + 0.224042 * v_2_28 - 0.182070 * v_2_29 - 0.528590 * v_2_30 + 0.571348 * v_2_31 + 0.443694);
-	let v_3_25 = sin(0.189458 * v_2_0 + 0.715274 * v_2_1 + 1.203278 * v_2_2 - 0.493068 * v_2_3 - 0.277635 * v_2_4 - 0.049482 * v_2_5 + 0.186091 * v_2_6 + 0.329293 * v_2_7 - 0.240028 * v_2_8 + 0.340134 * v_2_9 - 0.732178 * v_2_10 + 0.562000 * v_2_11 + 0.667762 * v_2_12 + 0.655981 * v_2_13 + 0.527413 * v_2_14 + 0.278350 * v_2_15 - 0.259432 * v_2_16 - 1.488787 * v_2_17 - 0.252514 * v_2_18 - 0.214842 * v_2_19 + 0.411441 * v_2_20 - 0.563427 * v_2_21 + 0.444388 * v_2_22 - 0.261793 * v_2_23 + 0.400852 * v_2_24 + 0.039202 * v_2_25 - 0.204906 * v_2_26 + 0.712622 * v_2_27 + 0.202029 * v_2_28 + 0.434688 * v_2_29 + 0.570608 * v_2_30 + 0.112112 * v_2_31 - 0.639982);
-	let v_3_26 = sin(0.008812 * v_2_0 + 0.029619 * v_2_1 - 0.622071 * v_2_2 - 0.406010 * v_2_3 - 0.012552 * v_2_4 - 0.028311 * v_2_5 + 0.392390 * v_2_6 + 0.342387 * v_2_7 - 0.242289 * v_2_8 + 0.160915 * v_2_9 - 0.053841 * v_2_10 + 0.049378 * v_2_11 + 0.279768 * v_2_12 - 0.065506 * v_2_13 - 0.518243 * v_2_14 - 0.316819 * v_2_15 + 0.302592 * v_2_16 - 0.282615 * v_2_17 - 0.151630 * v_2_18 - 0.316109 * v_2_19 - 0.035885 * v_2_20 + 0.086050 * v_2_21 + 0.141030 * v_2_22 - 0.038889 * v_2_23 + 0.728289 * v_2_24 - 0.275307 * v_2_25 + 0.174463 * v_2_26 + 0.153918 * v_2_27 + 0.364272 * v_2_28 + 0.252798 * v_2_29 + 0.115096 * v_2_30 + 0.106738 * v_2_31 + 0.127241);
-	let v_3_27 = sin(0.076088 * v_2_0 + 0.294681 * v_2_1 + 0.328046 * v_2_2 + 0.195533 * v_2_3 + 0.256984 * v_2_4 - 0.189316 * v_2_5 + 0.171346 * v_2_6 - 0.103438 * v_2_7 + 0.179138 * v_2_8 + 0.243704 * v_2_9 + 0.092888 * v_2_10 - 0.051311 * v_2_11 - 0.468287 * v_2_12 - 0.220529 * v_2_13 + 0.284789 * v_2_14 + 0.246905 * v_2_15 - 0.125867 * v_2_16 - 0.064687 * v_2_17 + 0.484673 * v_2_18 - 0.599960 * v_2_19 + 0.088950 * v_2_20 - 0.294324 * v_2_21 + 0.544087 * v_2_22 - 0.074213 * v_2_23 - 0.314365 * v_2_24 + 0.187174 * v_2_25 + 0.212219 * v_2_26 - 0.082628 * v_2_27 - 0.052740 * v_2_28 + 0.328641 * v_2_29 + 0.292163 * v_2_30 - 0.355812 * v_2_31 - 0.353487);
-	let v_3_28 = sin(-0.489775 * v_2_0 - 0.398814 * v_2_1 - 0.119874 * v_2_2 - 0.090835 * v_2_3 - 0.505519 * v_2_4 + 0.376232 * v_2_5 - 0.047998 * v_2_6 + 0.367208 * v_2_7 + 0.304198 * v_2_8 - 0.057283 * v_2_9 - 0.322326 * v_2_10 - 0.928035 * v_2_11 - 0.155113 * v_2_12 - 0.167566 * v_2_13 - 0.857800 * v_2_14 - 0.313413 * v_2_15 - 0.030761 * v_2_16 - 0.687045 * v_2_17 + 0.055104 * v_2_18 - 0.643601 * v_2_19 + 0.481330 * v_2_20 - 0.197407 * v_2_21 - 0.071916 * v_2_22 + 0.326651 * v_2_23 + 0.075814 * v_2_24 - 0.528350 * v_2_25 + 0.444045 * v_2_26 - 0.443900 * v_2_27 - 0.790172 * v_2_28 - 0.413364 * v_2_29 + 0.243782 * v_2_30 - 0.495727 * v_2_31 + 0.847387);
-	let v_3_29 = sin(-0.284037 * v_2_0 - 0.023716 * v_2_1 - 0.073744 * v_2_2 + 0.180590 * v_2_3 + 0.174055 * v_2_4 - 0.120701 * v_2_5 - 0.178039 * v_2_6 + 0.222386 * v_2_7 + 0.283655 * v_2_8 + 0.383545 * v_2_9 + 0.263992 * v_2_10 + 0.664803 * v_2_11 + 0.269605 * v_2_12 - 0.249750 * v_2_13 - 0.609173 * v_2_14 + 0.341684 * v_2_15 + 0.121396 * v_2_16 + 0.245409 * v_2_17 - 0.376882 * v_2_18 - 0.199446 * v_2_19 - 0.174183 * v_2_20 - 0.984504 * v_2_21 + 0.524973 * v_2_22 - 0.012277 * v_2_23 + 0.104796 * v_2_24 + 0.101973 * v_2_25 + 0.134980 * v_2_26 - 0.664452 * v_2_27 - 0.185056 * v_2_28 - 0.144188 * v_2_29 - 0.469455 * v_2_30 + 0.189815 * v_2_31 - 0.177494);
-	let v_3_30 = sin(-0.330014 * v_2_0 - 0.076416 * v_2_1 - 0.129070 * v_2_2 - 0.073350 * v_2_3 - 0.418322 * v_2_4 - 0.177679 * v_2_5 - 0.543658 * v_2_6 - 0.203862 * v_2_7 + 0.734689 * v_2_8 + 0.265883 * v_2_9 - 0.001993 * v_2_10 - 0.229017 * v_2_11 + 0.200967 * v_2_12 - 0.257070 * v_2_13 - 0.393270 * v_2_14 + 0.099930 * v_2_15 - 0.007156 * v_2_16 + 0.998782 * v_2_17 + 0.254218 * v_2_18 + 0.104614 * v_2_19 + 0.038283 * v_2_20 - 0.078632 * v_2_21 - 0.514710 * v_2_22 + 0.731456 * v_2_23 - 0.335306 * v_2_24 + 0.123916 * v_2_25 - 0.002212 * v_2_26 - 0.202271 * v_2_27 + 0.395220 * v_2_28 - 0.246860 * v_2_29 + 0.490098 * v_2_30 - 0.244505 * v_2_31 - 0.171263);
-	let v_3_31 = sin(0.433097 * v_2_0 - 0.293113 * v_2_1 - 0.859577 * v_2_2 - 0.529148 * v_2_3 - 0.369412 * v_2_4 + 0.596329 * v_2_5 - 1.065272 * v_2_6 - 0.304605 * v_2_7 - 0.263359 * v_2_8 + 0.647525 * v_2_9 - 0.098290 * v_2_10 + 0.550445 * v_2_11 + 0.198533 * v_2_12 - 0.246900 * v_2_13 - 0.595325 * v_2_14 + 0.363841 * v_2_15 - 0.044602 * v_2_16 - 0.442697 * v_2_17 - 0.464413 * v_2_18 + 0.256305 * v_2_19 + 0.111913 * v_2_20 + 0.150100 * v_2_21 + 0.445190 * v_2_22 + 0.425881 * v_2_23 + 0.424995 * v_2_24 - 0.546803 * v_2_25 + 0.245830 * v_2_26 - 0.977408 * v_2_27 + 0.384089 * v_2_28 - 0.025427 * v_2_29 + 0.104480 * v_2_30 - 0.388798 * v_2_31 - 0.079658);
-	let v_4_0 = sin(-0.571559 * v_3_0 + 0.438705 * v_3_1 + 0.201319 * v_3_2 + 0.182028 * v_3_3 - 0.326090 * v_3_4 - 0.289834 * v_3_5 - 0.689473 * v_3_6 - 0.023884 * v_3_7 - 0.304672 * v_3_8 + 0.064191 * v_3_9 - 0.059193 * v_3_10 - 0.215294 * v_3_11 - 0.425356 * v_3_12 - 0.277658 * v_3_13 + 0.107983 * v_3_14 - 0.340506 * v_3_15 - 0.473571 * v_3_16 - 0.675798 * v_3_17 + 0.169263 * v_3_18 + 0.202183 * v_3_19 + 0.252566 * v_3_20 - 0.011543 * v_3_21 - 0.180984 * v_3_22 + 0.596653 * v_3_23 + 0.433529 * v_3_24 + 0.365671 * v_3_25 - 0.067586 * v_3_26 - 0.444359 * v_3_27 - 0.428177 * v_3_28 - 0.385350 * v_3_29 + 0.527211 * v_3_30 - 0.142961 * v_3_31 - 0.920099);
-	let v_4_1 = sin(0.504748 * v_3_0 + 0.600211 * v_3_1 - 0.107590 * v_3_2 + 0.598666 * v_3_3 - 0.580578 * v_3_4 - 0.604105 * v_3_5 + 1.151244 * v_3_6 - 0.171267 * v_3_7 - 0.153127 * v_3_8 + 0.237632 * v_3_9 + 0.285394 * v_3_10 + 0.512471 * v_3_11 + 0.372023 * v_3_12 + 0.502538 * v_3_13 - 0.400125 * v_3_14 - 0.418891 * v_3_15 - 0.931063 * v_3_16 + 0.574050 * v_3_17 + 0.623133 * v_3_18 + 0.530161 * v_3_19 - 0.679785 * v_3_20 - 0.419067 * v_3_21 - 0.355224 * v_3_22 + 0.211547 * v_3_23 + 0.137363 * v_3_24 + 0.496762 * v_3_25 + 0.657111 * v_3_26 + 0.332835 * v_3_27 + 1.018951 * v_3_28 - 0.617343 * v_3_29 + 0.034239 * v_3_30 - 0.283576 * v_3_31 - 0.757003);
-	let v_4_2 = sin(0.209210 * v_3_0 + 0.449388 * v_3_1 - 0.121556 * v_3_2 - 0.587074 * v_3_3 + 0.104780 * v_3_4 + 0.279055 * v_3_5 + 0.255239 * v_3_6 - 0.451114 * v_3_7 - 0.478503 * v_3_8 - 0.180637 * v_3_9 - 0.358958 * v_3_10 + 0.147932 * v_3_11 - 0.550317 * v_3_12 + 0.189672 * v_3_13 - 0.280666 * v_3_14 - 0.291301 * v_3_15 + 0.193975 * v_3_16 - 0.487790 * v_3_17 - 0.188258 * v_3_18 + 0.010668 * v_3_19 - 0.330111 * v_3_20 + 0.373443 * v_3_21 - 0.127072 * v_3_22 + 0.475300 * v_3_23 + 0.379519 * v_3_24 - 0.127539 * v_3_25 + 0.146511 * v_3_26 - 0.394626 * v_3_27 - 0.039623 * v_3_28 - 0.082480 * v_3_29 + 0.356680 * v_3_30 + 0.152106 * v_3_31 - 0.288698);
-	let v_4_3 = sin(0.212159 * v_3_0 + 0.920140 * v_3_1 - 0.106153 * v_3_2 + 0.161865 * v_3_3 + 0.124029 * v_3_4 - 0.378030 * v_3_5 - 0.067245 * v_3_6 + 0.772615 * v_3_7 + 0.222764 * v_3_8 + 0.107594 * v_3_9 - 0.020384 * v_3_10 + 0.058801 * v_3_11 + 0.222531 * v_3_12 - 0.068318 * v_3_13 - 0.394535 * v_3_14 - 0.243419 * v_3_15 - 0.498067 * v_3_16 + 0.159490 * v_3_17 + 0.292033 * v_3_18 + 0.166433 * v_3_19 - 0.281807 * v_3_20 - 0.055960 * v_3_21 - 0.313426 * v_3_22 - 0.392064 * v_3_23 - 0.121620 * v_3_24 + 0.068165 * v_3_25 + 0.038994 * v_3_26 - 0.792122 * v_3_27 + 0.210265 * v_3_28 + 0.080172 * v_3_29 + 0.613385 * v_3_30 + 0.495597 * v_3_31 + 0.690557);
-	let v_4_4 = sin(0.148461 * v_3_0 - 0.053021 * v_3_1 - 0.171784 * v_3_2 - 0.296679 * v_3_3 + 0.510220 * v_3_4 + 0.261201 * v_3_5 + 0.223564 * v_3_6 + 0.151484 * v_3_7 - 0.249597 * v_3_8 - 0.125892 * v_3_9 - 0.406923 * v_3_10 - 0.570722 * v_3_11 - 0.354528 * v_3_12 + 0.063088 * v_3_13 - 0.185473 * v_3_14 - 0.100045 * v_3_15 - 0.255652 * v_3_16 + 0.074934 * v_3_17 - 0.205202 * v_3_18 - 0.494289 * v_3_19 + 0.227579 * v_3_20 + 0.340112 * v_3_21 + 0.498984 * v_3_22 - 0.256114 * v_3_23 + 0.383282 * v_3_24 + 0.071325 * v_3_25 + 0.302448 * v_3_26 + 0.125058 * v_3_27 + 0.054919 * v_3_28 + 0.005857 * v_3_29 - 0.064915 * v_3_30 + 0.458026 * v_3_31 + 0.218469);
-	let v_4_5 = sin(-0.192201 * v_3_0 - 0.162720 * v_3_1 - 0.298839 * v_3_2 - 0.240853 * v_3_3 - 0.615982 * v_3_4 + 0.252664 * v_3_5 + 0.717264 * v_3_6 - 0.622722 * v_3_7 + 0.192816 * v_3_8 + 0.182861 * v_3_9 + 0.263106 * v_3_10 + 0.722620 * v_3_11 - 0.297324 * v_3_12 + 0.814273 * v_3_13 - 0.446477 * v_3_14 - 0.371527 * v_3_15 - 0.503547 * v_3_16 - 0.366889 * v_3_17 + 0.575426 * v_3_18 + 0.089696 * v_3_19 - 0.241695 * v_3_20 - 0.258914 * v_3_21 - 0.169852 * v_3_22 + 0.450792 * v_3_23 - 0.590060 * v_3_24 - 0.482152 * v_3_25 - 0.181071 * v_3_26 + 0.124941 * v_3_27 + 0.356991 * v_3_28 - 0.123360 * v_3_29 + 0.099160 * v_3_30 + 0.499566 * v_3_31 - 0.464222);
-	let v_4_6 = sin(0.309157 * v_3_0 + 0.139336 * v_3_1 - 0.314888 * v_3_2 - 0.482597 * v_3_3 + 0.365415 * v_3_4 + 0.385920 * v_3_5 + 0.422050 * v_3_6 - 0.146188 * v_3_7 - 0.195839 * v_3_8 + 0.773806 * v_3_9 - 0.434886 * v_3_10 + 0.242350 * v_3_11 + 0.176203 * v_3_12 - 0.507147 * v_3_13 - 0.112639 * v_3_14 + 0.263051 * v_3_15 - 0.048526 * v_3_16 + 0.373561 * v_3_17 - 0.065187 * v_3_18 + 0.273526 * v_3_19 - 0.043822 * v_3_20 + 0.008951 * v_3_21 + 0.498202 * v_3_22 - 0.717591 * v_3_23 - 0.153244 * v_3_24 + 0.141669 * v_3_25 - 0.110608 * v_3_26 - 0.248324 * v_3_27 + 0.584430 * v_3_28 - 0.005545 * v_3_29 - 0.114135 * v_3_30 - 0.386154 * v_3_31 + 1.043961);
-	let v_4_7 = sin(-0.147622 * v_3_0 - 0.045143 * v_3_1 - 0.224859 * v_3_2 + 0.081554 * v_3_3 - 0.083712 * v_3_4 + 0.039065 * v_3_5 - 0.095214 * v_3_6 + 0.229177 * v_3_7 - 0.122612 * v_3_8 - 0.476197 * v_3_9 + 0.018940 * v_3_10 - 0.422144 * v_3_11 - 0.180901 * v_3_12 - 0.232807 * v_3_13 + 0.395347 * v_3_14 + 0.246314 * v_3_15 + 0.126383 * v_3_16 - 0.040592 * v_3_17 - 0.057904 * v_3_18 - 0.106125 * v_3_19 - 0.144584 * v_3_20 + 0.093944 * v_3_21 - 0.007298 * v_3_22 + 0.425719 * v_3_23 + 0.473104 * v_3_24 - 0.091039 * v_3_25 + 0.262894 * v_3_26 - 0.288551 * v_3_27 - 0.135959 * v_3_28 + 0.133988 * v_3_29 - 0.470873 * v_3_30 + 0.198942 * v_3_31 + 0.111274);
-	let v_4_8 = sin(-0.035907 * v_3_0 - 0.022631 * v_3_1 + 0.320658 * v_3_2 + 0.078848 * v_3_3 - 0.047437 * v_3_4 + 0.465427 * v_3_5 + 0.218618 * v_3_6 - 0.122955 * v_3_7 + 0.269572 * v_3_8 + 0.273800 * v_3_9 - 0.113631 * v_3_10 - 0.366341 * v_3_11 - 0.283310 * v_3_12 - 0.649311 * v_3_13 - 0.153890 * v_3_14 + 0.035289 * v_3_15 + 0.311799 * v_3_16 + 0.154693 * v_3_17 + 0.161857 * v_3_18 - 1.066773 * v_3_19 - 0.047771 * v_3_20 - 0.279985 * v_3_21 - 0.079120 * v_3_22 + 0.461113 * v_3_23 - 0.325444 * v_3_24 + 0.126811 * v_3_25 + 0.905368 * v_3_26 - 0.164007 * v_3_27 + 0.127585 * v_3_28 - 0.854088 * v_3_29 - 0.005991 * v_3_30 - 0.387432 * v_3_31 + 0.418954);
-	let v_4_9 = sin(0.025052 * v_3_0 + 0.020046 * v_3_1 - 0.118952 * v_3_2 - 0.057197 * v_3_3 - 0.144287 * v_3_4 + 0.043362 * v_3_5 - 0.312380 * v_3_6 + 0.576088 * v_3_7 + 0.155534 * v_3_8 + 0.270481 * v_3_9 + 0.482518 * v_3_10 + 0.344074 * v_3_11 + 0.214129 * v_3_12 - 0.035947 * v_3_13 + 0.407248 * v_3_14 - 0.299223 * v_3_15 - 0.279018 * v_3_16 + 0.151557 * v_3_17 - 0.126810 * v_3_18 + 1.200036 * v_3_19 + 0.154704 * v_3_20 + 0.205483 * v_3_21 - 0.123290 * v_3_22 + 0.013166 * v_3_23 - 0.105587 * v_3_24 + 0.205316 * v_3_25 + 0.640263 * v_3_26 + 0.516810 * v_3_27 - 0.341987 * v_3_28 - 0.280724 * v_3_29 + 0.369534 * v_3_30 + 0.110382 * v_3_31 - 0.092301);
-	let v_4_10 = sin(0.100023 * v_3_0 - 0.207599 * v_3_1 + 0.224011 * v_3_2 + 0.325382 * v_3_3 + 0.407927 * v_3_4 + 0.199761 * v_3_5 - 0.417823 * v_3_6 - 0.113724 * v_3_7 - 0.313526 * v_3_8 + 0.098825 * v_3_9 - 0.341005 * v_3_10 - 0.140144 * v_3_11 + 0.258624 * v_3_12 - 0.031038 * v_3_13 + 0.209418 * v_3_14 - 0.153442 * v_3_15 + 0.164237 * v_3_16 + 0.123341 * v_3_17 + 0.111275 * v_3_18 - 0.847223 * v_3_19 - 0.101539 * v_3_20 - 0.579190 * v_3_21 - 0.064835 * v_3_22 + 0.564083 * v_3_23 - 0.462698 * v_3_24 + 0.039677 * v_3_25 + 0.479771 * v_3_26 + 0.131403 * v_3_27 - 0.220762 * v_3_28 - 0.163964 * v_3_29 + 0.007474 * v_3_30 - 0.049667 * v_3_31 + 0.007561);
-	let v_4_11 = sin(-0.359387 * v_3_0 - 0.004362 * v_3_1 - 0.354085 * v_3_2 + 0.005643 * v_3_3 - 0.333431 * v_3_4 + 0.450914 * v_3_5 - 0.377434 * v_3_6 - 0.157894 * v_3_7 + 0.174011 * v_3_8 + 0.312377 * v_3_9 - 0.347326 * v_3_10 + 0.101516 * v_3_11 + 0.459110 * v_3_12 + 0.205816 * v_3_13 - 0.653735 * v_3_14 - 0.210405 * v_3_15 + 0.353158 * v_3_16 + 0.371624 * v_3_17 - 0.058637 * v_3_18 + 0.275034 * v_3_19 + 0.134476 * v_3_20 - 0.318975 * v_3_21 + 0.005510 * v_3_22 + 0.351913 * v_3_23 - 0.145062 * v_3_24 - 0.089319 * v_3_25 - 0.156092 * v_3_26 - 0.146287 * v_3_27 + 0.165169 * v_3_28 + 0.300594 * v_3_29 + 0.448372 * v_3_30 + 0.045698 * v_3_31 + 0.599127);
-	let v_4_12 = sin(0.560890 * v_3_0 + 0.075929 * v_3_1 - 0.284791 * v_3_2 + 0.030839 * v_3_3 - 0.080624 * v_3_4 - 0.592824 * v_3_5 - 0.005560 * v_3_6 - 0.377925 * v_3_7 + 0.564202 * v_3_8 + 0.027557 * v_3_9 - 0.325202 * v_3_10 + 0.227100 * v_3_11 - 0.283476 * v_3_12 - 0.319328 * v_3_13 - 0.134453 * v_3_14 + 0.129015 * v_3_15 - 0.428441 * v_3_16 - 0.476458 * v_3_17 + 0.042555 * v_3_18 - 0.547843 * v_3_19 - 0.021984 * v_3_20 - 0.693560 * v_3_21 - 0.438945 * v_3_22 + 1.002811 * v_3_23 - 0.591893 * v_3_24 + 0.258194 * v_3_25 + 0.627184 * v_3_26 - 0.089790 * v_3_27 - 0.155225 * v_3_28 - 0.481961 * v_3_29 + 0.338686 * v_3_30 - 0.178528 * v_3_31 - 0.143822);
-	let v_4_13 = sin(-0.293634 * v_3_0 - 0.108487 * v_3_1 + 0.054760 * v_3_2 - 0.070331 * v_3_3 + 0.116593 * v_3_4 - 0.215483 * v_3_5 - 0.568310 * v_3_6 - 0.035095 * v_3_7 + 0.002681 * v_3_8 + 0.008811 * v_3_9 - 0.025891 * v_3_10 + 0.246342 * v_3_11 + 0.237997 * v_3_12 - 0.307893 * v_3_13 + 0.040921 * v_3_14 - 0.373119 * v_3_15 + 0.426803 * v_3_16 - 0.324373 * v_3_17 - 0.246117 * v_3_18 - 0.299904 * v_3_19 + 0.379273 * v_3_20 + 0.197878 * v_3_21 - 0.092858 * v_3_22 + 0.541551 * v_3_23 - 0.078169 * v_3_24 + 0.012087 * v_3_25 - 0.414955 * v_3_26 + 0.068126 * v_3_27 - 0.022755 * v_3_28 + 0.426296 * v_3_29 + 0.144307 * v_3_30 + 0.037047 * v_3_31 + 0.128824);
-	let v_4_14 = sin(0.443908 * v_3_0 - 0.187016 * v_3_1 - 0.094228 * v_3_2 + 0.208479 * v_3_3 + 0.319578 * v_3_4 + 0.002576 * v_3_5 - 0.462441 * v_3_6 + 0.602768 * v_3_7 - 0.144256 * v_3_8 - 0.073779 * v_3_9 - 0.435826 * v_3_10 - 0.277268 * v_3_11 + 0.428604 * v_3_12 - 0.442688 * v_3_13 - 0.310432 * v_3_14 - 0.624205 * v_3_15 - 0.359954 * v_3_16 + 0.096184 * v_3_17 + 0.324806 * v_3_18 - 0.308828 * v_3_19 - 0.589673 * v_3_20 + 0.782076 * v_3_21 - 0.000670 * v_3_22 - 0.336700 * v_3_23 + 0.001329 * v_3_24 - 0.387991 * v_3_25 + 0.672694 * v_3_26 + 0.223986 * v_3_27 + 0.582568 * v_3_28 + 0.904341 * v_3_29 + 0.080328 * v_3_30 + 0.046495 * v_3_31 + 0.710578);
-	let v_4_15 = sin(0.775349 * v_3_0 + 0.352723 * v_3_1 - 0.267100 * v_3_2 - 0.452086 * v_3_3 - 0.626324 * v_3_4 + 0.463027 * v_3_5 + 0.609164 * v_3_6 + 0.727686 * v_3_7 + 0.435902 * v_3_8 + 0.004419 * v_3_9 - 0.074347 * v_3_10 + 0.410679 * v_3_11 - 0.756364 * v_3_12 - 0.110440 * v_3_13 - 0.102495 * v_3_14 + 0.306805 * v_3_15 + 0.210614 * v_3_16 + 0.375536 * v_3_17 + 0.200279 * v_3_18 - 0.024681 * v_3_19 - 0.016930 * v_3_20 + 0.182688 * v_3_21 - 0.805691 * v_3_22 + 0.288708 * v_3_23 - 0.087068 * v_3_24 - 0.164841 * v_3_25 - 1.327335 * v_3_26 + 0.127764 * v_3_27 + 0.184330 * v_3_28 - 0.308814 * v_3_29 + 0.010807 * v_3_30 - 0.692230 * v_3_31 - 0.772767);
-	let v_4_16 = sin(-0.352713 * v_3_0 + 0.387909 * v_3_1 + 0.504083 * v_3_2 + 0.041123 * v_3_3 + 0.438998 * v_3_4 + 0.848681 * v_3_5 - 0.451698 * v_3_6 + 0.259511 * v_3_7 - 0.483189 * v_3_8 + 0.611967 * v_3_9 - 0.341352 * v_3_10 - 0.576080 * v_3_11 + 0.217961 * v_3_12 + 0.225514 * v_3_13 + 0.310329 * v_3_14 + 0.453927 * v_3_15 + 0.685696 * v_3_16 + 0.027456 * v_3_17 - 0.258119 * v_3_18 - 0.702323 * v_3_19 + 0.088730 * v_3_20 - 0.121434 * v_3_21 + 0.320523 * v_3_22 - 0.370833 * v_3_23 + 0.628063 * v_3_24 - 0.439456 * v_3_25 - 0.732444 * v_3_26 - 0.130912 * v_3_27 + 0.184162 * v_3_28 + 1.092367 * v_3_29 + 0.407462 * v_3_30 - 0.276049 * v_3_31 + 0.594396);
-	let v_4_17 = sin(0.301151 * v_3_0 + 0.324008 * v_3_1 + 0.157596 * v_3_2 - 0.642753 * v_3_3 - 0.080591 * v_3_4 + 0.902693 * v_3_5 - 0.386508 * v_3_6 - 0.101455 * v_3_7 + 0.042863 * v_3_8 - 0.026121 * v_3_9 - 0.112485 * v_3_10 + 0.643474 * v_3_11 + 0.311381 * v_3_12 - 0.137681 * v_3_13 - 0.395680 * v_3_14 - 0.039078 * v_3_15 + 0.200625 * v_3_16 - 0.079026 * v_3_17 - 0.303301 * v_3_18 + 0.553255 * v_3_19 - 0.146351 * v_3_20 + 0.519484 * v_3_21 + 0.371622 * v_3_22 + 0.036179 * v_3_23 - 0.478186 * v_3_24 + 0.435510 * v_3_25 - 0.349775 * v_3_26 - 0.845436 * v_3_27 - 0.054527 * v_3_28 - 0.639064 * v_3_29 + 0.661410 * v_3_30 - 0.076823 * v_3_31 + 0.136226);
-	let v_4_18 = sin(-0.852354 * v_3_0 - 0.412441 * v_3_1 + 0.404658 * v_3_2 - 0.106473 * v_3_3 + 0.828316 * v_3_4 - 0.531861 * v_3_5 - 0.013306 * v_3_6 - 0.529181 * v_3_7 - 0.366080 * v_3_8 - 0.990129 * v_3_9 + 0.572143 * v_3_10 - 0.791271 * v_3_11 - 1.231474 * v_3_12 - 0.330439 * v_3_13 + 1.068714 * v_3_14 + 0.443148 * v_3_15 - 0.034137 * v_3_16 + 0.104787 * v_3_17 + 0.258372 * v_3_18 + 0.190831 * v_3_19 - 0.018284 * v_3_20 - 0.428549 * v_3_21 - 0.164926 * v_3_22 - 0.356911 * v_3_23 + 1.108481 * v_3_24 - 0.547443 * v_3_25 + 0.036236 * v_3_26 + 0.181041 * v_3_27 + 0.139128 * v_3_28 - 0.095384 * v_3_29 - 0.125639 * v_3_30 + 0.290048 * v_3_31 - 0.213286);
-	let v_4_19 = sin(0.271632 * v_3_0 - 0.063822 * v_3_1 + 0.530696 * v_3_2 - 0.616548 * v_3_3 + 0.139064 * v_3_4 - 0.280258 * v_3_5 + 0.595592 * v_3_6 - 0.331834 * v_3_7 - 0.020354 * v_3_8 - 0.508807 * v_3_9 - 0.046492 * v_3_10 + 0.214053 * v_3_11 + 0.730174 * v_3_12 - 0.462571 * v_3_13 - 0.635753 * v_3_14 - 0.348360 * v_3_15 + 0.454239 * v_3_16 - 0.397661 * v_3_17 - 0.816497 * v_3_18 - 0.008603 * v_3_19 + 0.450900 * v_3_20 - 0.019060 * v_3_21 - 0.702483 * v_3_22 - 0.402472 * v_3_23 + 0.360832 * v_3_24 - 0.411889 * v_3_25 + 0.770772 * v_3_26 + 0.001035 * v_3_27 - 0.221365 * v_3_28 + 0.715157 * v_3_29 - 0.212585 * v_3_30 - 0.050727 * v_3_31 - 1.555241);
-	let v_4_20 = sin(0.003071 * v_3_0 + 0.267394 * v_3_1 + 0.771288 * v_3_2 + 0.022946 * v_3_3 + 0.060090 * v_3_4 - 0.000086 * v_3_5 + 0.344559 * v_3_6 - 0.259225 * v_3_7 - 0.384924 * v_3_8 + 0.147319 * v_3_9 + 0.181250 * v_3_10 - 0.030808 * v_3_11 + 0.248828 * v_3_12 - 0.056787 * v_3_13 + 0.104683 * v_3_14 + 0.000080 * v_3_15 - 0.468884 * v_3_16 + 0.066750 * v_3_17 + 0.358944 * v_3_18 - 0.079281 * v_3_19 - 0.270633 * v_3_20 + 0.266167 * v_3_21 - 0.241617 * v_3_22 - 0.353313 * v_3_23 + 0.059945 * v_3_24 + 0.513527 * v_3_25 - 0.431180 * v_3_26 + 0.505815 * v_3_27 - 0.104030 * v_3_28 + 0.075062 * v_3_29 + 0.005112 * v_3_30 - 0.509342 * v_3_31 - 0.481930);
-	let v_4_21 = sin(-0.216535 * v_3_0 - 0.273451 * v_3_1 + 0.280766 * v_3_2 + 0.164264 * v_3_3 - 0.011042 * v_3_4 - 0.277657 * v_3_5 - 0.361387 * v_3_6 - 0.001802 * v_3_7 - 0.084543 * v_3_8 - 0.207899 * v_3_9 - 0.071821 * v_3_10 + 0.649000 * v_3_11 + 0.378407 * v_3_12 - 0.261831 * v_3_13 + 0.391114 * v_3_14 - 0.577726 * v_3_15 - 0.233140 * v_3_16 - 0.076271 * v_3_17 - 0.155296 * v_3_18 + 0.314925 * v_3_19 + 0.292984 * v_3_20 + 0.440882 * v_3_21 - 0.148816 * v_3_22 + 0.172176 * v_3_23 + 0.189721 * v_3_24 - 0.225051 * v_3_25 + 1.092200 * v_3_26 - 0.998896 * v_3_27 - 0.150850 * v_3_28 + 0.435754 * v_3_29 + 0.401281 * v_3_30 + 0.063959 * v_3_31 - 0.737140);
-	let v_4_22 = sin(-0.372299 * v_3_0 + 0.097449 * v_3_1 - 0.113378 * v_3_2 - 0.308545 * v_3_3 - 0.386453 * v_3_4 - 0.017519 * v_3_5 - 0.292922 * v_3_6 - 0.224718 * v_3_7 + 0.089356 * v_3_8 - 0.399307 * v_3_9 + 0.028265 * v_3_10 - 0.213229 * v_3_11 + 0.281252 * v_3_12 + 0.071371 * v_3_13 - 0.325254 * v_3_14 - 0.124281 * v_3_15 + 0.138921 * v_3_16 + 0.301592 * v_3_17 - 0.230671 * v_3_18 - 0.832860 * v_3_19 - 0.111055 * v_3_20 + 0.002461 * v_3_21 + 0.133179 * v_3_22 + 0.100718 * v_3_23 + 0.396903 * v_3_24 - 0.101362 * v_3_25 - 0.266263 * v_3_26 + 0.437671 * v_3_27 - 0.032698 * v_3_28 + 0.299342 * v_3_29 - 0.172018 * v_3_30 - 0.167813 * v_3_31 + 0.369952);
-	let v_4_23 = sin(0.230180 * v_3_0 - 0.340178 * v_3_1 - 0.308790 * v_3_2 - 0.036140 * v_3_3 + 0.817300 * v_3_4 - 0.685719 * v_3_5 - 0.561541 * v_3_6 + 0.125072 * v_3_7 + 0.549687 * v_3_8 + 0.244505 * v_3_9 - 0.617030 * v_3_10 - 0.079645 * v_3_11 + 0.033465 * v_3_12 - 0.286174 * v_3_13 + 0.083305 * v_3_14 + 0.220316 * v_3_15 + 0.625870 * v_3_16 + 0.325054 * v_3_17 - 0.732909 * v_3_18 - 0.015962 * v_3_19 + 0.080845 * v_3_20 - 0.648980 * v_3_21 + 0.961395 * v_3_22 - 0.119591 * v_3_23 - 0.410073 * v_3_24 - 0.064623 * v_3_25 - 0.252714 * v_3_26 + 0.146440 * v_3_27 - 0.020246 * v_3_28 - 0.132280 * v_3_29 + 0.146662 * v_3_30 - 0.079297 * v_3_31 + 0.637144);
-	let v_5_0 = sin(0.549875 * v_4_0 - 0.140348 * v_4_1 - 0.196942 * v_4_2 + 0.429229 * v_4_3 - 0.337985 * v_4_4 - 0.099592 * v_4_5 + 0.194323 * v_4_6 - 0.690726 * v_4_7 - 0.292019 * v_4_8 + 0.873068 * v_4_9 - 0.026534 * v_4_10 + 0.487437 * v_4_11 - 0.283585 * v_4_12 + 0.153546 * v_4_13 - 0.019734 * v_4_14 - 0.151849 * v_4_15 + 0.181243 * v_4_16 + 0.048763 * v_4_17 - 0.146648 * v_4_18 - 0.136170 * v_4_19 - 0.230742 * v_4_20 + 0.113008 * v_4_21 - 0.116004 * v_4_22 + 0.059970 * v_4_23 + 0.032420);
-	let v_5_1 = sin(0.566636 * v_4_0 - 0.539992 * v_4_1 + 0.171416 * v_4_2 + 0.162311 * v_4_3 - 1.089966 * v_4_4 + 0.224320 * v_4_5 - 0.273488 * v_4_6 + 0.180602 * v_4_7 - 0.120958 * v_4_8 - 0.278350 * v_4_9 - 0.417433 * v_4_10 + 0.268729 * v_4_11 + 0.188335 * v_4_12 - 0.205185 * v_4_13 + 0.086405 * v_4_14 - 0.354127 * v_4_15 - 0.328805 * v_4_16 - 0.171805 * v_4_17 - 0.078426 * v_4_18 - 0.393350 * v_4_19 + 0.531168 * v_4_20 + 0.170612 * v_4_21 + 0.212440 * v_4_22 - 0.300232 * v_4_23 + 0.073984);
-	let v_5_2 = sin(-0.193289 * v_4_0 - 0.368781 * v_4_1 - 0.343266 * v_4_2 + 0.172442 * v_4_3 - 0.807722 * v_4_4 - 0.263345 * v_4_5 + 0.390984 * v_4_6 + 0.352392 * v_4_7 - 0.681360 * v_4_8 + 0.090634 * v_4_9 + 0.608460 * v_4_10 + 0.209887 * v_4_11 - 0.967029 * v_4_12 + 0.156044 * v_4_13 - 0.277708 * v_4_14 + 0.049351 * v_4_15 + 0.127598 * v_4_16 - 0.194637 * v_4_17 - 0.390528 * v_4_18 + 0.100272 * v_4_19 + 0.179240 * v_4_20 - 0.205631 * v_4_21 + 0.140386 * v_4_22 + 0.525021 * v_4_23 + 0.814595);
-	let v_5_3 = sin(0.556753 * v_4_0 + 0.086924 * v_4_1 - 0.418854 * v_4_2 + 0.026509 * v_4_3 + 0.327401 * v_4_4 + 0.074943 * v_4_5 - 0.535276 * v_4_6 - 0.174954 * v_4_7 + 0.232611 * v_4_8 + 0.193135 * v_4_9 - 0.031852 * v_4_10 - 0.549535 * v_4_11 + 0.045022 * v_4_12 - 0.243306 * v_4_13 + 0.163552 * v_4_14 + 0.117086 * v_4_15 - 0.399583 * v_4_16 + 0.522662 * v_4_17 + 0.266244 * v_4_18 + 0.032837 * v_4_19 + 0.445903 * v_4_20 + 0.197549 * v_4_21 + 0.289618 * v_4_22 - 0.026379 * v_4_23 - 0.497240);
-	let v_5_4 = sin(-0.285926 * v_4_0 + 0.182659 * v_4_1 - 0.560705 * v_4_2 - 0.023633 * v_4_3 + 0.042300 * v_4_4 - 0.361925 * v_4_5 - 0.038607 * v_4_6 + 0.174578 * v_4_7 + 0.484496 * v_4_8 + 0.551196 * v_4_9 - 0.525952 * v_4_10 + 0.240062 * v_4_11 - 0.185572 * v_4_12 + 1.181642 * v_4_13 - 0.231475 * v_4_14 + 0.123761 * v_4_15 + 0.017643 * v_4_16 + 0.585665 * v_4_17 - 0.092486 * v_4_18 + 0.437144 * v_4_19 + 0.331187 * v_4_20 + 0.486897 * v_4_21 - 0.955769 * v_4_22 + 0.537915 * v_4_23 + 0.186993);
-	let v_5_5 = sin(0.208218 * v_4_0 - 0.142253 * v_4_1 - 0.446791 * v_4_2 + 0.102349 * v_4_3 + 0.143475 * v_4_4 - 0.332830 * v_4_5 + 0.164841 * v_4_6 - 0.128953 * v_4_7 + 0.142046 * v_4_8 + 0.280122 * v_4_9 + 0.113214 * v_4_10 + 0.639263 * v_4_11 - 0.100701 * v_4_12 + 0.245500 * v_4_13 + 0.316454 * v_4_14 - 0.131605 * v_4_15 + 0.487086 * v_4_16 + 0.141977 * v_4_17 - 0.199768 * v_4_18 - 0.032760 * v_4_19 - 0.157363 * v_4_20 - 0.113420 * v_4_21 + 0.634704 * v_4_22 - 0.150535 * v_4_23 + 0.521799);
-	let v_5_6 = sin(-0.423742 * v_4_0 - 0.524954 * v_4_1 - 0.925064 * v_4_2 + 0.152475 * v_4_3 + 0.575329 * v_4_4 - 0.469397 * v_4_5 + 0.064019 * v_4_6 + 0.659530 * v_4_7 + 0.158570 * v_4_8 + 0.060260 * v_4_9 + 0.164322 * v_4_10 + 0.170212 * v_4_11 + 0.147246 * v_4_12 + 0.111810 * v_4_13 + 0.068580 * v_4_14 - 0.436919 * v_4_15 - 0.208029 * v_4_16 + 0.234146 * v_4_17 - 0.121219 * v_4_18 - 0.055657 * v_4_19 + 0.098372 * v_4_20 + 0.418185 * v_4_21 + 0.107728 * v_4_22 + 0.517681 * v_4_23 + 0.059065);
-	let v_5_7 = sin(0.124332 * v_4_0 + 0.007902 * v_4_1 - 0.078393 * v_4_2 - 0.413229 * v_4_3 - 0.020928 * v_4_4 + 0.359961 * v_4_5 - 0.721530 * v_4_6 - 0.223597 * v_4_7 + 0.040305 * v_4_8 - 0.162146 * v_4_9 - 0.934388 * v_4_10 + 0.553820 * v_4_11 - 0.434025 * v_4_12 + 0.698819 * v_4_13 - 0.309185 * v_4_14 - 0.274982 * v_4_15 - 0.458637 * v_4_16 - 0.286257 * v_4_17 + 0.026852 * v_4_18 + 0.498259 * v_4_19 - 0.085958 * v_4_20 + 0.315931 * v_4_21 + 0.911219 * v_4_22 + 0.268918 * v_4_23 + 0.956378);
-	let v_5_8 = sin(0.669952 * v_4_0 - 0.353317 * v_4_1 - 0.231480 * v_4_2 + 0.055407 * v_4_3 - 0.521107 * v_4_4 - 0.065777 * v_4_5 - 0.120712 * v_4_6 + 0.867789 * v_4_7 + 0.298618 * v_4_8 - 0.078688 * v_4_9 - 0.244543 * v_4_10 - 0.314191 * v_4_11 + 0.879704 * v_4_12 + 0.844619 * v_4_13 - 0.642415 * v_4_14 + 0.026264 * v_4_15 - 0.539276 * v_4_16 + 0.446598 * v_4_17 + 0.388116 * v_4_18 + 0.087696 * v_4_19 - 0.063569 * v_4_20 + 0.449695 * v_4_21 - 0.115034 * v_4_22 - 0.422239 * v_4_23 - 1.551086);
-	let v_5_9 = sin(-0.400464 * v_4_0 - 0.097822 * v_4_1 + 0.159505 * v_4_2 + 0.485839 * v_4_3 - 0.122942 * v_4_4 - 0.262259 * v_4_5 + 0.299447 * v_4_6 - 0.001073 * v_4_7 + 0.310423 * v_4_8 - 0.270933 * v_4_9 + 0.012910 * v_4_10 + 0.089524 * v_4_11 + 0.182323 * v_4_12 + 0.259360 * v_4_13 + 0.230278 * v_4_14 - 0.391449 * v_4_15 + 0.385416 * v_4_16 + 0.257430 * v_4_17 + 0.532602 * v_4_18 + 0.404767 * v_4_19 + 0.140718 * v_4_20 + 0.019511 * v_4_21 + 0.499167 * v_4_22 + 0.593705 * v_4_23 + 0.331548);
-	let v_5_10 = sin(-0.647545 * v_4_0 + 0.280986 * v_4_1 + 0.143665 * v_4_2 + 0.723811 * v_4_3 - 0.660766 * v_4_4 + 0.263426 * v_4_5 - 0.023747 * v_4_6 + 0.377754 * v_4_7 + 0.489540 * v_4_8 + 0.470520 * v_4_9 - 0.350906 * v_4_10 + 0.359063 * v_4_11 + 0.017764 * v_4_12 + 0.046126 * v_4_13 - 0.520797 * v_4_14 + 0.136845 * v_4_15 - 0.113337 * v_4_16 + 0.036906 * v_4_17 + 0.166717 * v_4_18 - 0.466966 * v_4_19 + 0.274036 * v_4_20 + 0.424980 * v_4_21 - 0.018774 * v_4_22 - 0.227427 * v_4_23 - 0.861850);
-	let v_5_11 = sin(-0.172001 * v_4_0 + 0.017935 * v_4_1 + 0.094448 * v_4_2 - 0.584456 * v_4_3 + 0.394043 * v_4_4 - 0.410398 * v_4_5 + 0.161138 * v_4_6 - 0.184249 * v_4_7 + 0.208574 * v_4_8 - 0.486918 * v_4_9 + 0.170267 * v_4_10 + 0.418752 * v_4_11 + 0.729323 * v_4_12 + 0.008940 * v_4_13 - 0.357256 * v_4_14 - 0.182652 * v_4_15 - 0.271981 * v_4_16 - 0.345371 * v_4_17 + 0.193327 * v_4_18 + 0.216240 * v_4_19 - 0.494330 * v_4_20 + 0.680495 * v_4_21 - 0.221019 * v_4_22 + 0.177594 * v_4_23 - 0.329187);
-	let v_5_12 = sin(-0.668715 * v_4_0 + 0.381641 * v_4_1 + 0.340600 * v_4_2 - 0.448269 * v_4_3 - 0.572357 * v_4_4 - 0.265598 * v_4_5 + 0.030747 * v_4_6 + 0.385711 * v_4_7 + 0.219372 * v_4_8 - 0.253492 * v_4_9 + 0.271556 * v_4_10 + 1.172294 * v_4_11 - 0.228060 * v_4_12 + 0.584268 * v_4_13 - 0.377725 * v_4_14 + 0.127909 * v_4_15 - 0.258753 * v_4_16 - 0.101764 * v_4_17 + 0.019037 * v_4_18 + 0.110701 * v_4_19 + 0.268016 * v_4_20 + 0.032762 * v_4_21 + 0.217474 * v_4_22 - 0.174669 * v_4_23 - 0.408489);
-	let v_5_13 = sin(-0.111889 * v_4_0 - 0.396159 * v_4_1 - 0.084957 * v_4_2 + 0.420377 * v_4_3 - 0.077202 * v_4_4 + 0.062626 * v_4_5 + 0.264598 * v_4_6 + 0.241241 * v_4_7 - 0.607922 * v_4_8 + 0.479132 * v_4_9 + 0.445743 * v_4_10 - 0.119163 * v_4_11 + 0.679398 * v_4_12 + 0.103163 * v_4_13 + 0.521198 * v_4_14 + 0.068613 * v_4_15 - 0.152857 * v_4_16 + 0.120004 * v_4_17 - 0.028197 * v_4_18 - 0.385399 * v_4_19 + 0.163577 * v_4_20 - 0.563562 * v_4_21 - 1.094819 * v_4_22 + 0.528318 * v_4_23 + 0.363983);
-	let v_5_14 = sin(-0.161046 * v_4_0 + 0.214544 * v_4_1 - 0.056052 * v_4_2 + 0.361705 * v_4_3 - 0.247744 * v_4_4 - 0.015336 * v_4_5 - 0.287180 * v_4_6 - 0.598205 * v_4_7 - 0.067697 * v_4_8 - 0.010427 * v_4_9 - 0.897574 * v_4_10 + 0.115635 * v_4_11 - 0.265180 * v_4_12 - 0.949500 * v_4_13 + 0.081761 * v_4_14 - 0.172782 * v_4_15 + 0.046635 * v_4_16 + 0.203557 * v_4_17 + 0.111819 * v_4_18 + 0.161629 * v_4_19 + 0.840430 * v_4_20 + 0.273948 * v_4_21 + 0.035463 * v_4_22 - 0.018570 * v_4_23 + 0.343111);
-	let v_5_15 = sin(-0.043873 * v_4_0 + 0.304696 * v_4_1 + 0.238971 * v_4_2 + 0.047390 * v_4_3 - 0.313457 * v_4_4 + 0.129291 * v_4_5 + 0.050922 * v_4_6 - 0.116201 * v_4_7 - 0.206470 * v_4_8 + 0.227885 * v_4_9 - 0.126115 * v_4_10 - 0.597892 * v_4_11 - 0.272811 * v_4_12 + 1.199262 * v_4_13 - 0.093775 * v_4_14 - 0.147444 * v_4_15 - 0.114427 * v_4_16 - 0.135636 * v_4_17 - 0.272815 * v_4_18 + 0.148468 * v_4_19 + 1.040073 * v_4_20 + 0.304727 * v_4_21 - 0.640896 * v_4_22 + 0.059150 * v_4_23 - 0.145530);
-	var v_6_0 = 0.5 + (0.048904 * v_5_0 + 0.028878 * v_5_1 - 0.033498 * v_5_2 + 0.040516 * v_5_3 - 0.024041 * v_5_4 - 0.067716 * v_5_5 - 0.045497 * v_5_6 - 0.027565 * v_5_7 + 0.021977 * v_5_8 - 0.029499 * v_5_9 + 0.034521 * v_5_10 - 0.051241 * v_5_11 - 0.029079 * v_5_12 + 0.031881 * v_5_13 + 0.039989 * v_5_14 + 0.029940 * v_5_15 - 0.020651);
-	v_6_0 += 0.05 * noise(200.0 * fc / res.x);
-    v_6_0 += 0.05 * noise(400.0 * fc / res.x);
-    return sRGBtoLinear(v_6_0);
-}
-
-@compute @workgroup_size(16, 16)
-#dispatch_once initialization
-fn initialization(@builtin(global_invocation_id) gid: vec3<u32>) {
-    let res = textureDimensions(screen);
-	if (gid.x >= res.x || gid.y >= res.y) { return; }
-    let fc = vec2<f32>(gid.xy) + .5;
-    let dx = vec2<f32>(sin(f32(time.elapsed)) * f32(res.x) * .2, 0.);
-    canvas[gid.x + gid.y * res.x] = lenna(fc + dx, vec2<f32>(res));
-}
-
-@compute @workgroup_size(16, 16)
-fn main_image(@builtin(global_invocation_id) gid: vec3<u32>) {
-    let res = textureDimensions(screen);
-    let col = vec3<f32>(canvas[gid.x + gid.y * res.x]);
-    textureStore(screen, vec2<u32>(gid.x, res.y - 1u - gid.y), vec4<f32>(col, 1.));
-}
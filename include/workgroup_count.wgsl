@@ -1,28 +0,0 @@
-// This is a bit of a contrived example, setting the number of workgroups is more useful for when you want the number of threads dispatched to match up with a buffer that's larger than the viewport.
-
-// Render a 128x256 pixel rectangle
-#workgroup_count main_image 8 16 1
-
-@compute @workgroup_size(16, 16)
-fn main_image(@builtin(global_invocation_id) id: uint3) {
-    // Viewport resolution (in pixels)
-    let screen_size = uint2(textureDimensions(screen));
-
-    // Prevent overdraw for workgroups on the edge of the viewport
-    if (id.x >= screen_size.x || id.y >= screen_size.y) { return; }
-
-    // Pixel coordinates (centre of pixel, origin at bottom left)
-    let fragCoord = float2(float(id.x) + .5, float(screen_size.y - id.y) - .5);
-
-    // Normalised pixel coordinates (from 0 to 1)
-    let uv = fragCoord / float2(screen_size);
-
-    // Time varying pixel colour
-    var col = .5 + .5 * cos(time.elapsed + uv.xyx + float3(0.,2.,4.));
-
-    // Convert from gamma-encoded to linear colour space
-    col = pow(col, float3(2.2));
-
-    // Output to screen (linear colour space)
-    textureStore(screen, int2(id.xy), float4(col, 1.));
-}
@@ -1,14 +1,19 @@
-use clap::{Parser, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::fmt;
 use std::path::PathBuf;
 
 /// CLI arguments for the codeskew tool
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct Cli {
+    /// Maintenance commands; without one, `input` is rendered
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Input code file to render
     #[arg(required = true)]
-    pub input: PathBuf,
+    pub input: Option<PathBuf>,
 
     /// Output image file
     #[arg(short, long, default_value = "output.png")]
@@ -26,10 +31,14 @@ pub struct Cli {
     #[arg(short = 'H', long, default_value_t = 800)]
     pub height: u32,
 
-    /// Font to use for rendering
+    /// Font family to use for rendering (system font or Nerd Font name)
     #[arg(short, long, default_value = "FiraCode Nerd Font Mono")]
     pub font: String,
 
+    /// Load the font from a TTF/OTF file instead of looking the family up
+    #[arg(long, value_name = "PATH")]
+    pub font_file: Option<PathBuf>,
+
     /// Font size
     #[arg(short = 's', long, default_value_t = 14.0)]
     pub fontsize: f32,
@@ -58,21 +67,35 @@ pub struct Cli {
     #[arg(short, long, default_value_t = 0.5)]
     pub depth: f32,
 
-    /// Perspective distance for 3D effect
+    /// Camera distance in pixels for the 3D effect
     #[arg(short = 'p', long, default_value_t = 1000.0)]
     pub perspective: f32,
 
-    /// Apply blur effect (blur amount)
+    /// Depth-of-field blur radius in pixels: text away from the focus goes soft
     #[arg(short, long, default_value_t = 0.0)]
     pub blur: f32,
 
-    /// Start gradient color (hex)
-    #[arg(long, default_value = "#1a1a1a")]
-    pub gradient_start: String,
+    /// Row of the rendered code, counting from 1, that --blur keeps sharp
+    #[arg(long, value_name = "LINE")]
+    pub focus_line: Option<usize>,
+
+    /// Depth that --blur keeps sharp, 1 being flat text at the center of the screen
+    /// [default: the depth of --focus-line, or of the fold point]
+    #[arg(long, value_name = "DEPTH")]
+    pub focus_depth: Option<f32>,
 
-    /// End gradient color (hex)
-    #[arg(long, default_value = "#4a4a4a")]
-    pub gradient_end: String,
+    /// Background gradient, e.g. "linear(135deg, #0f2027, #203a43 40%, #2c5364)",
+    /// "radial(at 30% 40%, navy, black)", "conic(from 90deg, magma)" or "viridis"
+    #[arg(long, value_name = "SPEC")]
+    pub gradient: Option<String>,
+
+    /// Start color of a diagonal background gradient [default: #1a1a1a]
+    #[arg(long, conflicts_with = "gradient")]
+    pub gradient_start: Option<String>,
+
+    /// End color of a diagonal background gradient [default: #4a4a4a]
+    #[arg(long, conflicts_with = "gradient")]
+    pub gradient_end: Option<String>,
 
     /// Enable animation (for GIF output)
     #[arg(short, long, default_value_t = false)]
@@ -98,10 +121,46 @@ pub struct Cli {
     #[arg(long, default_value_t = 30.0)]
     pub fps: f32,
 
-    /// Background shader for the composite renderer
+    /// Shader time in seconds at which still images are captured
+    #[arg(long, default_value_t = 0.0, value_name = "SECONDS")]
+    pub time: f32,
+
+    /// Simulate this many frames (at --fps) before capturing a still, so feedback shaders settle
+    #[arg(long, default_value_t = 0, value_name = "FRAMES")]
+    pub warmup_frames: u32,
+
+    /// Average this many frames into a still image for progressive (path-traced) shaders
+    #[arg(long, default_value_t = 1, value_name = "FRAMES")]
+    pub accumulate: u32,
+
+    /// Exit with an error when a shader `#assert` fails on a rendered frame
+    #[arg(long, default_value_t = false)]
+    pub fail_on_assert: bool,
+
+    /// Print the records shaders write with `debugLog(id, value)` after each rendered frame
+    #[arg(long, default_value_t = false)]
+    pub shader_debug: bool,
+
+    /// Write the shader `debugLog` records to this JSON file
+    #[arg(long, value_name = "PATH")]
+    pub shader_debug_json: Option<PathBuf>,
+
+    /// Background shader for the composite renderer, e.g. `bandwidth` or `davidar/caustics`
     #[arg(long, default_value = "bandwidth")]
     pub shader: String,
 
+    /// Directory searched for shaders before $CODESKEW_SHADER_PATH and the built-in library (repeatable)
+    #[arg(long = "shader-dir", value_name = "DIR")]
+    pub shader_dirs: Vec<PathBuf>,
+
+    /// Set a shader parameter, read in WGSL as `custom.<name>` (repeatable)
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = parse_param)]
+    pub params: Vec<(String, f32)>,
+
+    /// Configuration file (YAML) with a `params:` map of shader parameters
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
     /// Launch live animated preview window (shorthand for --format wgpu)
     #[arg(short = 'L', long, default_value_t = false)]
     pub live: bool,
@@ -110,6 +169,10 @@ pub struct Cli {
     #[arg(long, default_value_t = 0.4)]
     pub fold: f32,
 
+    /// Fraction of the height where the fold makes text largest
+    #[arg(long, default_value_t = 0.67)]
+    pub fold_point: f32,
+
     /// Text skew angle for 3D perspective effect
     #[arg(long, default_value_t = 0.15)]
     pub skew_angle: f32,
@@ -117,6 +180,246 @@ pub struct Cli {
     /// Overall 3D scale factor for perspective effects
     #[arg(long, default_value_t = 0.6)]
     pub scale: f32,
+
+    /// Camera yaw in degrees: positive turns the right edge of the text away
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    pub yaw: f32,
+
+    /// Camera pitch in degrees: positive tips the top of the text toward the viewer
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    pub pitch: f32,
+
+    /// Camera roll in degrees, counterclockwise
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    pub roll: f32,
+
+    /// Point of the text in the middle of the view, as fractions of its width and height
+    #[arg(long, value_name = "X,Y", default_value = "0.5,0.5", value_parser = parse_look_at, allow_hyphen_values = true)]
+    pub look_at: (f32, f32),
+
+    /// Draw the code on 3D geometry with a raster pass instead of in the shader
+    #[arg(long, value_enum)]
+    pub surface: Option<Surface>,
+
+    /// Vertical field of view in degrees [default: text shown pixel for pixel at --perspective]
+    #[arg(long, value_name = "DEGREES")]
+    pub fov: Option<f32>,
+
+    /// GPU backend selection (cpu forces a software rasterizer such as lavapipe/llvmpipe)
+    #[arg(long, value_enum, default_value_t = GpuBackend::Auto)]
+    pub backend: GpuBackend,
+
+    /// Number of colors in the GIF palette
+    #[arg(long, default_value_t = 256, value_parser = clap::value_parser!(u16).range(2..=256))]
+    pub gif_colors: u16,
+
+    /// GIF palette strategy: one palette for the whole animation or one per frame
+    #[arg(long, value_enum, default_value_t = GifPalette::Global)]
+    pub gif_palette: GifPalette,
+
+    /// Apply Floyd–Steinberg dithering when quantizing GIF frames
+    #[arg(long, default_value_t = false)]
+    pub dither: bool,
+
+    /// WebP quality (0-100); for lossless this is compression effort
+    #[arg(long, default_value_t = 90.0)]
+    pub webp_quality: f32,
+
+    /// Encode WebP losslessly
+    #[arg(long, default_value_t = false)]
+    pub lossless: bool,
+
+    /// SVG background: an embedded shader render or a vector gradient
+    #[arg(long, value_enum, default_value_t = SvgBackground::Image)]
+    pub svg_background: SvgBackground,
+
+    /// Render at most this many lines; longer inputs are truncated with a warning
+    #[arg(long)]
+    pub max_lines: Option<usize>,
+
+    /// Soft-wrap lines longer than this many columns instead of widening the text grid
+    #[arg(long, value_name = "COLUMNS")]
+    pub wrap: Option<usize>,
+}
+
+/// Subcommands besides rendering
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Manage the Nerd Font cache used for text rendering
+    Fonts {
+        #[command(subcommand)]
+        action: FontsCommand,
+    },
+    /// List the background shaders --shader accepts and check that they compile
+    Shaders(ShadersArgs),
+}
+
+/// `codeskew fonts` actions
+#[derive(Subcommand, Debug)]
+pub enum FontsCommand {
+    /// Download Nerd Fonts into the cache (default: FiraCode)
+    Install {
+        /// Nerd Fonts release names, e.g. FiraCode, JetBrainsMono, Hack
+        names: Vec<String>,
+
+        /// Release tag to fetch, or "latest" (default: pinned release or $CODESKEW_NERDFONT_VERSION)
+        #[arg(long)]
+        release: Option<String>,
+    },
+    /// List cached fonts and the embedded fallback face
+    List,
+    /// Remove cached fonts from releases other than the configured one
+    Prune {
+        /// Remove every cached font, including the configured release
+        #[arg(long, default_value_t = false)]
+        all: bool,
+    },
+}
+
+/// `codeskew shaders` options
+#[derive(Args, Debug)]
+pub struct ShadersArgs {
+    /// Only list shaders and their metadata, without compiling them
+    #[arg(long, default_value_t = false)]
+    pub no_check: bool,
+
+    /// Render a thumbnail of every shader into one grid image
+    #[arg(long, value_name = "PATH")]
+    pub contact_sheet: Option<PathBuf>,
+
+    /// Code drawn over every thumbnail (default: a built-in snippet)
+    #[arg(long, value_name = "FILE")]
+    pub input: Option<PathBuf>,
+
+    /// Width and height of each contact sheet thumbnail
+    #[arg(long, value_name = "PIXELS", default_value_t = 256)]
+    pub thumb_size: u32,
+
+    /// Directory searched for shaders first (repeatable)
+    #[arg(long = "shader-dir", value_name = "DIR")]
+    pub shader_dirs: Vec<PathBuf>,
+
+    /// GPU backend used to compile and render the shaders
+    #[arg(long, value_enum, default_value_t = GpuBackend::Auto)]
+    pub backend: GpuBackend,
+}
+
+/// Background used behind the text in SVG output
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Default)]
+pub enum SvgBackground {
+    /// Shader frame rendered offscreen and embedded as a PNG <image>
+    #[default]
+    Image,
+    /// The --gradient background as an SVG gradient, or the default diagonal one
+    Gradient,
+}
+
+impl fmt::Display for SvgBackground {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SvgBackground::Image => write!(f, "image"),
+            SvgBackground::Gradient => write!(f, "gradient"),
+        }
+    }
+}
+
+/// Palette strategy for GIF quantization
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Default)]
+pub enum GifPalette {
+    /// A single palette built from frames sampled across the animation
+    #[default]
+    Global,
+    /// A local palette for every frame
+    PerFrame,
+}
+
+impl fmt::Display for GifPalette {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GifPalette::Global => write!(f, "global"),
+            GifPalette::PerFrame => write!(f, "per-frame"),
+        }
+    }
+}
+
+/// 3D geometry the code is drawn on by `--surface`
+///
+/// The order matches the `KIND_*` constants in `toy/surface.wgsl`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Surface {
+    /// Flat sheet tilted back from the viewer
+    Plane,
+    /// Sheet rolled around a horizontal axis, like a scroll
+    Cylinder,
+    /// Page with its bottom right corner curling up
+    Curl,
+    /// Sheet rippling over time
+    Wave,
+}
+
+/// GPU backend used to render
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum, Default)]
+pub enum GpuBackend {
+    /// Hardware adapter if available, otherwise fall back to a software adapter
+    #[default]
+    Auto,
+    /// Vulkan only
+    Vulkan,
+    /// OpenGL / GLES only
+    Gl,
+    /// Software (CPU) adapter only
+    Cpu,
+}
+
+/// Parse `--param name=value`, requiring a WGSL identifier for the name
+fn parse_param(s: &str) -> Result<(String, f32), String> {
+    let (name, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected NAME=VALUE, got '{s}'"))?;
+    let name = name.trim();
+    if !is_param_name(name) {
+        return Err(format!("'{name}' is not a valid WGSL identifier"));
+    }
+    let value = value
+        .trim()
+        .parse::<f32>()
+        .map_err(|e| format!("invalid value for '{name}': {e}"))?;
+    Ok((name.to_string(), value))
+}
+
+fn parse_look_at(s: &str) -> Result<(f32, f32), String> {
+    let (x, y) = s
+        .split_once(',')
+        .ok_or_else(|| format!("expected X,Y, got '{s}'"))?;
+    let coordinate = |value: &str| {
+        value
+            .trim()
+            .parse::<f32>()
+            .map_err(|e| format!("invalid coordinate '{}': {e}", value.trim()))
+    };
+    Ok((coordinate(x)?, coordinate(y)?))
+}
+
+/// Whether `name` can be used as a field of the WGSL `Custom` struct
+pub fn is_param_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with("__")
+        && name != "_"
+}
+
+impl fmt::Display for GpuBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuBackend::Auto => write!(f, "auto"),
+            GpuBackend::Vulkan => write!(f, "vulkan"),
+            GpuBackend::Gl => write!(f, "gl"),
+            GpuBackend::Cpu => write!(f, "cpu"),
+        }
+    }
 }
 
 /// Output format for the rendered code
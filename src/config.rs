@@ -1,16 +1,29 @@
-use crate::cli::{Cli, OutputFormat};
+use crate::cli::{Cli, GifPalette, GpuBackend, OutputFormat, Surface, SvgBackground, is_param_name};
 use crate::error::CodeSkewError;
 use crate::glyphon::ligature_config::LigatureConfig;
+use crate::gradient::{self, GradientGenerator, GradientSpec};
+use crate::shader_library::is_shader_name;
+use crate::transform::ViewCamera;
+use crate::toy::{Camera, DepthOfField, MAX_CUSTOM_PARAMS};
 use anyhow::Result;
+use indexmap::IndexMap;
+use serde::Deserialize;
 use std::path::{Path, PathBuf};
 
-/// Gradient colors for the background - optimized for performance
+/// Telegram sticker limits: square side in pixels, animation length and file size
+pub const TELEGRAM_STICKER_SIZE: u32 = 512;
+pub const TELEGRAM_MAX_DURATION: f32 = 3.0;
+pub const TELEGRAM_MAX_BYTES: usize = 256 * 1024;
+
+/// Gradient colors for the background
+#[deprecated(note = "use `GradientSpec`; `GradientSpec::from(colors)` converts")]
 #[derive(Debug, Clone)]
 pub struct GradientColors {
     pub start: String,
     pub end: String,
 }
 
+#[allow(deprecated)]
 impl GradientColors {
     /// Create a new gradient colors instance
     #[inline]
@@ -18,10 +31,13 @@ impl GradientColors {
         Self { start, end }
     }
 
-    /// Create gradient colors from CLI arguments - optimized for minimal allocation
+    /// Create gradient colors from CLI arguments, with the default colors for missing flags
     #[inline]
     pub fn from_cli(cli: &Cli) -> Self {
-        Self::new(cli.gradient_start.clone(), cli.gradient_end.clone())
+        Self::new(
+            cli.gradient_start.clone().unwrap_or_else(|| gradient::DEFAULT_START.to_string()),
+            cli.gradient_end.clone().unwrap_or_else(|| gradient::DEFAULT_END.to_string()),
+        )
     }
 
     /// Get the start color
@@ -39,33 +55,42 @@ impl GradientColors {
     /// Validate gradient colors format
     #[inline]
     pub fn validate(&self) -> Result<(), CodeSkewError> {
-        // Validate start color format using the accessor method
-        if !self.is_valid_color(self.start()) {
-            return Err(CodeSkewError::ConfigError(format!(
-                "Invalid gradient start color: {}",
-                self.start()
-            )));
-        }
-
-        // Validate end color format using the accessor method
-        if !self.is_valid_color(self.end()) {
-            return Err(CodeSkewError::ConfigError(format!(
-                "Invalid gradient end color: {}",
-                self.end()
-            )));
-        }
+        GradientGenerator::new(&GradientSpec::from(self.clone())).map(|_| ())
+    }
+}
 
-        Ok(())
+#[allow(deprecated)]
+impl From<GradientColors> for GradientSpec {
+    fn from(colors: GradientColors) -> Self {
+        GradientSpec::from_colors(&colors.start, &colors.end)
     }
+}
 
-    /// Check if a color string is valid (hex format)
-    #[inline]
-    fn is_valid_color(&self, color: &str) -> bool {
-        if color.starts_with('#') && color.len() == 7 {
-            color[1..].chars().all(|c| c.is_ascii_hexdigit())
-        } else {
-            false
-        }
+/// Settings read from the `--config` YAML file; command-line flags take precedence
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Shader parameters for the `custom` uniform
+    pub params: IndexMap<String, f32>,
+}
+
+impl ConfigFile {
+    /// Read and parse a config file
+    pub fn load(path: &Path) -> Result<Self, CodeSkewError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            CodeSkewError::ConfigError(format!(
+                "Failed to read config file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        serde_yaml::from_str(&content).map_err(|e| {
+            CodeSkewError::ConfigError(format!(
+                "Failed to parse config file {}: {}",
+                path.display(),
+                e
+            ))
+        })
     }
 }
 
@@ -77,28 +102,65 @@ pub struct Config {
     pub width: u32,
     pub height: u32,
     pub font: String,
+    pub font_file: Option<PathBuf>,
     pub fontsize: f32,
     pub skew: f32,
     pub depth: f32,
     pub perspective: f32,
     pub blur: f32,
+    pub focus_line: Option<usize>,
+    pub focus_depth: Option<f32>,
     pub animate: bool,
     pub theme: String,
     pub centered: bool,
-    pub gradient: GradientColors,
+    /// Background gradient for shaders and SVG, `None` unless one of the gradient flags is given
+    pub gradient: Option<GradientSpec>,
     pub format: OutputFormat,
     pub telegram: bool,
     pub duration: f32,
     pub fps: f32,
+    pub time: f32,
+    pub warmup_frames: u32,
+    pub accumulate: u32,
+    pub fail_on_assert: bool,
+    pub shader_debug: bool,
+    pub shader_debug_json: Option<PathBuf>,
     pub shader: String,
+    /// Directories from `--shader-dir`, searched before the rest of the shader library
+    pub shader_dirs: Vec<PathBuf>,
+    /// Shader parameters from the config file, overridden by `--param`
+    pub params: IndexMap<String, f32>,
+    /// The `--config` file `params` were read from, re-read on hot reload
+    pub config_file: Option<PathBuf>,
+    /// The `--param` overrides, kept so a reloaded config file can't undo them
+    pub param_overrides: Vec<(String, f32)>,
+    pub backend: GpuBackend,
+    pub gif_colors: u16,
+    pub gif_palette: GifPalette,
+    pub dither: bool,
+    pub webp_quality: f32,
+    pub webp_lossless: bool,
+    pub svg_background: SvgBackground,
+    pub max_lines: Option<usize>,
+    pub wrap: Option<usize>,
 
     // Ligature configuration
     pub ligature_config: LigatureConfig,
 
     // 3D perspective parameters
     pub fold: f32,
+    pub fold_point: f32,
     pub skew_angle: f32,
     pub scale: f32,
+
+    // Camera, in degrees
+    pub yaw: f32,
+    pub pitch: f32,
+    pub roll: f32,
+    pub fov: Option<f32>,
+    /// Look-at point as fractions of the text width and height
+    pub look_at: (f32, f32),
+    pub surface: Option<Surface>,
 }
 
 impl Config {
@@ -115,8 +177,7 @@ impl Config {
             }
         };
 
-        // Create the gradient colors with minimal allocation
-        let gradient = GradientColors::from_cli(cli);
+        let gradient = Self::gradient_from_cli(cli)?;
 
         // Create ligature configuration based on CLI args and config file
         let ligature_config = Self::create_ligature_config(cli)?;
@@ -128,40 +189,148 @@ impl Config {
 
         // Telegram optimization - compile-time constants for dimensions
         let (width, height, telegram) = if cli.telegram {
-            (512_u32, 512_u32, true)
+            (TELEGRAM_STICKER_SIZE, TELEGRAM_STICKER_SIZE, true)
         } else {
             (cli.width, cli.height, false)
         };
 
+        let params = Self::merge_params(cli.config.as_deref(), &cli.params)?;
+
+        let input = cli
+            .input
+            .clone()
+            .ok_or_else(|| CodeSkewError::ConfigError("No input file given".to_string()))?;
+
         // Create the configuration - reuse existing allocations where possible
         Ok(Config {
-            input: cli.input.clone(),
+            input,
             output: cli.output.clone(),
             width,
             height,
             font: cli.font.clone(),
+            font_file: cli.font_file.clone(),
             fontsize: cli.fontsize,
             skew: cli.skew,
             depth: cli.depth,
             perspective: cli.perspective,
             blur: cli.blur,
+            focus_line: cli.focus_line,
+            focus_depth: cli.focus_depth,
             animate: cli.animate || telegram, // Always animate for Telegram
             theme: cli.theme.clone(),
             centered: cli.centered,
             gradient,
             format: if telegram { OutputFormat::Webp } else { format },
             telegram,
-            duration: cli.duration,
+            duration: if telegram {
+                cli.duration.min(TELEGRAM_MAX_DURATION)
+            } else {
+                cli.duration
+            },
             fps: cli.fps,
+            time: cli.time,
+            warmup_frames: cli.warmup_frames,
+            accumulate: cli.accumulate,
+            fail_on_assert: cli.fail_on_assert,
+            shader_debug: cli.shader_debug,
+            shader_debug_json: cli.shader_debug_json.clone(),
             shader: cli.shader.clone(),
+            shader_dirs: cli.shader_dirs.clone(),
+            params,
+            config_file: cli.config.clone(),
+            param_overrides: cli.params.clone(),
+            backend: cli.backend,
+            gif_colors: cli.gif_colors,
+            gif_palette: cli.gif_palette,
+            dither: cli.dither,
+            webp_quality: cli.webp_quality,
+            webp_lossless: cli.lossless,
+            svg_background: cli.svg_background,
+            max_lines: cli.max_lines,
+            wrap: cli.wrap,
 
             // Ligature configuration
             ligature_config,
 
             // 3D perspective parameters
             fold: cli.fold,
+            fold_point: cli.fold_point,
             skew_angle: cli.skew_angle,
             scale: cli.scale,
+
+            // Camera
+            yaw: cli.yaw,
+            pitch: cli.pitch,
+            roll: cli.roll,
+            fov: cli.fov,
+            look_at: cli.look_at,
+            surface: cli.surface,
+        })
+    }
+
+    /// `--gradient`, or a diagonal gradient when `--gradient-start` or `--gradient-end` is given
+    fn gradient_from_cli(cli: &Cli) -> Result<Option<GradientSpec>, CodeSkewError> {
+        if let Some(spec) = &cli.gradient {
+            return spec.parse().map(Some);
+        }
+        if cli.gradient_start.is_none() && cli.gradient_end.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(GradientSpec::from_colors(
+            cli.gradient_start
+                .as_deref()
+                .unwrap_or(gradient::DEFAULT_START),
+            cli.gradient_end.as_deref().unwrap_or(gradient::DEFAULT_END),
+        )))
+    }
+
+    /// Shader parameters: config file first, then `--param` overrides
+    fn merge_params(
+        config_file: Option<&Path>,
+        overrides: &[(String, f32)],
+    ) -> Result<IndexMap<String, f32>, CodeSkewError> {
+        let mut params = match config_file {
+            Some(path) => ConfigFile::load(path)?.params,
+            None => IndexMap::new(),
+        };
+        params.extend(overrides.iter().cloned());
+        Ok(params)
+    }
+
+    /// Re-read shader parameters after the config file changed, without applying them
+    pub fn read_params(&self) -> Result<IndexMap<String, f32>, CodeSkewError> {
+        let params = Self::merge_params(self.config_file.as_deref(), &self.param_overrides)?;
+        Self::validate_params(&params)?;
+        Ok(params)
+    }
+
+    /// The text warp shaders read as `codeskew_camera.*`
+    pub fn camera(&self) -> Camera {
+        Camera {
+            skew: self.skew.to_radians(),
+            depth: self.depth,
+            perspective: self.perspective,
+            fold: self.fold,
+            fold_point: self.fold_point,
+            skew_angle: self.skew_angle,
+            scale: self.scale,
+            height: self.height as f32,
+            ..Camera::default()
+        }
+        .with_view(&ViewCamera::from_config(self))
+    }
+
+    /// Settings of the blur pass, `None` without `--blur`
+    ///
+    /// `line_height` is the height of a row of code in the text texture, in pixels.
+    pub fn depth_of_field(&self, line_height: f32) -> Option<DepthOfField> {
+        (self.blur > 0.0).then(|| DepthOfField {
+            radius: self.blur,
+            focus_depth: self.focus_depth,
+            focus_band: self.focus_line.map(|line| {
+                let top = (line - 1) as f32 * line_height / self.height as f32;
+                [top, top + line_height / self.height as f32]
+            }),
         })
     }
 
@@ -244,6 +413,15 @@ impl Config {
             ));
         }
 
+        if let Some(font_file) = &self.font_file
+            && !font_file.is_file()
+        {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Font file not found: {}",
+                font_file.display()
+            )));
+        }
+
         // Validate skew range for visual appeal
         if self.skew < -45.0 || self.skew > 45.0 {
             return Err(CodeSkewError::ConfigError(format!(
@@ -260,14 +438,51 @@ impl Config {
             )));
         }
 
-        // Validate perspective for 3D effect
-        if self.perspective < 0.0 {
+        // Validate perspective for 3D effect, it is the camera distance
+        if self.perspective <= 0.0 {
             return Err(CodeSkewError::ConfigError(format!(
-                "Perspective must be non-negative: {}",
+                "Perspective must be positive: {}",
                 self.perspective
             )));
         }
 
+        // The text plane must face the camera
+        if self.yaw.abs() >= 90.0 || self.pitch.abs() >= 90.0 {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Yaw and pitch must be between -90 and 90 degrees: {}, {}",
+                self.yaw, self.pitch
+            )));
+        }
+
+        if !(self.look_at.0.is_finite() && self.look_at.1.is_finite()) {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Look-at point must be finite: {}, {}",
+                self.look_at.0, self.look_at.1
+            )));
+        }
+
+        if let Some(fov) = self.fov
+            && !(fov > 0.0 && fov < 180.0)
+        {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Field of view must be between 0 and 180 degrees: {fov}"
+            )));
+        }
+
+        if !(self.fold_point > 0.0 && self.fold_point < 1.0) {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Fold point must be between 0 and 1: {}",
+                self.fold_point
+            )));
+        }
+
+        if self.fold <= -1.0 || self.scale <= -1.0 {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Fold and scale must be greater than -1: {}, {}",
+                self.fold, self.scale
+            )));
+        }
+
         // Validate blur for visual quality
         if self.blur < 0.0 {
             return Err(CodeSkewError::ConfigError(format!(
@@ -276,6 +491,20 @@ impl Config {
             )));
         }
 
+        if self.focus_line == Some(0) {
+            return Err(CodeSkewError::ConfigError(
+                "Focus line counts from 1".to_string(),
+            ));
+        }
+
+        if let Some(depth) = self.focus_depth
+            && depth <= 0.0
+        {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Focus depth must be positive: {depth}"
+            )));
+        }
+
         // Check animation settings for consistency
         if self.animate && self.duration <= 0.0 {
             return Err(CodeSkewError::ConfigError(format!(
@@ -284,6 +513,54 @@ impl Config {
             )));
         }
 
+        if !(0.0..=100.0).contains(&self.webp_quality) {
+            return Err(CodeSkewError::ConfigError(format!(
+                "WebP quality must be between 0 and 100: {}",
+                self.webp_quality
+            )));
+        }
+
+        if self.fps <= 0.0 {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Animation fps must be positive: {}",
+                self.fps
+            )));
+        }
+
+        if !self.time.is_finite() || self.time < 0.0 {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Capture time must be non-negative: {}",
+                self.time
+            )));
+        }
+
+        if self.accumulate == 0 {
+            return Err(CodeSkewError::ConfigError(
+                "--accumulate must be at least 1 frame".to_string(),
+            ));
+        }
+
+        if !is_shader_name(&self.shader) {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Invalid shader name '{}': use a relative name such as davidar/caustics",
+                self.shader
+            )));
+        }
+
+        Self::validate_params(&self.params)?;
+
+        if self.max_lines == Some(0) {
+            return Err(CodeSkewError::ConfigError(
+                "--max-lines must be at least 1".to_string(),
+            ));
+        }
+
+        if self.wrap == Some(0) {
+            return Err(CodeSkewError::ConfigError(
+                "--wrap must be at least 1 column".to_string(),
+            ));
+        }
+
         // Validate centered flag consistency with dimensions
         if self.centered && (self.width < 100 || self.height < 100) {
             return Err(CodeSkewError::ConfigError(
@@ -292,7 +569,9 @@ impl Config {
         }
 
         // Validate gradient colors
-        self.gradient.validate()?;
+        if let Some(gradient) = &self.gradient {
+            GradientGenerator::new(gradient)?;
+        }
 
         // File system checks (more expensive, so done last)
         if !self.input.exists() {
@@ -322,4 +601,24 @@ impl Config {
 
         Ok(())
     }
+
+    /// Shader parameters must be WGSL identifiers and fit the `custom` uniform
+    fn validate_params(params: &IndexMap<String, f32>) -> Result<(), CodeSkewError> {
+        if let Some(name) = params.keys().find(|name| !is_param_name(name)) {
+            return Err(CodeSkewError::ConfigError(format!(
+                "Shader parameter '{}' is not a valid WGSL identifier",
+                name
+            )));
+        }
+
+        if params.len() > MAX_CUSTOM_PARAMS {
+            return Err(CodeSkewError::ConfigError(format!(
+                "At most {} shader parameters are supported, got {}",
+                MAX_CUSTOM_PARAMS,
+                params.len()
+            )));
+        }
+
+        Ok(())
+    }
 }
@@ -26,6 +26,15 @@ pub enum CodeSkewError {
     #[allow(dead_code)] // Public API - animation error handling for external use
     AnimationError(String),
 
+    #[error("Shader error at {file}:{line}:{col}: {message}\n{snippet}")]
+    ShaderError {
+        file: String,
+        line: usize,
+        col: usize,
+        message: String,
+        snippet: String,
+    },
+
     #[error("Output error: {0}")]
     OutputError(String),
 
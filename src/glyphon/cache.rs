@@ -18,6 +18,9 @@ const MAX_AGE_FRAMES: u64 = 180;
 /// Cleanup interval in frames (every second at 60fps)
 const CLEANUP_INTERVAL_FRAMES: u64 = 60;
 
+/// A byte range of shaped text drawn in a single glyph color
+pub type ColorRun = (std::ops::Range<usize>, GlyphColor);
+
 /// Zero-allocation text area pool using fixed-size arrays and atomic counters
 ///
 /// This implementation guarantees:
@@ -381,16 +384,21 @@ impl<const SIZE: usize> LockFreeShapeCache<SIZE> {
 
     /// Get or create a shaped buffer with lock-free operation
     #[inline(always)]
+    ///
+    /// `color_runs` colors byte ranges of `text` individually; the colors are part of
+    /// the cache key, so the same text in different colors is shaped separately.
+    #[allow(clippy::too_many_arguments)]
     pub fn get_or_create(
         &mut self,
         text: &str,
+        color_runs: &[ColorRun],
         font_system: &mut FontSystem,
         metrics: Metrics,
         attrs: &glyphon::Attrs,
         shaping: glyphon::Shaping,
         frame_count: u64,
     ) -> (usize, &Buffer) {
-        let text_hash = Self::hash_text_fnv1a_optimized(text);
+        let text_hash = Self::hash_rich_text(text, color_runs);
 
         // Fast lookup using optimized linear search
         if let Some(index) = self.find_cached_entry(text_hash) {
@@ -406,7 +414,14 @@ impl<const SIZE: usize> LockFreeShapeCache<SIZE> {
 
         // Create new buffer
         let mut buffer = Buffer::new(font_system, metrics);
-        buffer.set_text(font_system, text, attrs, shaping);
+        if color_runs.is_empty() {
+            buffer.set_text(font_system, text, attrs, shaping);
+        } else {
+            let spans = color_runs
+                .iter()
+                .map(|(range, color)| (&text[range.clone()], attrs.clone().color(*color)));
+            buffer.set_rich_text(font_system, spans, attrs, shaping, None);
+        }
         buffer.shape_until_scroll(font_system, false);
 
         // Atomically update cache entry
@@ -504,6 +519,20 @@ impl<const SIZE: usize> LockFreeShapeCache<SIZE> {
         hash
     }
 
+    /// Hash text together with its color runs
+    #[inline(always)]
+    pub fn hash_rich_text(text: &str, color_runs: &[ColorRun]) -> u64 {
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        color_runs
+            .iter()
+            .fold(Self::hash_text_fnv1a_optimized(text), |hash, (range, color)| {
+                [range.start as u64, range.end as u64, color.0 as u64]
+                    .into_iter()
+                    .fold(hash, |hash, value| (hash ^ value).wrapping_mul(FNV_PRIME))
+            })
+    }
+
     /// Get comprehensive cache statistics
     #[inline(always)]
     pub fn stats(&self) -> CacheStats {
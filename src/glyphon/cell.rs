@@ -17,6 +17,7 @@ pub struct Cell {
     pub foreground: u8,  // Index into color palette
     pub background: u8,  // Index into color palette
     pub style_flags: u8, // Bold, italic, underline, etc.
+    pub foreground_rgb: Option<[u8; 3]>, // True-color foreground, overrides `foreground`
 }
 
 impl Default for Cell {
@@ -27,6 +28,7 @@ impl Default for Cell {
             foreground: 7, // Default white
             background: 0, // Default black
             style_flags: 0,
+            foreground_rgb: None,
         }
     }
 }
@@ -40,6 +42,7 @@ impl Cell {
             foreground: 7,
             background: 0,
             style_flags: 0,
+            foreground_rgb: None,
         }
     }
 
@@ -51,6 +54,19 @@ impl Cell {
             foreground,
             background,
             style_flags,
+            foreground_rgb: None,
+        }
+    }
+
+    /// Create a cell with a 24-bit foreground color
+    #[inline]
+    pub const fn rgb(character: char, r: u8, g: u8, b: u8) -> Self {
+        Self {
+            character,
+            foreground: 7,
+            background: 0,
+            style_flags: 0,
+            foreground_rgb: Some([r, g, b]),
         }
     }
 
@@ -85,40 +101,55 @@ impl Cell {
             && self.foreground == 7
             && self.background == 0
             && self.style_flags == 0
+            && self.foreground_rgb.is_none()
     }
 }
 
-/// Fixed-size terminal grid with zero-allocation updates
-#[repr(align(64))] // Cache line alignment for better performance
-pub struct CellGrid<const COLS: usize, const ROWS: usize> {
-    cells: [[Cell; COLS]; ROWS],
-    dirty_rows: [bool; ROWS],
+/// Terminal grid sized at runtime, with zero-allocation updates once created
+///
+/// Cells are stored row-major in a single allocation so rows stay contiguous.
+pub struct CellGrid {
+    cells: Vec<Cell>,
+    dirty_rows: Vec<bool>,
+    cols: usize,
+    rows: usize,
     change_count: u64,
 }
 
-impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
-    /// Create a new empty grid
+impl CellGrid {
+    /// Create a new empty grid of `cols` x `rows` cells
     #[inline]
-    pub const fn new() -> Self {
-        let empty_cell = Cell {
-            character: ' ',
-            foreground: 7,
-            background: 0,
-            style_flags: 0,
-        };
-
+    pub fn new(cols: usize, rows: usize) -> Self {
         Self {
-            cells: [[empty_cell; COLS]; ROWS],
-            dirty_rows: [false; ROWS],
+            cells: vec![Cell::default(); cols * rows],
+            dirty_rows: vec![false; rows],
+            cols,
+            rows,
             change_count: 0,
         }
     }
 
+    /// Resize the grid, clearing its contents and marking every row dirty
+    pub fn resize(&mut self, cols: usize, rows: usize) {
+        self.cells.clear();
+        self.cells.resize(cols * rows, Cell::default());
+        self.dirty_rows.clear();
+        self.dirty_rows.resize(rows, true);
+        self.cols = cols;
+        self.rows = rows;
+        self.change_count = self.change_count.wrapping_add(1);
+    }
+
+    #[inline]
+    fn row_range(&self, row: usize) -> std::ops::Range<usize> {
+        row * self.cols..(row + 1) * self.cols
+    }
+
     /// Set a cell at the given position
     #[inline]
     pub fn set_cell(&mut self, col: usize, row: usize, cell: Cell) {
-        if col < COLS && row < ROWS {
-            self.cells[row][col] = cell;
+        if col < self.cols && row < self.rows {
+            self.cells[row * self.cols + col] = cell;
             self.dirty_rows[row] = true;
             self.change_count = self.change_count.wrapping_add(1);
         }
@@ -127,8 +158,8 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
     /// Get a cell at the given position
     #[inline]
     pub fn get_cell(&self, col: usize, row: usize) -> Option<Cell> {
-        if col < COLS && row < ROWS {
-            Some(self.cells[row][col])
+        if col < self.cols && row < self.rows {
+            Some(self.cells[row * self.cols + col])
         } else {
             None
         }
@@ -137,10 +168,10 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
     /// Get a mutable reference to a cell
     #[inline]
     pub fn get_cell_mut(&mut self, col: usize, row: usize) -> Option<&mut Cell> {
-        if col < COLS && row < ROWS {
+        if col < self.cols && row < self.rows {
             self.dirty_rows[row] = true;
             self.change_count = self.change_count.wrapping_add(1);
-            Some(&mut self.cells[row][col])
+            Some(&mut self.cells[row * self.cols + col])
         } else {
             None
         }
@@ -148,9 +179,9 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
 
     /// Get an entire row
     #[inline]
-    pub fn get_row(&self, row: usize) -> Option<&[Cell; COLS]> {
-        if row < ROWS {
-            Some(&self.cells[row])
+    pub fn get_row(&self, row: usize) -> Option<&[Cell]> {
+        if row < self.rows {
+            Some(&self.cells[self.row_range(row)])
         } else {
             None
         }
@@ -158,11 +189,12 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
 
     /// Get a mutable row (marks it as dirty)
     #[inline]
-    pub fn get_row_mut(&mut self, row: usize) -> Option<&mut [Cell; COLS]> {
-        if row < ROWS {
+    pub fn get_row_mut(&mut self, row: usize) -> Option<&mut [Cell]> {
+        if row < self.rows {
             self.dirty_rows[row] = true;
             self.change_count = self.change_count.wrapping_add(1);
-            Some(&mut self.cells[row])
+            let range = self.row_range(row);
+            Some(&mut self.cells[range])
         } else {
             None
         }
@@ -171,13 +203,13 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
     /// Check if a row is dirty
     #[inline]
     pub fn is_row_dirty(&self, row: usize) -> bool {
-        row < ROWS && self.dirty_rows[row]
+        row < self.rows && self.dirty_rows[row]
     }
 
     /// Mark a row as dirty
     #[inline]
     pub fn mark_row_dirty(&mut self, row: usize) {
-        if row < ROWS {
+        if row < self.rows {
             self.dirty_rows[row] = true;
             self.change_count = self.change_count.wrapping_add(1);
         }
@@ -186,30 +218,20 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
     /// Clear dirty flags for all rows
     #[inline]
     pub fn clear_dirty_flags(&mut self) {
-        self.dirty_rows = [false; ROWS];
+        self.dirty_rows.fill(false);
     }
 
     /// Clear the entire grid
     #[inline]
     pub fn clear(&mut self) {
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                self.cells[row][col] = Cell::default();
-            }
-            self.dirty_rows[row] = true;
-        }
-        self.change_count = self.change_count.wrapping_add(1);
+        self.fill(Cell::default());
     }
 
     /// Fill the grid with a specific cell
     #[inline]
     pub fn fill(&mut self, cell: Cell) {
-        for row in 0..ROWS {
-            for col in 0..COLS {
-                self.cells[row][col] = cell;
-            }
-            self.dirty_rows[row] = true;
-        }
+        self.cells.fill(cell);
+        self.dirty_rows.fill(true);
         self.change_count = self.change_count.wrapping_add(1);
     }
 
@@ -219,10 +241,10 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
         self.change_count
     }
 
-    /// Get dimensions
+    /// Get dimensions as `(cols, rows)`
     #[inline]
     pub const fn dimensions(&self) -> (usize, usize) {
-        (COLS, ROWS)
+        (self.cols, self.rows)
     }
 
     /// Get number of dirty rows
@@ -232,13 +254,13 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
     }
 
     /// Iterate over dirty rows with their indices
-    pub fn dirty_rows_iter(&self) -> impl Iterator<Item = (usize, &[Cell; COLS])> + '_ {
+    pub fn dirty_rows_iter(&self) -> impl Iterator<Item = (usize, &[Cell])> + '_ {
         self.dirty_rows
             .iter()
             .enumerate()
             .filter_map(|(idx, &dirty)| {
                 if dirty {
-                    Some((idx, &self.cells[idx]))
+                    Some((idx, &self.cells[self.row_range(idx)]))
                 } else {
                     None
                 }
@@ -248,10 +270,9 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
     /// Clear a specific row
     #[inline]
     pub fn clear_row(&mut self, row: usize) {
-        if row < ROWS {
-            for col in 0..COLS {
-                self.cells[row][col] = Cell::default();
-            }
+        if row < self.rows {
+            let range = self.row_range(row);
+            self.cells[range].fill(Cell::default());
             self.dirty_rows[row] = true;
             self.change_count = self.change_count.wrapping_add(1);
         }
@@ -260,8 +281,9 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
     /// Copy a row from source to destination
     #[inline]
     pub fn copy_row(&mut self, src_row: usize, dst_row: usize) {
-        if src_row < ROWS && dst_row < ROWS && src_row != dst_row {
-            self.cells[dst_row] = self.cells[src_row];
+        if src_row < self.rows && dst_row < self.rows && src_row != dst_row {
+            let src = self.row_range(src_row);
+            self.cells.copy_within(src, dst_row * self.cols);
             self.dirty_rows[dst_row] = true;
             self.change_count = self.change_count.wrapping_add(1);
         }
@@ -269,53 +291,33 @@ impl<const COLS: usize, const ROWS: usize> CellGrid<COLS, ROWS> {
 
     /// Scroll the grid up by n rows
     pub fn scroll_up(&mut self, n: usize) {
-        if n == 0 || n >= ROWS {
+        if n == 0 || n >= self.rows {
             return;
         }
 
-        // Move rows up
-        for row in 0..(ROWS - n) {
-            self.cells[row] = self.cells[row + n];
-        }
-
-        // Clear bottom rows
-        for row in (ROWS - n)..ROWS {
-            for col in 0..COLS {
-                self.cells[row][col] = Cell::default();
-            }
-        }
+        // Move rows up, then clear the bottom rows
+        let split = (self.rows - n) * self.cols;
+        self.cells.copy_within(n * self.cols.., 0);
+        self.cells[split..].fill(Cell::default());
 
         // Mark all as dirty
-        self.dirty_rows = [true; ROWS];
+        self.dirty_rows.fill(true);
         self.change_count = self.change_count.wrapping_add(1);
     }
 
     /// Scroll the grid down by n rows
     pub fn scroll_down(&mut self, n: usize) {
-        if n == 0 || n >= ROWS {
+        if n == 0 || n >= self.rows {
             return;
         }
 
-        // Move rows down
-        for row in (n..ROWS).rev() {
-            self.cells[row] = self.cells[row - n];
-        }
-
-        // Clear top rows
-        for row in 0..n {
-            for col in 0..COLS {
-                self.cells[row][col] = Cell::default();
-            }
-        }
+        // Move rows down, then clear the top rows
+        let moved = (self.rows - n) * self.cols;
+        self.cells.copy_within(..moved, n * self.cols);
+        self.cells[..n * self.cols].fill(Cell::default());
 
         // Mark all as dirty
-        self.dirty_rows = [true; ROWS];
+        self.dirty_rows.fill(true);
         self.change_count = self.change_count.wrapping_add(1);
     }
 }
-
-impl<const COLS: usize, const ROWS: usize> Default for CellGrid<COLS, ROWS> {
-    fn default() -> Self {
-        Self::new()
-    }
-}
@@ -1,5 +1,6 @@
 //! Color palette management for zero-allocation terminal rendering
 
+use super::cell::Cell;
 use glyphon::Color as GlyphColor;
 
 /// Color palette size (supports full 8-bit color space)
@@ -103,6 +104,15 @@ impl ColorPalette {
         self.glyph_colors[index as usize]
     }
 
+    /// Get glyphon color for a cell, preferring its 24-bit foreground over the palette
+    #[inline]
+    pub fn get_cell_glyph_color(&self, cell: &Cell) -> GlyphColor {
+        match cell.foreground_rgb {
+            Some([r, g, b]) => GlyphColor::rgb(r, g, b),
+            None => self.get_glyph_color(cell.foreground),
+        }
+    }
+
     /// Get linear color for blending
     #[inline]
     pub fn get_linear_color(&self, index: u8) -> [f32; 4] {
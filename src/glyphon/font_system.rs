@@ -1,7 +1,9 @@
 //! Font system management and metrics for the renderer
 
+use crate::error::CodeSkewError;
 use anyhow::{Context, Result};
 use glyphon::{FontSystem, Metrics};
+use std::collections::HashSet;
 use std::path::Path;
 // Use super to navigate up from font_system -> glyphon -> crate root
 use super::super::nerdfont::quic;
@@ -11,6 +13,9 @@ pub const MIN_FONT_SIZE: f32 = 8.0;
 pub const MAX_FONT_SIZE: f32 = 48.0;
 pub const DEFAULT_FONT_SIZE: f32 = 14.0;
 
+/// Family used when `--font` is left at its default
+pub const DEFAULT_FONT_FAMILY: &str = "FiraCode Nerd Font Mono";
+
 /// Monospace font character width ratio
 pub const MONOSPACE_RATIO: f32 = 0.6;
 
@@ -91,6 +96,112 @@ pub async fn create_font_system_with_nerd_font(font_name: &str) -> Result<FontSy
     Ok(font_system)
 }
 
+/// Create a font system that can shape text in the configured family
+///
+/// Returns the font system together with the exact family name to shape with.
+/// The family is resolved, in order, from `font_file`, the system font database
+/// and the Nerd Font loader. Only the default family falls back to the
+/// embedded face (or the system monospace font); any other missing family is a `FontError`.
+pub async fn create_font_system_for_family(
+    family: &str,
+    font_file: Option<&Path>,
+) -> Result<(FontSystem, String), CodeSkewError> {
+    let mut font_system = FontSystem::new();
+
+    if let Some(path) = font_file {
+        let before = face_ids(&font_system);
+        load_font_file(&mut font_system, path)
+            .map_err(|e| CodeSkewError::FontError(format!("{e:#}")))?;
+
+        let loaded = new_families(&font_system, &before);
+        if loaded.is_empty() {
+            return Err(CodeSkewError::FontError(format!(
+                "No usable font faces in {}",
+                path.display()
+            )));
+        }
+
+        // Prefer the requested family if the file provides it, otherwise use the file's own
+        let resolved = loaded
+            .iter()
+            .find(|name| name.eq_ignore_ascii_case(family))
+            .unwrap_or(&loaded[0])
+            .clone();
+        log::info!("Using font family '{resolved}' from {}", path.display());
+        return Ok((font_system, resolved));
+    }
+
+    if let Some(resolved) = find_family(&font_system, family) {
+        log::info!("Using system font family '{resolved}'");
+        return Ok((font_system, resolved));
+    }
+
+    // Not installed: try the Nerd Font loader, which knows a handful of families by name
+    let before = face_ids(&font_system);
+    if let Err(e) = quic::load_with_family(&mut font_system, Some(family)).await {
+        log::warn!("Nerd Font loader failed for '{family}': {e}");
+    }
+    let loaded = new_families(&font_system, &before);
+    if let Some(resolved) = find_family(&font_system, family).or_else(|| {
+        // Downloaded Nerd Fonts register under their full name, e.g. "JetBrainsMono Nerd Font Mono"
+        let wanted = family.to_lowercase().replace(' ', "");
+        loaded
+            .into_iter()
+            .find(|name| name.to_lowercase().replace(' ', "").starts_with(&wanted))
+    }) {
+        log::info!("Using Nerd Font family '{resolved}'");
+        return Ok((font_system, resolved));
+    }
+
+    if family == DEFAULT_FONT_FAMILY {
+        // The loader only adds the embedded face when a download fails, so make sure it is there
+        if find_family(&font_system, quic::EMBEDDED_FAMILY).is_none()
+            && let Err(e) = quic::load_embedded(&mut font_system)
+        {
+            log::warn!("Embedded font unavailable: {e}");
+        }
+        let fallback = find_family(&font_system, quic::EMBEDDED_FAMILY).unwrap_or_else(|| {
+            font_system
+                .db()
+                .family_name(&glyphon::fontdb::Family::Monospace)
+                .to_string()
+        });
+        log::warn!("Default font '{family}' is unavailable, falling back to '{fallback}'");
+        return Ok((font_system, fallback));
+    }
+
+    Err(CodeSkewError::FontError(format!(
+        "Font family '{family}' not found; install it or pass --font-file <path>"
+    )))
+}
+
+/// Case-insensitive lookup of a family name in the font database
+fn find_family(font_system: &FontSystem, family: &str) -> Option<String> {
+    font_system.db().faces().find_map(|face| {
+        face.families
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(family))
+            .map(|(name, _)| name.clone())
+    })
+}
+
+fn face_ids(font_system: &FontSystem) -> HashSet<glyphon::fontdb::ID> {
+    font_system.db().faces().map(|face| face.id).collect()
+}
+
+/// Primary family names of faces added since `before` was taken, in load order
+fn new_families(font_system: &FontSystem, before: &HashSet<glyphon::fontdb::ID>) -> Vec<String> {
+    let mut families: Vec<String> = Vec::new();
+    for face in font_system.db().faces().filter(|face| !before.contains(&face.id)) {
+        if let Some((name, _)) = face.families.first()
+            && !families.contains(name)
+        {
+            families.push(name.clone());
+        }
+    }
+    families
+}
+
 /// Load a specific font file into the font system
 pub fn load_font_file(font_system: &mut FontSystem, path: &Path) -> Result<()> {
     let font_data = std::fs::read(path)
@@ -5,11 +5,11 @@
 //! intelligent buffer management and optimized data structures.
 
 use super::{
-    cache::{LockFreeShapeCache, ZeroAllocTextAreaPool},
+    cache::{ColorRun, LockFreeShapeCache, ZeroAllocTextAreaPool},
     cell::Cell,
     color::ColorPalette,
 };
-use glyphon::{Attrs, Color, Family, Metrics, Shaping, TextBounds};
+use glyphon::{Attrs, Color, Family, Metrics, Shaping, Style, TextBounds, Weight};
 
 /// Zero-allocation text renderer with safe buffer management
 pub struct ZeroAllocTextRenderer;
@@ -20,12 +20,14 @@ impl ZeroAllocTextRenderer {
     /// This implementation eliminates unsafe code by using Arc-based buffer sharing
     /// and pre-allocated text area pools for zero allocation in hot paths.
     #[inline(always)]
-    pub fn create_text_areas_for_dirty_rows<'a, const COLS: usize, const ROWS: usize>(
-        dirty_rows: impl ExactSizeIterator<Item = (usize, &'a [Cell; COLS])>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_text_areas_for_dirty_rows<'a>(
+        dirty_rows: impl ExactSizeIterator<Item = (usize, &'a [Cell])>,
         shape_cache: &'a mut LockFreeShapeCache<{ super::cache::SHAPE_CACHE_SIZE }>,
         text_area_pool: &'a mut ZeroAllocTextAreaPool<{ super::cache::TEXT_AREA_POOL_SIZE }>,
         color_palette: &ColorPalette,
         font_system: &mut glyphon::FontSystem,
+        font_family: &str,
         config: &TextRenderConfig,
         frame_count: u64,
     ) -> SafeTextAreaCollection<'a> {
@@ -36,7 +38,7 @@ impl ZeroAllocTextRenderer {
         text_area_pool.reset();
 
         let metrics = config.font_metrics();
-        let attrs = TextRenderConfig::font_attrs();
+        let attrs = TextRenderConfig::font_attrs(font_family);
 
         // Process each row with safe buffer management
         for (row_idx, cells) in dirty_rows {
@@ -46,6 +48,7 @@ impl ZeroAllocTextRenderer {
                 // Get or create the shaped buffer safely
                 let buffer = shape_cache.get_or_create(
                     &row_data.text,
+                    &row_data.color_runs,
                     font_system,
                     metrics,
                     &attrs,
@@ -54,7 +57,7 @@ impl ZeroAllocTextRenderer {
                 );
 
                 // Add to text area pool - this copies the text for lifetime safety
-                let text_hash = super::cache::LockFreeShapeCache::<{ super::cache::SHAPE_CACHE_SIZE }>::hash_text_fnv1a_optimized(&row_data.text);
+                let text_hash = super::cache::LockFreeShapeCache::<{ super::cache::SHAPE_CACHE_SIZE }>::hash_rich_text(&row_data.text, &row_data.color_runs);
                 let _ = text_area_pool.add_area(
                     buffer.0, // cache index
                     text_hash,
@@ -72,12 +75,14 @@ impl ZeroAllocTextRenderer {
 
     /// Create text areas for dirty rows (fallback for non-ExactSizeIterator)
     #[inline(always)]
-    pub fn create_text_areas_for_dirty_rows_fallback<'a, const COLS: usize, const ROWS: usize>(
-        dirty_rows: impl Iterator<Item = (usize, &'a [Cell; COLS])>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_text_areas_for_dirty_rows_fallback<'a>(
+        dirty_rows: impl Iterator<Item = (usize, &'a [Cell])>,
         shape_cache: &'a mut LockFreeShapeCache<{ super::cache::SHAPE_CACHE_SIZE }>,
         text_area_pool: &'a mut ZeroAllocTextAreaPool<{ super::cache::TEXT_AREA_POOL_SIZE }>,
         color_palette: &ColorPalette,
         font_system: &mut glyphon::FontSystem,
+        font_family: &str,
         config: &TextRenderConfig,
         frame_count: u64,
     ) -> SafeTextAreaCollection<'a> {
@@ -85,7 +90,7 @@ impl ZeroAllocTextRenderer {
         text_area_pool.reset();
 
         let metrics = config.font_metrics();
-        let attrs = TextRenderConfig::font_attrs();
+        let attrs = TextRenderConfig::font_attrs(font_family);
 
         for (row_idx, cells) in dirty_rows {
             let row_data = RowData::from_cells(cells, row_idx, config, color_palette);
@@ -93,6 +98,7 @@ impl ZeroAllocTextRenderer {
             if !row_data.text.is_empty() {
                 let (cache_index, _buffer) = shape_cache.get_or_create(
                     &row_data.text,
+                    &row_data.color_runs,
                     font_system,
                     metrics,
                     &attrs,
@@ -100,7 +106,7 @@ impl ZeroAllocTextRenderer {
                     frame_count,
                 );
 
-                let text_hash = super::cache::LockFreeShapeCache::<{ super::cache::SHAPE_CACHE_SIZE }>::hash_text_fnv1a_optimized(&row_data.text);
+                let text_hash = super::cache::LockFreeShapeCache::<{ super::cache::SHAPE_CACHE_SIZE }>::hash_rich_text(&row_data.text, &row_data.color_runs);
 
                 let _ = text_area_pool.add_area(
                     cache_index,
@@ -122,7 +128,7 @@ impl ZeroAllocTextRenderer {
     /// Optimized implementation with SIMD-friendly operations and
     /// branch prediction hints for maximum throughput.
     #[inline(always)]
-    pub fn build_row_text<const COLS: usize>(row_cells: &[Cell; COLS]) -> String {
+    pub fn build_row_text(row_cells: &[Cell]) -> String {
         // Find last non-space character using vectorized search
         let last_non_space = Self::find_last_non_space_simd_friendly(row_cells);
 
@@ -134,7 +140,7 @@ impl ZeroAllocTextRenderer {
         let mut text = String::with_capacity(last_non_space);
 
         // Optimized character extraction with manual unrolling for small arrays
-        if COLS <= 16 {
+        if row_cells.len() <= 16 {
             // Small array optimization - manual unrolling
             for cell in row_cells.iter().take(last_non_space) {
                 text.push(cell.character);
@@ -149,17 +155,18 @@ impl ZeroAllocTextRenderer {
 
     /// SIMD-friendly last non-space character finder
     #[inline(always)]
-    fn find_last_non_space_simd_friendly<const COLS: usize>(row_cells: &[Cell; COLS]) -> usize {
+    fn find_last_non_space_simd_friendly(row_cells: &[Cell]) -> usize {
         // Process in chunks for better vectorization
         const CHUNK_SIZE: usize = 8;
-        let full_chunks = COLS / CHUNK_SIZE;
-        let remainder = COLS % CHUNK_SIZE;
+        let cols = row_cells.len();
+        let full_chunks = cols / CHUNK_SIZE;
+        let remainder = cols % CHUNK_SIZE;
 
         // Start from the end and work backwards
         // Process remainder first
         if remainder > 0 {
             let start_idx = full_chunks * CHUNK_SIZE;
-            for i in (start_idx..COLS).rev() {
+            for i in (start_idx..cols).rev() {
                 if row_cells[i].character != ' ' {
                     return i + 1;
                 }
@@ -181,9 +188,9 @@ impl ZeroAllocTextRenderer {
 
     /// Chunked text building for large arrays
     #[inline(always)]
-    fn build_text_chunked<const COLS: usize>(
+    fn build_text_chunked(
         text: &mut String,
-        row_cells: &[Cell; COLS],
+        row_cells: &[Cell],
         length: usize,
     ) {
         const CHUNK_SIZE: usize = 16;
@@ -212,8 +219,8 @@ impl ZeroAllocTextRenderer {
     /// Combines text building and color detection with vectorized operations
     /// for maximum cache efficiency and throughput.
     #[inline(always)]
-    pub fn build_row_text_with_color<const COLS: usize>(
-        row_cells: &[Cell; COLS],
+    pub fn build_row_text_with_color(
+        row_cells: &[Cell],
         color_palette: &ColorPalette,
     ) -> (String, Color) {
         let last_non_space = Self::find_last_non_space_simd_friendly(row_cells);
@@ -232,7 +239,7 @@ impl ZeroAllocTextRenderer {
 
             // Branchless color detection using conditional moves
             if first_visible_color.is_none() & (cell.character != ' ') {
-                first_visible_color = Some(color_palette.get_glyph_color(cell.foreground));
+                first_visible_color = Some(color_palette.get_cell_glyph_color(cell));
             }
         }
 
@@ -240,18 +247,45 @@ impl ZeroAllocTextRenderer {
         (text, color)
     }
 
+    /// Split the visible part of a row into byte ranges of equal glyph color
+    ///
+    /// Spaces join the surrounding run since their color is never drawn.
+    pub fn build_row_color_runs(
+        row_cells: &[Cell],
+        color_palette: &ColorPalette,
+    ) -> Vec<ColorRun> {
+        let last_non_space = Self::find_last_non_space_simd_friendly(row_cells);
+        let mut runs: Vec<ColorRun> = Vec::new();
+        let mut offset = 0;
+
+        for cell in row_cells.iter().take(last_non_space) {
+            let end = offset + cell.character.len_utf8();
+            let color = color_palette.get_cell_glyph_color(cell);
+
+            match runs.last_mut() {
+                Some((range, run_color)) if cell.character == ' ' || *run_color == color => {
+                    range.end = end;
+                }
+                _ => runs.push((offset..end, color)),
+            }
+            offset = end;
+        }
+
+        runs
+    }
+
     /// Get default color for a row based on first visible character
     ///
     /// Uses early termination and branch prediction hints for optimal performance.
     #[inline(always)]
-    pub fn get_row_default_color<const COLS: usize>(
-        row_cells: &[Cell; COLS],
+    pub fn get_row_default_color(
+        row_cells: &[Cell],
         color_palette: &ColorPalette,
     ) -> Color {
         // Early termination search optimized for common case
         for cell in row_cells.iter() {
             if cell.character != ' ' {
-                return color_palette.get_glyph_color(cell.foreground);
+                return color_palette.get_cell_glyph_color(cell);
             }
         }
         color_palette.get_glyph_color(7) // Default white
@@ -260,13 +294,14 @@ impl ZeroAllocTextRenderer {
     /// Create a single text area using safe buffer management
     #[inline(always)]
     #[allow(clippy::too_many_arguments)]
-    pub fn create_text_area_for_row<'a, const COLS: usize>(
+    pub fn create_text_area_for_row<'a>(
         row: usize,
-        cells: &'a [Cell; COLS],
+        cells: &'a [Cell],
         shape_cache: &'a mut LockFreeShapeCache<{ super::cache::SHAPE_CACHE_SIZE }>,
         text_area_pool: &'a mut ZeroAllocTextAreaPool<{ super::cache::TEXT_AREA_POOL_SIZE }>,
         color_palette: &ColorPalette,
         font_system: &mut glyphon::FontSystem,
+        font_family: &str,
         config: &TextRenderConfig,
         frame_count: u64,
     ) -> Result<(), super::cache::TextAreaPoolError> {
@@ -276,9 +311,10 @@ impl ZeroAllocTextRenderer {
             return Ok(());
         }
 
-        let attrs = TextRenderConfig::font_attrs();
+        let attrs = TextRenderConfig::font_attrs(font_family);
         let buffer = shape_cache.get_or_create(
             &row_data.text,
+            &row_data.color_runs,
             font_system,
             config.font_metrics(),
             &attrs,
@@ -286,7 +322,7 @@ impl ZeroAllocTextRenderer {
             frame_count,
         );
 
-        let text_hash = super::cache::LockFreeShapeCache::<{ super::cache::SHAPE_CACHE_SIZE }>::hash_text_fnv1a_optimized(&row_data.text);
+        let text_hash = super::cache::LockFreeShapeCache::<{ super::cache::SHAPE_CACHE_SIZE }>::hash_rich_text(&row_data.text, &row_data.color_runs);
         text_area_pool.add_area(
             buffer.0, // cache index
             text_hash,
@@ -357,6 +393,7 @@ impl<'a> SafeTextAreaCollection<'a> {
 #[derive(Debug)]
 pub struct RowData {
     pub text: String,
+    pub color_runs: Vec<ColorRun>,
     pub bounds: TextBounds,
     pub y_position: f32,
     pub default_color: Color,
@@ -365,19 +402,21 @@ pub struct RowData {
 impl RowData {
     /// Create row data from cells with aggressive inlining
     #[inline(always)]
-    fn from_cells<const COLS: usize>(
-        cells: &[Cell; COLS],
+    fn from_cells(
+        cells: &[Cell],
         row_idx: usize,
         config: &TextRenderConfig,
         color_palette: &ColorPalette,
     ) -> Self {
         let (text, default_color) =
             ZeroAllocTextRenderer::build_row_text_with_color(cells, color_palette);
+        let color_runs = ZeroAllocTextRenderer::build_row_color_runs(cells, color_palette);
         let y_position = config.row_y_position(row_idx);
         let bounds = config.row_bounds(row_idx);
 
         Self {
             text,
+            color_runs,
             bounds,
             y_position,
             default_color,
@@ -529,10 +568,13 @@ impl TextRenderConfig {
         Metrics::relative(self.font_size, 1.0)
     }
 
-    /// Get optimized monospace font attributes
+    /// Get font attributes for the given family name, upright and regular weight
     #[inline(always)]
-    pub fn font_attrs() -> Attrs<'static> {
-        Attrs::new().family(Family::Monospace)
+    pub fn font_attrs(family: &str) -> Attrs<'_> {
+        Attrs::new()
+            .family(Family::Name(family))
+            .weight(Weight::NORMAL)
+            .style(Style::Normal)
     }
 
     /// Update surface dimensions with recalculation
@@ -620,12 +662,12 @@ impl TextRenderConfig {
 }
 
 /// Lock-free batch processor for maximum throughput
-pub struct LockFreeBatchProcessor<'a, const COLS: usize> {
+pub struct LockFreeBatchProcessor<'a> {
     config: &'a TextRenderConfig,
     color_palette: &'a ColorPalette,
 }
 
-impl<'a, const COLS: usize> LockFreeBatchProcessor<'a, COLS> {
+impl<'a> LockFreeBatchProcessor<'a> {
     /// Create new batch processor
     #[inline(always)]
     pub const fn new(config: &'a TextRenderConfig, color_palette: &'a ColorPalette) -> Self {
@@ -637,13 +679,13 @@ impl<'a, const COLS: usize> LockFreeBatchProcessor<'a, COLS> {
 
     /// Process a single row with maximum optimization
     #[inline(always)]
-    pub fn process_row(&self, row_idx: usize, cells: &[Cell; COLS]) -> RowData {
+    pub fn process_row(&self, row_idx: usize, cells: &[Cell]) -> RowData {
         RowData::from_cells(cells, row_idx, self.config, self.color_palette)
     }
 
     /// Check if row needs processing (branchless)
     #[inline(always)]
-    pub fn should_process_row(&self, cells: &[Cell; COLS]) -> bool {
+    pub fn should_process_row(&self, cells: &[Cell]) -> bool {
         cells.iter().any(|cell| cell.character != ' ')
     }
 
@@ -657,7 +699,7 @@ impl<'a, const COLS: usize> LockFreeBatchProcessor<'a, COLS> {
     #[inline(always)]
     pub fn process_rows_batch<'b>(
         &self,
-        rows: impl Iterator<Item = (usize, &'b [Cell; COLS])>,
+        rows: impl Iterator<Item = (usize, &'b [Cell])>,
     ) -> Vec<RowData> {
         let mut results = Vec::new();
 
@@ -760,4 +802,35 @@ impl RenderStats {
 
 /// Type aliases for common configurations
 pub type TextRenderer = ZeroAllocTextRenderer;
-pub type BatchProcessor<'a, const COLS: usize> = LockFreeBatchProcessor<'a, COLS>;
+pub type BatchProcessor<'a> = LockFreeBatchProcessor<'a>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_runs_are_byte_ranges_split_where_the_color_changes() {
+        let red = [200, 30, 30];
+        let blue = [30, 30, 200];
+        let cell = |character, [r, g, b]: [u8; 3]| Cell::rgb(character, r, g, b);
+        // Multi-byte characters, a space in the other color and trailing spaces
+        let row = [
+            cell('é', red),
+            cell('→', red),
+            cell(' ', blue),
+            cell('λ', blue),
+            cell('x', blue),
+            cell('y', red),
+            cell(' ', red),
+            cell(' ', blue),
+        ];
+
+        let runs = ZeroAllocTextRenderer::build_row_color_runs(&row, &ColorPalette::default());
+        let (red, blue) = (Color::rgb(200, 30, 30), Color::rgb(30, 30, 200));
+        assert_eq!(runs, [(0..6, red), (6..9, blue), (9..10, red)]);
+
+        let text: String = row.iter().map(|cell| cell.character).collect();
+        assert_eq!(&text[0..6], "é→ ");
+        assert_eq!(&text[6..9], "λx");
+    }
+}
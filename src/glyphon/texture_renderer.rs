@@ -4,21 +4,22 @@
 //! instead of directly to the surface, for use in compute shader pipelines.
 
 use super::{
-    cache::{LockFreeShapeCache, ZeroAllocTextAreaPool},
+    cache::{LockFreeShapeCache, TEXT_AREA_POOL_SIZE, ZeroAllocTextAreaPool},
     cell::{Cell, CellGrid},
     color::ColorPalette,
-    font_system::{FontMetrics, create_font_system_with_nerd_font},
+    font_system::{FontMetrics, MONOSPACE_RATIO, create_font_system_for_family},
     text_rendering::{TextRenderConfig, ZeroAllocTextRenderer},
 };
 use anyhow::{Context, Result};
 use glyphon::{
     Cache, ColorMode, FontSystem, Resolution, SwashCache, TextAtlas, TextRenderer, Viewport,
 };
+use std::path::Path;
 use std::sync::Arc;
 use wgpu::{Device, Queue, RenderPassColorAttachment, SurfaceConfiguration, TextureFormat};
 
 /// Production-quality texture renderer using ratagpu's architecture
-pub struct GlyphonTextureRenderer<const COLS: usize, const ROWS: usize> {
+pub struct GlyphonTextureRenderer {
     // Core wgpu resources
     device: Arc<Device>,
     queue: Queue,
@@ -27,6 +28,7 @@ pub struct GlyphonTextureRenderer<const COLS: usize, const ROWS: usize> {
 
     // Glyphon components
     font_system: FontSystem,
+    font_family: String,
     swash_cache: SwashCache,
     cache: Cache,
     viewport: Viewport,
@@ -34,10 +36,10 @@ pub struct GlyphonTextureRenderer<const COLS: usize, const ROWS: usize> {
     text_renderer: TextRenderer,
 
     // Zero-allocation data structures (ratagpu style)
-    cell_grid: CellGrid<COLS, ROWS>,
+    cell_grid: CellGrid,
     color_palette: ColorPalette,
     shape_cache: LockFreeShapeCache<2048>, // SHAPE_CACHE_SIZE from ratagpu
-    text_area_pool: ZeroAllocTextAreaPool<128>, // TEXT_AREA_POOL_SIZE from ratagpu
+    text_area_pool: ZeroAllocTextAreaPool<TEXT_AREA_POOL_SIZE>,
 
     // Configuration
     font_size: f32,
@@ -46,19 +48,25 @@ pub struct GlyphonTextureRenderer<const COLS: usize, const ROWS: usize> {
     frame_count: u64,
 }
 
-impl<const COLS: usize, const ROWS: usize> GlyphonTextureRenderer<COLS, ROWS> {
+impl GlyphonTextureRenderer {
     /// Create new texture renderer using ratagpu's proven architecture
+    ///
+    /// `font_family` is resolved through `create_font_system_for_family`, loading
+    /// `font_file` first when given; an unknown family fails with `FontError`.
     pub async fn new(
         device: Arc<Device>,
         queue: Queue,
         config: SurfaceConfiguration,
         font_size: f32,
+        font_family: &str,
+        font_file: Option<&Path>,
     ) -> Result<Self> {
         let color_mode = ColorMode::Accurate;
         let scale_factor = 1.0;
 
-        // Initialize glyphon components (ratagpu style) with FiraCode Nerd Font Mono
-        let mut font_system = create_font_system_with_nerd_font("FiraCode Nerd Font Mono").await?;
+        // Initialize glyphon components (ratagpu style) with the configured font family
+        let (mut font_system, font_family) =
+            create_font_system_for_family(font_family, font_file).await?;
         let swash_cache = SwashCache::new();
         let cache = Cache::new(&*device);
         let viewport = Viewport::new(&*device, &cache);
@@ -71,8 +79,8 @@ impl<const COLS: usize, const ROWS: usize> GlyphonTextureRenderer<COLS, ROWS> {
         );
         let text_renderer = TextRenderer::new(&mut atlas, &*device, Default::default(), None);
 
-        // Initialize zero-allocation data structures
-        let cell_grid = CellGrid::new();
+        // Initialize zero-allocation data structures; the grid is sized by `load_layout`
+        let cell_grid = CellGrid::new(0, 0);
         let mut color_palette = ColorPalette::new();
         color_palette.initialize_runtime();
         let shape_cache = LockFreeShapeCache::new();
@@ -88,6 +96,7 @@ impl<const COLS: usize, const ROWS: usize> GlyphonTextureRenderer<COLS, ROWS> {
             config,
             color_mode,
             font_system,
+            font_family,
             swash_cache,
             cache,
             viewport,
@@ -104,26 +113,42 @@ impl<const COLS: usize, const ROWS: usize> GlyphonTextureRenderer<COLS, ROWS> {
         })
     }
 
+    /// Largest font size at which a `cols` x `rows` grid fits in `width` x `height` pixels
+    pub fn fit_font_size(cols: usize, rows: usize, width: u32, height: u32) -> f32 {
+        let by_height = height as f32 / rows.max(1) as f32;
+        let by_width = width as f32 / (cols.max(1) as f32 * MONOSPACE_RATIO);
+        by_height.min(by_width)
+    }
+
+    /// Font family the text is shaped with
+    pub fn font_family(&self) -> &str {
+        &self.font_family
+    }
+
+    /// Height of a row of the cell grid in the texture, in pixels
+    pub fn line_height(&self) -> f32 {
+        self.line_height * self.scale_factor
+    }
+
+    /// Grid dimensions as `(cols, rows)`
+    pub fn grid_dimensions(&self) -> (usize, usize) {
+        self.cell_grid.dimensions()
+    }
+
     /// Set cell content (ratagpu compatible interface)
     pub fn set_cell(&mut self, row: usize, col: usize, cell: Cell) {
-        if row < ROWS && col < COLS {
-            self.cell_grid.set_cell(row, col, cell);
-        }
+        self.cell_grid.set_cell(col, row, cell);
     }
 
-    /// Load layout data into cell grid
+    /// Load layout data into cell grid, resizing it to fit every line
     pub fn load_layout(&mut self, layout: &[crate::layout::PositionedLine]) {
-        // Clear existing content
-        self.cell_grid.clear();
-
-        for (row_idx, line) in layout.iter().enumerate().take(ROWS) {
-            for (col_idx, styled_char) in line.chars.iter().enumerate().take(COLS) {
-                let cell = Cell {
-                    character: styled_char.char,
-                    foreground: 15, // White - we'll handle colors differently
-                    background: 0,  // Black
-                    style_flags: 0,
-                };
+        let cols = layout.iter().map(|line| line.chars.len()).max().unwrap_or(0);
+        self.cell_grid.resize(cols, layout.len());
+
+        for (row_idx, line) in layout.iter().enumerate() {
+            for (col_idx, styled_char) in line.chars.iter().enumerate() {
+                let color = &styled_char.color;
+                let cell = Cell::rgb(styled_char.char, color.r, color.g, color.b);
                 self.set_cell(row_idx, col_idx, cell);
             }
         }
@@ -153,116 +178,137 @@ impl<const COLS: usize, const ROWS: usize> GlyphonTextureRenderer<COLS, ROWS> {
                 dimension: wgpu::TextureDimension::D2,
                 format: TextureFormat::Rgba8UnormSrgb,
                 usage: wgpu::TextureUsages::RENDER_ATTACHMENT
-                    | wgpu::TextureUsages::TEXTURE_BINDING,
+                    | wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_SRC,
                 view_formats: &[],
             });
 
         let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
 
-        // Process dirty rows and create text areas (ratagpu's exact approach)
-        let text_area_vec = {
-            let config = TextRenderConfig::new(
-                self.font_size,
-                self.line_height,
-                self.scale_factor,
-                width,
-                height,
-            );
-
-            // Use ratagpu's zero-allocation text renderer
-            let text_areas =
-                ZeroAllocTextRenderer::create_text_areas_for_dirty_rows_fallback::<COLS, ROWS>(
-                    self.cell_grid.dirty_rows_iter(),
+        let config = TextRenderConfig::new(
+            self.font_size,
+            self.line_height,
+            self.scale_factor,
+            width,
+            height,
+        );
+
+        // The text area pool holds a fixed number of rows, so larger grids are drawn
+        // in several passes over the same texture
+        let dirty_rows: Vec<usize> = self.cell_grid.dirty_rows_iter().map(|(row, _)| row).collect();
+        let mut batches: Vec<&[usize]> = dirty_rows.chunks(TEXT_AREA_POOL_SIZE).collect();
+        if batches.is_empty() {
+            batches.push(&[]);
+        }
+
+        for (batch_idx, batch) in batches.into_iter().enumerate() {
+            // Process dirty rows and create text areas (ratagpu's exact approach)
+            let text_area_vec = {
+                let cell_grid = &self.cell_grid;
+                let rows = batch
+                    .iter()
+                    .filter_map(|&row| cell_grid.get_row(row).map(|cells| (row, cells)));
+
+                // Use ratagpu's zero-allocation text renderer
+                let text_areas = ZeroAllocTextRenderer::create_text_areas_for_dirty_rows_fallback(
+                    rows,
                     &mut self.shape_cache,
                     &mut self.text_area_pool,
                     &self.color_palette,
                     &mut self.font_system,
+                    &self.font_family,
                     &config,
                     self.frame_count,
                 );
 
-            // Convert to glyphon text areas (ratagpu's exact method)
-            let buffer_indices: Vec<_> = text_areas
-                .iter()
-                .map(|area| {
-                    (
-                        area.buffer_cache_index(),
-                        area.left(),
-                        area.top(),
-                        area.scale(),
-                        *area.bounds(),
-                        *area.default_color(),
-                    )
-                })
-                .collect();
-
-            let mut glyphon_areas = Vec::with_capacity(buffer_indices.len());
-            for (cache_index, left, top, scale, bounds, default_color) in buffer_indices {
-                if let Some(buffer) = self.shape_cache.get_buffer(cache_index) {
-                    glyphon_areas.push(glyphon::TextArea {
-                        buffer,
-                        left,
-                        top,
-                        scale,
-                        bounds,
-                        default_color,
-                        custom_glyphs: &[],
-                    });
+                // Convert to glyphon text areas (ratagpu's exact method)
+                let buffer_indices: Vec<_> = text_areas
+                    .iter()
+                    .map(|area| {
+                        (
+                            area.buffer_cache_index(),
+                            area.left(),
+                            area.top(),
+                            area.scale(),
+                            *area.bounds(),
+                            *area.default_color(),
+                        )
+                    })
+                    .collect();
+
+                let mut glyphon_areas = Vec::with_capacity(buffer_indices.len());
+                for (cache_index, left, top, scale, bounds, default_color) in buffer_indices {
+                    if let Some(buffer) = self.shape_cache.get_buffer(cache_index) {
+                        glyphon_areas.push(glyphon::TextArea {
+                            buffer,
+                            left,
+                            top,
+                            scale,
+                            bounds,
+                            default_color,
+                            custom_glyphs: &[],
+                        });
+                    }
                 }
-            }
 
-            glyphon_areas
-        };
+                glyphon_areas
+            };
 
-        // Prepare rendering (ratagpu style)
-        let has_text_areas = !text_area_vec.is_empty();
-        if has_text_areas {
-            self.text_renderer
-                .prepare(
-                    &*self.device,
-                    &self.queue,
-                    &mut self.font_system,
-                    &mut self.atlas,
-                    &self.viewport,
-                    text_area_vec,
-                    &mut self.swash_cache,
-                )
-                .context("Failed to prepare text rendering")?;
-        }
+            // Prepare rendering (ratagpu style)
+            let has_text_areas = !text_area_vec.is_empty();
+            if has_text_areas {
+                self.text_renderer
+                    .prepare(
+                        &*self.device,
+                        &self.queue,
+                        &mut self.font_system,
+                        &mut self.atlas,
+                        &self.viewport,
+                        text_area_vec,
+                        &mut self.swash_cache,
+                    )
+                    .context("Failed to prepare text rendering")?;
+            }
 
-        // Record commands (ratagpu style)
-        let mut encoder =
-            self.device
-                .as_ref()
-                .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                    label: Some("Glyphon Texture Render"),
-                });
+            // Record commands (ratagpu style)
+            let mut encoder =
+                self.device
+                    .as_ref()
+                    .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                        label: Some("Glyphon Texture Render"),
+                    });
 
-        {
-            let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("Glyphon Text Pass"),
-                color_attachments: &[Some(RenderPassColorAttachment {
-                    view: &view,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            });
+            {
+                let load = if batch_idx == 0 {
+                    wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT)
+                } else {
+                    wgpu::LoadOp::Load
+                };
+                let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("Glyphon Text Pass"),
+                    color_attachments: &[Some(RenderPassColorAttachment {
+                        view: &view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load,
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
 
-            if has_text_areas {
-                self.text_renderer
-                    .render(&self.atlas, &self.viewport, &mut render_pass)
-                    .context("Failed to render text")?;
+                if has_text_areas {
+                    self.text_renderer
+                        .render(&self.atlas, &self.viewport, &mut render_pass)
+                        .context("Failed to render text")?;
+                }
             }
-        }
 
-        // Submit commands
-        self.queue.submit(Some(encoder.finish()));
+            // Submit each batch before the next `prepare` overwrites the vertex buffer
+            self.queue.submit(Some(encoder.finish()));
+        }
 
         // Maintenance (ratagpu style)
         if self.frame_count % 60 == 0 {
@@ -278,3 +324,108 @@ impl<const COLS: usize, const ROWS: usize> GlyphonTextureRenderer<COLS, ROWS> {
         Ok(texture)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::GpuBackend;
+    use crate::layout::{CharColor, PositionedLine, StyledChar};
+    use crate::toy::init_wgpu_headless;
+
+    /// The texture's texels, row by row
+    fn read_texture(device: &Device, queue: &Queue, texture: &wgpu::Texture) -> Vec<[u8; 4]> {
+        let (width, height) = (texture.width(), texture.height());
+        let bytes_per_row = (width * 4).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: (bytes_per_row * height) as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&Default::default());
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::TexelCopyBufferInfo {
+                buffer: &buffer,
+                layout: wgpu::TexelCopyBufferLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            texture.size(),
+        );
+        queue.submit(Some(encoder.finish()));
+        buffer.slice(..).map_async(wgpu::MapMode::Read, |result| result.expect("map"));
+        device.poll(wgpu::PollType::Wait).expect("poll");
+
+        let data = buffer.slice(..).get_mapped_range();
+        data.chunks(bytes_per_row as usize)
+            .flat_map(|row| row[..width as usize * 4].chunks(4).map(|texel| texel.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn tokens_keep_their_colors_in_the_texture() {
+        // Like main: a Tokio runtime for font downloads, on a thread with the main thread's
+        // stack size rather than a test thread's
+        let test = || {
+            let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().expect("runtime");
+            runtime.block_on(async {
+                let wgpu = match init_wgpu_headless(256, 64, GpuBackend::Cpu).await {
+                    Ok(wgpu) => wgpu,
+                    Err(e) => {
+                        eprintln!("skipping: {e}");
+                        return;
+                    }
+                };
+                let mut renderer = GlyphonTextureRenderer::new(
+                    wgpu.device.clone(),
+                    wgpu.queue.clone(),
+                    wgpu.surface_config.clone(),
+                    32.0,
+                    "FiraCode Nerd Font Mono",
+                    None,
+                )
+                .await
+                .expect("renderer");
+
+                // A red token, then a blue one
+                let chars = "MMMM WWWW"
+                    .chars()
+                    .enumerate()
+                    .map(|(i, char)| {
+                        let (r, b) = if i < 5 { (230, 20) } else { (20, 230) };
+                        StyledChar { char, color: CharColor { r, g: 20, b } }
+                    })
+                    .collect();
+                renderer.load_layout(&[PositionedLine { chars, x: 0.0, y: 0.0, scale: 1.0 }]);
+                let texture = renderer.render_to_texture(256, 64).expect("render");
+                let texels = read_texture(&wgpu.device, &wgpu.queue, &texture);
+
+                // The most opaque texel of each token, split where the space between them falls
+                let columns = |range: std::ops::Range<usize>| {
+                    texels
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| range.contains(&(i % 256)))
+                        .map(|(_, texel)| *texel)
+                        .max_by_key(|texel| texel[3])
+                        .expect("texels")
+                };
+                let drawn: Vec<usize> = (0..256)
+                    .filter(|&x| texels.iter().skip(x).step_by(256).any(|texel| texel[3] > 0))
+                    .collect();
+                let (left, right) = (drawn[0], drawn[drawn.len() - 1] + 1);
+                let middle = (left + right) / 2;
+                let red = columns(left..middle);
+                let blue = columns(middle..right);
+
+                assert!(red[3] > 128 && blue[3] > 128, "{red:?} {blue:?}");
+                assert!(red[0] > 2 * red[2].max(red[1]), "{red:?}");
+                assert!(blue[2] > 2 * blue[0].max(blue[1]), "{blue:?}");
+            })
+        };
+        std::thread::Builder::new().stack_size(8 << 20).spawn(test).unwrap().join().unwrap();
+    }
+}
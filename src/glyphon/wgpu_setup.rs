@@ -7,6 +7,8 @@
 //! - On-demand Instance/Surface creation
 //! - Pure stack-based operation
 
+use crate::cli::GpuBackend;
+use crate::toy::context::{create_instance, request_adapter};
 use anyhow::{Context, Result};
 use glyphon::ColorMode;
 use wgpu::{
@@ -86,23 +88,19 @@ impl<'frame> FrameHandle<'frame> {
 ///
 /// This function creates temporary Instance/Surface for initialization,
 /// then drops them. Only Device/Queue are kept.
-pub async fn initialize_wgpu(window: &Window) -> Result<GpuResources> {
+pub async fn initialize_wgpu(window: &Window, backend: GpuBackend) -> Result<GpuResources> {
     let size = window.inner_size();
 
     // Create instance on stack
-    let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+    let instance = create_instance(backend);
 
     // Create surface on stack
     let surface = instance.create_surface(window)?;
 
-    // Request adapter
-    let adapter = instance
-        .request_adapter(&wgpu::RequestAdapterOptions {
-            power_preference: wgpu::PowerPreference::HighPerformance,
-            compatible_surface: Some(&surface),
-            force_fallback_adapter: false,
-        })
+    // Request adapter, falling back to a software rasterizer when there is no GPU
+    let adapter = request_adapter(&instance, backend, Some(&surface))
         .await
+        .map_err(anyhow::Error::msg)
         .context("No compatible GPU adapter found")?;
 
     let adapter_info = adapter.get_info();
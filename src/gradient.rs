@@ -1,100 +1,342 @@
-use crate::config::GradientColors;
+//! Gradient backgrounds from CSS-style specs
+//!
+//! `linear(135deg, #0f2027, #203a43 40%, #2c5364)`, `radial(at 30% 40%, navy, black)` and
+//! `conic(from 90deg, magma)` take CSS color stops or a named preset. Stops are
+//! interpolated in OKLab, so midpoints keep their lightness instead of going muddy.
+
 use crate::error::CodeSkewError;
 use anyhow::Result;
-use colorgrad::{Color, Gradient as ColorGradient, LinearGradient};
-use image::{Rgb, Rgba, RgbImage, RgbaImage};
+use colorgrad::{BlendMode, Color, Gradient as ColorGradient, LinearGradient};
+use image::{Rgb, RgbImage, Rgba, RgbaImage};
 use std::any::Any;
+use std::f32::consts::TAU;
+use std::str::FromStr;
 
 // Re-export the Gradient trait publicly
 pub use colorgrad::Gradient;
 
+/// `--gradient-start` and `--gradient-end` defaults
+pub const DEFAULT_START: &str = "#1a1a1a";
+pub const DEFAULT_END: &str = "#4a4a4a";
+
+/// Named presets usable in place of color stops
+pub const PRESETS: &[&str] = &[
+    "viridis",
+    "inferno",
+    "magma",
+    "plasma",
+    "cividis",
+    "turbo",
+    "rainbow",
+    "sinebow",
+    "cubehelix",
+    "warm",
+    "cool",
+    "spectral",
+];
+
 /// Custom trait for gradient providers that can render to both RGB and RGBA images
 pub trait GradientProvider: Any {
     /// Generate a gradient background on an RGB image
     fn generate_rgb(&self, img: &mut RgbImage) -> Result<(), CodeSkewError>;
-    
+
     /// Generate a gradient background on an RGBA image
     fn generate_rgba(&self, img: &mut RgbaImage) -> Result<(), CodeSkewError>;
-    
+
     /// Get the color at a specific position (0.0 to 1.0) as RGB
     fn get_rgb_at(&self, pos: f32) -> [u8; 3];
-    
+
     /// Get the color at a specific position (0.0 to 1.0) as RGBA
     fn get_rgba_at(&self, pos: f32) -> [u8; 4];
-    
+
     /// Convert to Any for downcasting
     fn as_any(&self) -> &dyn Any;
 }
 
+/// How gradient positions are laid over the image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientShape {
+    /// Along a line through the center, `angle` in degrees clockwise from "to top"
+    Linear { angle: f32 },
+    /// Outward from `center`, reaching the end at the farthest corner
+    Radial { center: [f32; 2] },
+    /// Clockwise around `center`, starting `angle` degrees clockwise from the top
+    Conic { angle: f32, center: [f32; 2] },
+}
+
+/// Where a gradient's colors come from
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientStops {
+    /// CSS color stops, e.g. `#0f2027, #203a43 40%, #2c5364`
+    Css(String),
+    /// One of [`PRESETS`]
+    Preset(String),
+}
+
+/// A parsed `--gradient` spec
+#[derive(Debug, Clone, PartialEq)]
+pub struct GradientSpec {
+    pub shape: GradientShape,
+    pub stops: GradientStops,
+}
+
+impl Default for GradientSpec {
+    fn default() -> Self {
+        Self::from_colors(DEFAULT_START, DEFAULT_END)
+    }
+}
+
+impl GradientSpec {
+    /// Diagonal gradient from the top left to the bottom right
+    pub fn from_colors(start: &str, end: &str) -> Self {
+        Self {
+            shape: GradientShape::Linear { angle: 135.0 },
+            stops: GradientStops::Css(format!("{start}, {end}")),
+        }
+    }
+}
+
+impl FromStr for GradientSpec {
+    type Err = CodeSkewError;
+
+    /// Parse `shape(geometry, stops)`, or bare stops or a preset for a top to bottom gradient
+    ///
+    /// CSS's `linear-gradient(...)` spelling is accepted too.
+    fn from_str(spec: &str) -> Result<Self, CodeSkewError> {
+        let invalid = |reason: &str| {
+            CodeSkewError::ConfigError(format!("Invalid gradient '{spec}': {reason}"))
+        };
+
+        let spec = spec.trim();
+        let (name, args) = match spec.split_once('(') {
+            Some((name, rest))
+                if matches!(
+                    name.trim().trim_end_matches("-gradient"),
+                    "linear" | "radial" | "conic"
+                ) =>
+            {
+                let args = rest
+                    .strip_suffix(')')
+                    .ok_or_else(|| invalid("missing ')'"))?;
+                (name.trim().trim_end_matches("-gradient"), args)
+            }
+            _ => ("linear", spec),
+        };
+
+        let mut args = split_top_level(args);
+        let geometry = match args.first() {
+            Some(first) if is_geometry(first) => Some(args.remove(0)),
+            _ => None,
+        };
+
+        let shape = match (name, geometry) {
+            ("linear", None) => GradientShape::Linear { angle: 180.0 },
+            ("linear", Some(geometry)) => GradientShape::Linear {
+                angle: linear_angle(geometry)
+                    .ok_or_else(|| invalid("expected an angle or 'to <side>'"))?,
+            },
+            ("radial", geometry) => GradientShape::Radial {
+                center: match geometry {
+                    Some(geometry) => {
+                        let at = geometry
+                            .strip_prefix("at ")
+                            .ok_or_else(|| invalid("expected 'at <x>% <y>%'"))?;
+                        parse_position(at).ok_or_else(|| invalid("expected 'at <x>% <y>%'"))?
+                    }
+                    None => [0.5, 0.5],
+                },
+            },
+            (_, geometry) => {
+                let (mut angle, mut center) = (0.0, [0.5, 0.5]);
+                if let Some(geometry) = geometry {
+                    let (from, at) = match geometry.split_once("at ") {
+                        Some((from, at)) => (from.trim(), Some(at)),
+                        None => (geometry, None),
+                    };
+                    if let Some(from) = from.strip_prefix("from ") {
+                        angle = parse_angle(from.trim())
+                            .ok_or_else(|| invalid("expected 'from <angle>'"))?;
+                    } else if !from.is_empty() {
+                        return Err(invalid("expected 'from <angle>' or 'at <x>% <y>%'"));
+                    }
+                    if let Some(at) = at {
+                        center =
+                            parse_position(at).ok_or_else(|| invalid("expected 'at <x>% <y>%'"))?;
+                    }
+                }
+                GradientShape::Conic { angle, center }
+            }
+        };
+
+        let stops = match args.as_slice() {
+            [] => return Err(invalid("no colors")),
+            [preset] if PRESETS.contains(preset) => GradientStops::Preset(preset.to_string()),
+            _ => GradientStops::Css(args.join(", ")),
+        };
+        let spec = GradientSpec { shape, stops };
+
+        // Catch bad colors now rather than at render time
+        GradientGenerator::new(&spec)?;
+        Ok(spec)
+    }
+}
+
+/// Split on commas outside parentheses, so `rgb(1, 2, 3)` stays one stop
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(s[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(s[start..].trim());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+fn is_geometry(arg: &str) -> bool {
+    ["to ", "at ", "from "]
+        .iter()
+        .any(|prefix| arg.starts_with(prefix))
+        || parse_angle(arg).is_some()
+}
+
+/// `135deg`, `0.25turn` or `1.57rad`, in degrees
+fn parse_angle(s: &str) -> Option<f32> {
+    if let Some(deg) = s.strip_suffix("deg") {
+        deg.trim().parse().ok()
+    } else if let Some(turn) = s.strip_suffix("turn") {
+        turn.trim().parse::<f32>().ok().map(|turn| turn * 360.0)
+    } else if let Some(rad) = s.strip_suffix("rad") {
+        rad.trim().parse::<f32>().ok().map(f32::to_degrees)
+    } else {
+        None
+    }
+}
+
+/// An angle, or `to right`, `to bottom left` and so on. Corners are taken at 45 degrees.
+fn linear_angle(s: &str) -> Option<f32> {
+    let Some(sides) = s.strip_prefix("to ") else {
+        return parse_angle(s);
+    };
+    let (mut x, mut y) = (0.0f32, 0.0f32);
+    for side in sides.split_whitespace() {
+        match side {
+            "top" => y = -1.0,
+            "bottom" => y = 1.0,
+            "left" => x = -1.0,
+            "right" => x = 1.0,
+            _ => return None,
+        }
+    }
+    (x != 0.0 || y != 0.0).then(|| x.atan2(-y).to_degrees())
+}
+
+/// `30% 40%` as fractions of the width and height
+fn parse_position(s: &str) -> Option<[f32; 2]> {
+    let mut coords = s
+        .split_whitespace()
+        .map(|coord| coord.strip_suffix('%')?.parse::<f32>().ok());
+    let position = [coords.next()?? / 100.0, coords.next()?? / 100.0];
+    coords.next().is_none().then_some(position)
+}
+
+fn preset(name: &str) -> Option<Box<dyn ColorGradient>> {
+    use colorgrad::preset;
+    Some(match name {
+        "viridis" => preset::viridis().boxed(),
+        "inferno" => preset::inferno().boxed(),
+        "magma" => preset::magma().boxed(),
+        "plasma" => preset::plasma().boxed(),
+        "cividis" => preset::cividis().boxed(),
+        "turbo" => preset::turbo().boxed(),
+        "rainbow" => preset::rainbow().boxed(),
+        "sinebow" => preset::sinebow().boxed(),
+        "cubehelix" => preset::cubehelix_default().boxed(),
+        "warm" => preset::warm().boxed(),
+        "cool" => preset::cool().boxed(),
+        "spectral" => preset::spectral().boxed(),
+        _ => return None,
+    })
+}
+
 /// Generates gradient backgrounds for the code image
 #[derive(Clone)]
 pub struct GradientGenerator {
-    gradient: LinearGradient,
+    shape: GradientShape,
+    gradient: Box<dyn ColorGradient>,
 }
 
 impl GradientGenerator {
-    /// Create a new gradient generator with the specified colors
-    pub fn new(gradient_colors: &GradientColors) -> Self {
-        // Parse the gradient colors
-        let start_color = Self::parse_hex_color(&gradient_colors.start)
-            .expect("Invalid start color");
-        let end_color = Self::parse_hex_color(&gradient_colors.end)
-            .expect("Invalid end color");
-
-        // Create the gradient
-        let gradient = colorgrad::GradientBuilder::new()
-            .colors(&[start_color, end_color])
-            .build::<LinearGradient>()
-            .expect("Failed to create gradient");
-
-        Self { gradient }
-    }
-
-    /// Parse a hex color string into an RGB color
-    fn parse_hex_color(hex: &str) -> Result<Color, CodeSkewError> {
-        let hex = hex.trim_start_matches('#');
-        
-        // Parse the hex color
-        let r = u8::from_str_radix(&hex[0..2], 16)
-            .map_err(|_| CodeSkewError::RenderingError(format!("Invalid hex color: #{}", hex)))?;
-        let g = u8::from_str_radix(&hex[2..4], 16)
-            .map_err(|_| CodeSkewError::RenderingError(format!("Invalid hex color: #{}", hex)))?;
-        let b = u8::from_str_radix(&hex[4..6], 16)
-            .map_err(|_| CodeSkewError::RenderingError(format!("Invalid hex color: #{}", hex)))?;
-        
-        // Convert to 0.0-1.0 range and explicitly cast to f32
-        let r = (r as f32) / 255.0;
-        let g = (g as f32) / 255.0;
-        let b = (b as f32) / 255.0;
-        
-        Ok(Color::new(r, g, b, 1.0))
+    /// Create a new gradient generator for a spec
+    pub fn new(spec: &GradientSpec) -> Result<Self, CodeSkewError> {
+        let gradient = match &spec.stops {
+            GradientStops::Preset(name) => preset(name).ok_or_else(|| {
+                CodeSkewError::ConfigError(format!(
+                    "Unknown gradient preset '{name}', expected one of: {}",
+                    PRESETS.join(", ")
+                ))
+            })?,
+            GradientStops::Css(stops) => colorgrad::GradientBuilder::new()
+                .mode(BlendMode::Oklab)
+                .css(stops)
+                .build::<LinearGradient>()
+                .map_err(|e| {
+                    CodeSkewError::ConfigError(format!("Invalid gradient colors '{stops}': {e}"))
+                })?
+                .boxed(),
+        };
+        Ok(Self {
+            shape: spec.shape,
+            gradient,
+        })
+    }
+
+    pub fn shape(&self) -> GradientShape {
+        self.shape
+    }
+
+    /// Gradient position of the pixel center at `(x, y)`, from 0 to 1
+    pub fn position(&self, x: u32, y: u32, width: u32, height: u32) -> f32 {
+        let (w, h) = (width as f32, height as f32);
+        let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+        let t = match self.shape {
+            GradientShape::Linear { angle } => {
+                // The gradient line runs through the center, long enough to reach the corners
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let length = (w * sin).abs() + (h * cos).abs();
+                ((px - w / 2.0) * sin - (py - h / 2.0) * cos) / length + 0.5
+            }
+            GradientShape::Radial { center } => {
+                let (cx, cy) = (center[0] * w, center[1] * h);
+                let radius = cx.max(w - cx).hypot(cy.max(h - cy));
+                (px - cx).hypot(py - cy) / radius
+            }
+            GradientShape::Conic { angle, center } => {
+                let (dx, dy) = (px - center[0] * w, py - center[1] * h);
+                (dx.atan2(-dy) / TAU - angle / 360.0).rem_euclid(1.0)
+            }
+        };
+        t.clamp(0.0, 1.0)
+    }
+
+    /// The color at a gradient position, mapped onto the gradient's domain
+    fn color_at(&self, pos: f32) -> Color {
+        let (min, max) = self.gradient.domain();
+        self.gradient.at(min + pos * (max - min))
     }
 
     /// Generate a gradient background image with the specified dimensions
     pub fn generate(&self, width: u32, height: u32) -> Result<RgbaImage> {
-        // Create the image buffer
         let mut img = RgbaImage::new(width, height);
-        
-        // Fill the image with the gradient
-        for y in 0..height {
-            // Calculate the gradient position (0.0 to 1.0)
-            let pos = y as f32 / height as f32;
-            
-            // Get the color at this position - using f32 as required by the API
-            let color = self.gradient.at(pos);
-            
-            // Convert to RGBA u8 values
-            let r = (color.r * 255.0) as u8;
-            let g = (color.g * 255.0) as u8;
-            let b = (color.b * 255.0) as u8;
-            let a = 255; // Full opacity
-            
-            // Fill this row with the color
-            for x in 0..width {
-                img.put_pixel(x, y, Rgba([r, g, b, a]));
-            }
-        }
-        
+        self.generate_rgba(&mut img)?;
         Ok(img)
     }
 }
@@ -104,7 +346,7 @@ impl ColorGradient for GradientGenerator {
     fn at(&self, t: f32) -> Color {
         self.gradient.at(t)
     }
-    
+
     fn domain(&self) -> (f32, f32) {
         self.gradient.domain()
     }
@@ -113,72 +355,125 @@ impl ColorGradient for GradientGenerator {
 // Implement our custom GradientProvider trait for GradientGenerator
 impl GradientProvider for GradientGenerator {
     fn generate_rgb(&self, img: &mut RgbImage) -> Result<(), CodeSkewError> {
-        let width = img.width();
-        let height = img.height();
-        
-        // Fill the image with the gradient
-        for y in 0..height {
-            // Calculate the gradient position (0.0 to 1.0)
-            let pos = y as f32 / height as f32;
-            
-            // Get the color at this position
-            let color = self.gradient.at(pos);
-            let r = (color.r * 255.0) as u8;
-            let g = (color.g * 255.0) as u8;
-            let b = (color.b * 255.0) as u8;
-            
-            // Fill this row with the color
-            for x in 0..width {
-                img.put_pixel(x, y, Rgb([r, g, b]));
-            }
+        let (width, height) = img.dimensions();
+        for (x, y, pixel) in img.enumerate_pixels_mut() {
+            *pixel = Rgb(self.get_rgb_at(self.position(x, y, width, height)));
         }
-        
         Ok(())
     }
-    
+
     fn generate_rgba(&self, img: &mut RgbaImage) -> Result<(), CodeSkewError> {
-        let width = img.width();
-        let height = img.height();
-        
-        // Fill the image with the gradient
-        for y in 0..height {
-            // Calculate the gradient position (0.0 to 1.0)
-            let pos = y as f32 / height as f32;
-            
-            // Get the color at this position
-            let color = self.gradient.at(pos);
-            let r = (color.r * 255.0) as u8;
-            let g = (color.g * 255.0) as u8;
-            let b = (color.b * 255.0) as u8;
-            let a = 255; // Full opacity
-            
-            // Fill this row with the color
-            for x in 0..width {
-                img.put_pixel(x, y, Rgba([r, g, b, a]));
-            }
+        let (width, height) = img.dimensions();
+        for (x, y, pixel) in img.enumerate_pixels_mut() {
+            *pixel = Rgba(self.get_rgba_at(self.position(x, y, width, height)));
         }
-        
         Ok(())
     }
-    
+
     fn get_rgb_at(&self, pos: f32) -> [u8; 3] {
-        let color = self.gradient.at(pos);
-        let r = (color.r * 255.0) as u8;
-        let g = (color.g * 255.0) as u8;
-        let b = (color.b * 255.0) as u8;
+        let [r, g, b, _] = self.color_at(pos).to_rgba8();
         [r, g, b]
     }
-    
+
     fn get_rgba_at(&self, pos: f32) -> [u8; 4] {
-        let color = self.gradient.at(pos);
-        let r = (color.r * 255.0) as u8;
-        let g = (color.g * 255.0) as u8;
-        let b = (color.b * 255.0) as u8;
-        let a = 255; // Full opacity
-        [r, g, b, a]
-    }
-    
+        self.color_at(pos).to_rgba8()
+    }
+
     fn as_any(&self) -> &dyn Any {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specs_parse_shapes_and_stops() {
+        let spec: GradientSpec = "linear(135deg, #0f2027, #203a43 40%, #2c5364)"
+            .parse()
+            .unwrap();
+        assert_eq!(spec.shape, GradientShape::Linear { angle: 135.0 });
+        assert_eq!(
+            spec.stops,
+            GradientStops::Css("#0f2027, #203a43 40%, #2c5364".into())
+        );
+
+        let spec: GradientSpec = "radial-gradient(at 30% 40%, rgb(0, 0, 128), black)"
+            .parse()
+            .unwrap();
+        assert_eq!(spec.shape, GradientShape::Radial { center: [0.3, 0.4] });
+        assert_eq!(
+            spec.stops,
+            GradientStops::Css("rgb(0, 0, 128), black".into())
+        );
+
+        let spec: GradientSpec = "conic(from 0.25turn at 50% 100%, magma)".parse().unwrap();
+        assert_eq!(
+            spec.shape,
+            GradientShape::Conic {
+                angle: 90.0,
+                center: [0.5, 1.0]
+            }
+        );
+        assert_eq!(spec.stops, GradientStops::Preset("magma".into()));
+
+        let spec: GradientSpec = "#2b303b,#16181d".parse().unwrap();
+        assert_eq!(spec.shape, GradientShape::Linear { angle: 180.0 });
+        assert_eq!(
+            "linear(to bottom right, red, blue)"
+                .parse::<GradientSpec>()
+                .unwrap()
+                .shape,
+            GradientShape::Linear { angle: 135.0 }
+        );
+
+        for bad in [
+            "linear(135deg)",
+            "radial(at middle, red, blue)",
+            "linear(red, notacolor)",
+            "conic(red, blue",
+        ] {
+            assert!(bad.parse::<GradientSpec>().is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn shapes_lay_positions_over_the_image() {
+        let generator = |spec: &str| GradientGenerator::new(&spec.parse().unwrap()).unwrap();
+
+        // Top to bottom by default; black to white stays gray in the middle
+        let linear = generator("black, white");
+        assert!((linear.position(5, 0, 10, 10) - 0.05).abs() < 1e-5);
+        assert!((linear.position(5, 9, 10, 10) - 0.95).abs() < 1e-5);
+        let [r, g, b] = linear.get_rgb_at(0.5);
+        assert!(r == g && g == b && (90..170).contains(&r), "{r}");
+
+        let radial = generator("radial(red, blue)");
+        assert!(radial.position(50, 50, 100, 100) < 0.02);
+        assert!(radial.position(0, 0, 10, 10) > 0.9);
+
+        // Clockwise from the top
+        let conic = generator("conic(red, blue)");
+        assert!(conic.position(50, 0, 100, 100) < 0.01);
+        assert!((conic.position(99, 50, 100, 100) - 0.25).abs() < 0.01);
+
+        let image = generator("linear(90deg, #ff0000, #0000ff)")
+            .generate(8, 2)
+            .unwrap();
+        assert!(image.get_pixel(0, 0)[0] > 200 && image.get_pixel(7, 1)[2] > 200);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_gradient_colors_convert_to_a_diagonal_spec() {
+        let colors = crate::config::GradientColors::new("#ff0000".into(), "#0000ff".into());
+        assert!(colors.validate().is_ok());
+        assert_eq!(
+            GradientSpec::from(colors),
+            GradientSpec::from_colors("#ff0000", "#0000ff")
+        );
+        let bad = crate::config::GradientColors::new("#ff0000".into(), "nope".into());
+        assert!(bad.validate().is_err());
+    }
+}
@@ -198,3 +198,67 @@ impl SyntaxHighlighter {
         Ok(styled_lines)
     }
 }
+
+/// Soft-wrap highlighted lines to at most `width` characters
+///
+/// Continuation lines keep the source `line_number` and the span styles of the
+/// text they carry, so colors survive the split.
+pub fn wrap_lines(lines: Vec<StyledLine>, width: usize) -> Vec<StyledLine> {
+    let width = width.max(1);
+    let mut wrapped = Vec::with_capacity(lines.len());
+
+    for line in lines {
+        let line_chars: usize = line.spans.iter().map(|span| span.text.chars().count()).sum();
+        if line_chars <= width {
+            wrapped.push(line);
+            continue;
+        }
+
+        let mut current = StyledLine {
+            spans: Vec::new(),
+            line_number: line.line_number,
+        };
+        let mut current_chars = 0;
+
+        for span in line.spans {
+            let mut start = span.start;
+            let mut rest = span.text.as_str();
+
+            while !rest.is_empty() {
+                if current_chars == width {
+                    wrapped.push(std::mem::replace(
+                        &mut current,
+                        StyledLine {
+                            spans: Vec::new(),
+                            line_number: line.line_number,
+                        },
+                    ));
+                    current_chars = 0;
+                }
+
+                let take = width - current_chars;
+                let split = rest
+                    .char_indices()
+                    .nth(take)
+                    .map_or(rest.len(), |(idx, _)| idx);
+                let (piece, tail) = rest.split_at(split);
+
+                current_chars += piece.chars().count();
+                current.spans.push(StyledSpan {
+                    text: piece.to_string(),
+                    start,
+                    end: start + piece.len(),
+                    style: span.style.clone(),
+                });
+                start += piece.len();
+                rest = tail;
+            }
+        }
+
+        if !current.spans.is_empty() {
+            wrapped.push(current);
+        }
+    }
+
+    wrapped
+}
@@ -82,6 +82,39 @@ impl LayoutEngine {
         base_font_size
     }
 
+    /// Calculate the font size for the circular Telegram layout
+    fn calculate_circular_font_size(&self, line_count: usize) -> f32 {
+        let radius = self.config.width as f32 / 2.0;
+
+        // Improved font sizing for circular format
+        // Use inscribed square approach for better space utilization
+        let inscribed_square_side = radius * 2.0 / std::f32::consts::SQRT_2;
+        let usable_height = inscribed_square_side * 0.85; // Use 85% for padding
+        let optimal_font_size = usable_height / (line_count as f32 * 1.3);
+
+        // Constrain font size to reasonable bounds
+        optimal_font_size
+            .min(self.config.fontsize * 3.0) // Allow larger fonts for few lines
+            .clamp(10.0, 24.0) // Clamp between minimum readable and maximum size
+    }
+
+    /// Font size `layout` uses for the given code, before per-line scaling
+    pub fn layout_font_size(&self, highlighted_code: &[StyledLine]) -> f32 {
+        if self.config.telegram {
+            let line_count = highlighted_code
+                .iter()
+                .filter(|line| {
+                    !line.spans.is_empty()
+                        && line.spans.iter().any(|span| !span.text.trim().is_empty())
+                })
+                .count()
+                .max(1);
+            self.calculate_circular_font_size(line_count)
+        } else {
+            self.calculate_base_font_size(highlighted_code)
+        }
+    }
+
     /// Layout the highlighted code
     pub fn layout(
         &self,
@@ -123,16 +156,7 @@ impl LayoutEngine {
             line_count, self.config.width
         );
 
-        // Improved font sizing for circular format
-        // Use inscribed square approach for better space utilization
-        let inscribed_square_side = radius * 2.0 / std::f32::consts::SQRT_2;
-        let usable_height = inscribed_square_side * 0.85; // Use 85% for padding
-        let optimal_font_size = usable_height / (line_count as f32 * 1.3);
-
-        // Constrain font size to reasonable bounds
-        let base_font_size = optimal_font_size
-            .min(self.config.fontsize * 3.0) // Allow larger fonts for few lines
-            .clamp(10.0, 24.0); // Clamp between minimum readable and maximum size
+        let base_font_size = self.calculate_circular_font_size(line_count);
 
         println!("Circular font size: {base_font_size}");
 
@@ -12,18 +12,23 @@ pub mod cli;
 pub mod config;
 pub mod error;
 pub mod glyphon;
+pub mod gradient;
 pub mod highlight;
 pub mod layout;
 pub mod nerdfont;
 pub mod output;
 pub mod shader_data;
+pub mod shader_library;
 pub mod toy;
+pub mod transform;
 pub mod webgpu;
 
 // Public re-exports for main library interface
 pub use cli::Cli;
+#[allow(deprecated)]
 pub use config::{Config, GradientColors};
 pub use error::CodeSkewError;
+pub use gradient::{GradientGenerator, GradientSpec};
 pub use highlight::{SpanStyle, StyledLine, StyledSpan, SyntaxHighlighter};
 pub use layout::{LayoutEngine, PositionedLine};
 pub use output::OutputGenerator;
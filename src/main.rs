@@ -1,5 +1,6 @@
 use anyhow::Result;
 use clap::Parser;
+use codeskew::cli::Command;
 use codeskew::{Cli, Config, OutputGenerator};
 use env_logger::{Builder, Target};
 use log::{debug, info, warn};
@@ -31,8 +32,17 @@ async fn main() -> Result<()> {
 
     // Parse command line arguments
     let cli = Cli::parse();
+
+    // Maintenance subcommands don't render anything
+    if let Some(command) = &cli.command {
+        return match command {
+            Command::Fonts { action } => codeskew::nerdfont::commands::run(action).await,
+            Command::Shaders(args) => codeskew::shader_library::commands::run(args).await,
+        };
+    }
+
     debug!("✅ CLI arguments parsed successfully: input={:?}, output={:?}",
-           cli.input.display(),
+           cli.input.as_deref().map(|p| p.display()),
            cli.output.display());
 
     debug!("📋 Pipeline Stage 2: Configuration creation and validation");
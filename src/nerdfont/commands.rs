@@ -0,0 +1,79 @@
+//! `codeskew fonts` subcommand: install, list and prune the Nerd Font cache
+
+use super::loader::{self, FAVS, PINNED_RELEASE, VERSION_ENV};
+use super::quic::{EMBEDDED_FAMILY, embedded_font_bytes};
+use crate::cli::FontsCommand;
+use anyhow::Result;
+
+/// Font installed when `fonts install` is given no names
+const DEFAULT_INSTALL: &str = "FiraCode";
+
+/// Run a `codeskew fonts` action
+pub async fn run(action: &FontsCommand) -> Result<()> {
+    match action {
+        FontsCommand::Install { names, release } => {
+            let release = release.clone().unwrap_or_else(loader::configured_release);
+            let names = if names.is_empty() {
+                vec![DEFAULT_INSTALL.to_string()]
+            } else {
+                names.clone()
+            };
+
+            let mut failures = 0;
+            for name in &names {
+                match loader::install(name, &release).await {
+                    Ok(path) => println!("✅ {name} ({release}) → {}", path.display()),
+                    Err(e) => {
+                        failures += 1;
+                        eprintln!("❌ {name} ({release}): {e:#}");
+                    }
+                }
+            }
+            if failures > 0 {
+                anyhow::bail!("{failures} of {} fonts failed to install", names.len());
+            }
+        }
+        FontsCommand::List => {
+            let configured = loader::configured_release();
+            println!("Cache: {}", loader::cache_dir()?.display());
+            println!(
+                "Release: {configured}{}",
+                if configured == PINNED_RELEASE {
+                    " (pinned)".to_string()
+                } else {
+                    format!(" (from ${VERSION_ENV}, pinned is {PINNED_RELEASE})")
+                }
+            );
+
+            let cached = loader::list_cached()?;
+            if cached.is_empty() {
+                println!("No cached fonts. Try: codeskew fonts install {}", FAVS.join(" "));
+            }
+            for font in cached {
+                let marker = if font.release == configured { "*" } else { " " };
+                println!(
+                    "{marker} {:<16} {:<10} {:>8} KiB",
+                    font.name,
+                    font.release,
+                    font.size / 1024
+                );
+            }
+            println!(
+                "  {:<16} {:<10} {:>8} KiB (built in)",
+                EMBEDDED_FAMILY,
+                "embedded",
+                embedded_font_bytes()?.len() / 1024
+            );
+        }
+        FontsCommand::Prune { all } => {
+            let configured = loader::configured_release();
+            let keep = (!all).then_some(configured.as_str());
+            let removed = loader::prune(keep)?;
+            for path in &removed {
+                println!("🗑️  {}", path.display());
+            }
+            println!("Removed {} cached file(s)", removed.len());
+        }
+    }
+    Ok(())
+}
@@ -6,24 +6,55 @@ use anyhow::{anyhow, Context, Result};
 use directories::ProjectDirs;
 use reqwest::Client;
 use serde::Deserialize;
-use std::{fs, path::PathBuf};
+use std::{fs, path::{Path, PathBuf}};
 use tokio::io::AsyncWriteExt;
 use zip::ZipArchive;
 
 const API_URL: &str = "https://api.github.com/repos/ryanoasis/nerd-fonts/releases/latest";
+const DOWNLOAD_URL: &str = "https://github.com/ryanoasis/nerd-fonts/releases/download";
 const UA: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
+/// Nerd Fonts release downloaded unless `VERSION_ENV` says otherwise, so renders
+/// are reproducible across upstream releases.
+pub const PINNED_RELEASE: &str = "v3.4.0";
+
+/// Env var selecting the release: a tag such as `v3.2.1`, or `latest`.
+pub const VERSION_ENV: &str = "CODESKEW_NERDFONT_VERSION";
+
+/// Env var overriding the zip cache directory.
+pub const CACHE_ENV: &str = "CODESKEW_FONT_CACHE";
+
 /// Optimized JSON structure for GitHub release API response parsing.
 #[derive(Deserialize)]
 struct Release { 
     tag_name: String 
 }
 
-fn cache_dir() -> PathBuf {
+/// A Nerd Font zip in the local cache.
+#[derive(Debug, Clone)]
+pub struct CachedFont {
+    pub name: String,
+    pub release: String,
+    pub path: PathBuf,
+    pub size: u64,
+}
+
+/// Release selected by `VERSION_ENV`, defaulting to `PINNED_RELEASE`.
+pub fn configured_release() -> String {
+    std::env::var(VERSION_ENV)
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .unwrap_or_else(|| PINNED_RELEASE.to_string())
+}
+
+/// Directory holding downloaded `<font>-<release>.zip` archives.
+pub fn cache_dir() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os(CACHE_ENV) {
+        return Ok(PathBuf::from(dir));
+    }
     ProjectDirs::from("ai", "cyrup", "nerdfonts")
-        .expect("home directory")
-        .cache_dir()
-        .to_path_buf()
+        .map(|dirs| dirs.cache_dir().to_path_buf())
+        .ok_or_else(|| anyhow!("no home directory for the font cache; set {CACHE_ENV}"))
 }
 
 /// Async: get latest tag once per session.
@@ -39,27 +70,131 @@ async fn latest_tag(client: &Client) -> Result<String> {
     Ok(r.tag_name)
 }
 
-/// Download → cache → return the bytes of the *NerdFontMono-Regular.ttf* face.
-pub async fn nerd_font_bytes(font_name: &str) -> Result<Vec<u8>> {
+/// Every cached zip, sorted by font name then release.
+pub fn list_cached() -> Result<Vec<CachedFont>> {
+    list_cached_in(&cache_dir()?)
+}
+
+fn list_cached_in(dir: &Path) -> Result<Vec<CachedFont>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut fonts = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(stem) = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".zip"))
+        else {
+            continue;
+        };
+        if let Some((name, release)) = stem.rsplit_once('-') {
+            fonts.push(CachedFont {
+                name: name.to_string(),
+                release: release.to_string(),
+                size: fs::metadata(&path)?.len(),
+                path,
+            });
+        }
+    }
+    fonts.sort_by(|a, b| (&a.name, &a.release).cmp(&(&b.name, &b.release)));
+    Ok(fonts)
+}
+
+/// Cached zip for `font_name` at `release`; for `latest` the newest cached release.
+fn cached_zip(cache: &Path, font_name: &str, release: &str) -> Option<PathBuf> {
+    if release == "latest" {
+        return list_cached_in(cache)
+            .ok()?
+            .into_iter()
+            .filter(|font| font.name == font_name)
+            .max_by_key(|font| fs::metadata(&font.path).and_then(|m| m.modified()).ok())
+            .map(|font| font.path);
+    }
+    let path = cache.join(format!("{font_name}-{release}.zip"));
+    path.exists().then_some(path)
+}
+
+/// Download `font_name` at `release` into the cache unless it is already there.
+///
+/// Only `latest` needs the releases API; pinned tags go straight to the asset URL.
+/// Archives are written to a `.part` file and renamed, so an interrupted download
+/// never leaves a truncated zip in the cache.
+pub async fn install(font_name: &str, release: &str) -> Result<PathBuf> {
+    install_into(&cache_dir()?, DOWNLOAD_URL, font_name, release).await
+}
+
+/// `install` into `cache`, downloading from `{download_url}/{tag}/{font_name}.zip`.
+async fn install_into(cache: &Path, download_url: &str, font_name: &str, release: &str) -> Result<PathBuf> {
+    if let Some(path) = cached_zip(cache, font_name, release) {
+        return Ok(path);
+    }
+
     let client = Client::builder().user_agent(UA).build()?;
-    let tag = latest_tag(&client).await?;
+    let tag = if release == "latest" {
+        latest_tag(&client).await?
+    } else {
+        release.to_string()
+    };
 
-    let cache = cache_dir();
-    fs::create_dir_all(&cache)?;
+    fs::create_dir_all(cache)?;
     let zip_path = cache.join(format!("{font_name}-{tag}.zip"));
+    if zip_path.exists() {
+        return Ok(zip_path);
+    }
 
-    if !zip_path.exists() {
-        let url = format!(
-            "https://github.com/ryanoasis/nerd-fonts/releases/download/{tag}/{font_name}.zip"
-        );
-        let mut resp = client.get(&url).send().await?.error_for_status()?;
-        let mut file = tokio::fs::File::create(&zip_path).await?;
-        while let Some(chunk) = resp.chunk().await? {
-            file.write_all(&chunk).await?;
+    let url = format!("{download_url}/{tag}/{font_name}.zip");
+    let part_path = zip_path.with_extension("zip.part");
+    let mut resp = client.get(&url).send().await?.error_for_status()?;
+    let mut file = tokio::fs::File::create(&part_path).await?;
+    while let Some(chunk) = resp.chunk().await? {
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+    drop(file);
+
+    // Refuse to cache an archive without a usable face
+    if let Err(e) = mono_regular_face(&fs::read(&part_path)?, font_name) {
+        let _ = fs::remove_file(&part_path);
+        return Err(e);
+    }
+    fs::rename(&part_path, &zip_path)?;
+    Ok(zip_path)
+}
+
+/// Remove cached zips (and leftover partial downloads) whose release is not `keep`.
+///
+/// With `keep = None` the whole cache is cleared. Returns the removed paths.
+pub fn prune(keep: Option<&str>) -> Result<Vec<PathBuf>> {
+    prune_in(&cache_dir()?, keep)
+}
+
+fn prune_in(dir: &Path, keep: Option<&str>) -> Result<Vec<PathBuf>> {
+    let mut removed = Vec::new();
+    if !dir.exists() {
+        return Ok(removed);
+    }
+
+    for font in list_cached_in(dir)? {
+        if keep != Some(font.release.as_str()) {
+            fs::remove_file(&font.path)?;
+            removed.push(font.path);
+        }
+    }
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "part") {
+            fs::remove_file(&path)?;
+            removed.push(path);
         }
     }
+    Ok(removed)
+}
 
-    let zip_data = fs::read(&zip_path)?;
+/// Extract the *NerdFontMono-Regular.ttf* face from a release zip.
+fn mono_regular_face(zip_data: &[u8], font_name: &str) -> Result<Vec<u8>> {
     let reader = std::io::Cursor::new(zip_data);
     let mut zip = ZipArchive::new(reader)?;
     for i in 0..zip.len() {
@@ -73,6 +208,15 @@ pub async fn nerd_font_bytes(font_name: &str) -> Result<Vec<u8>> {
     Err(anyhow!("regular mono face not found in {font_name}"))
 }
 
+/// Cache → (download) → return the bytes of the *NerdFontMono-Regular.ttf* face.
+///
+/// Uses the release from `configured_release`. A cached archive is used without
+/// touching the network, so renders work offline once a font is installed.
+pub async fn nerd_font_bytes(font_name: &str) -> Result<Vec<u8>> {
+    let zip_path = install(font_name, &configured_release()).await?;
+    mono_regular_face(&fs::read(&zip_path)?, font_name)
+}
+
 /// Lock-free async font loading with zero-allocation error propagation
 pub async fn nerd_font_bytes_async(font_name: &str) -> Result<Vec<u8>> {
     nerd_font_bytes(font_name).await
@@ -82,4 +226,121 @@ pub async fn nerd_font_bytes_async(font_name: &str) -> Result<Vec<u8>> {
 pub const FAVS: &[&str] = &[
     "JetBrainsMono", "Iosevka", "Hack", "FiraCode", "Meslo", "SourceCodePro",
     "CaskaydiaCove", "VictorMono", "UbuntuMono", "Monaspace", "Terminus", "ProggyClean",
-];
\ No newline at end of file
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::AsyncReadExt;
+
+    /// A release zip holding one file called `face`
+    fn font_zip(face: &str) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        zip.start_file(face, zip::write::SimpleFileOptions::default()).unwrap();
+        zip.write_all(b"not really a font").unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    /// Answer every request on a local port with `body`, announced as `length` bytes so a
+    /// larger `length` cuts the download off. Returns the base URL and a request counter.
+    async fn serve(body: Vec<u8>, length: usize) -> (String, Arc<AtomicUsize>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut request = [0u8; 4096];
+                let _ = stream.read(&mut request).await;
+                let header = format!("HTTP/1.1 200 OK\r\nContent-Length: {length}\r\nConnection: close\r\n\r\n");
+                let _ = stream.write_all(header.as_bytes()).await;
+                let _ = stream.write_all(&body).await;
+            }
+        });
+        (url, requests)
+    }
+
+    fn file_names(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn install_renames_the_part_file_once_the_archive_checks_out() {
+        let cache = tempfile::tempdir().unwrap();
+        let zip = font_zip("Fake/FakeNerdFontMono-Regular.ttf");
+        let (url, requests) = serve(zip.clone(), zip.len()).await;
+
+        let path = install_into(cache.path(), &url, "Fake", "v1.0").await.expect("install");
+        assert_eq!(path, cache.path().join("Fake-v1.0.zip"));
+        assert_eq!(fs::read(&path).unwrap(), zip);
+        assert_eq!(file_names(cache.path()), ["Fake-v1.0.zip"]);
+
+        // Installed fonts come from the cache
+        install_into(cache.path(), &url, "Fake", "v1.0").await.expect("cached");
+        install_into(cache.path(), &url, "Fake", "latest").await.expect("cached");
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_installs_never_leave_a_zip() {
+        let cache = tempfile::tempdir().unwrap();
+
+        // A cut-off download only leaves the partial file, for `prune` to clear
+        let zip = font_zip("FakeNerdFontMono-Regular.ttf");
+        let (url, _) = serve(zip.clone(), zip.len() + 100).await;
+        assert!(install_into(cache.path(), &url, "Fake", "v1.0").await.is_err());
+        assert_eq!(file_names(cache.path()), ["Fake-v1.0.zip.part"]);
+
+        // An archive without the mono face is thrown away
+        let zip = font_zip("FakeNerdFont-Bold.ttf");
+        let (url, _) = serve(zip.clone(), zip.len()).await;
+        assert!(install_into(cache.path(), &url, "Fake", "v1.0").await.is_err());
+        assert!(file_names(cache.path()).is_empty());
+    }
+
+    #[test]
+    fn cached_fonts_are_listed_and_pruned_by_release() {
+        let cache = tempfile::tempdir().unwrap();
+        for (file, size) in [
+            ("Hack-v3.4.0.zip", 3),
+            ("FiraCode-v3.4.0.zip", 1),
+            ("Hack-v3.2.1.zip", 2),
+            ("Iosevka-v3.4.0.zip.part", 1),
+            ("notes.txt", 1),
+        ] {
+            fs::write(cache.path().join(file), vec![0u8; size]).unwrap();
+        }
+
+        let listed: Vec<(String, String, u64)> = list_cached_in(cache.path())
+            .unwrap()
+            .into_iter()
+            .map(|font| (font.name, font.release, font.size))
+            .collect();
+        let expected = [("FiraCode", "v3.4.0", 1), ("Hack", "v3.2.1", 2), ("Hack", "v3.4.0", 3)];
+        assert_eq!(
+            listed,
+            expected.map(|(name, release, size)| (name.to_string(), release.to_string(), size))
+        );
+
+        // Other releases and partial downloads go; files that aren't fonts stay
+        let mut removed = prune_in(cache.path(), Some("v3.4.0")).unwrap();
+        removed.sort();
+        assert_eq!(
+            removed,
+            [cache.path().join("Hack-v3.2.1.zip"), cache.path().join("Iosevka-v3.4.0.zip.part")]
+        );
+        assert_eq!(file_names(cache.path()), ["FiraCode-v3.4.0.zip", "Hack-v3.4.0.zip", "notes.txt"]);
+
+        prune_in(cache.path(), None).unwrap();
+        assert_eq!(file_names(cache.path()), ["notes.txt"]);
+    }
+}
@@ -1,5 +1,6 @@
 //! Nerd Font management system
 
+pub mod commands;
 pub mod loader;
 pub mod quic;
 
@@ -49,11 +49,15 @@ impl From<&str> for NerdFont {
 #[cfg(feature = "zstd")] 
 use zstd::decode_all;
 
+/// Family name of the face that ships inside the binary
+pub const EMBEDDED_FAMILY: &str = "Fira Mono";
+
+// Packed by build.rs from assets/fonts/FiraMono-Medium.ttf
 #[cfg(feature = "zstd")] 
-const EMBEDDED_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/iosevka.zst"));
+const EMBEDDED_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/embedded_mono.zst"));
 
 #[cfg(not(feature = "zstd"))] 
-const EMBEDDED_BYTES: &[u8] = &[];
+const EMBEDDED_BYTES: &[u8] = include_bytes!("../../assets/fonts/FiraMono-Medium.ttf");
 
 /// Errors that may occur while loading fonts.
 #[derive(Debug, Error)]
@@ -84,25 +88,14 @@ pub async fn load_with_family_async(font_system: &mut glyphon::FontSystem, font_
     match super::loader::nerd_font_bytes(github_name).await {
         Ok(font_bytes) => {
             font_system.db_mut().load_font_data(font_bytes);
-            log::info!("Downloaded and loaded {github_name} Nerd Font from GitHub");
+            log::info!("Loaded {github_name} Nerd Font (cache or GitHub)");
             Ok(())
         }
         Err(e) => {
             log::warn!("Failed to download {github_name} from GitHub: {e}, using embedded fallback");
             
             // Fallback to embedded font
-            #[cfg(feature = "zstd")]
-            {
-                let bytes = decode_all(&*EMBEDDED_BYTES)?;
-                font_system.db_mut().load_font_data(bytes);
-            }
-            #[cfg(not(feature = "zstd"))]
-            {
-                font_system.db_mut().load_font_data(EMBEDDED_BYTES.to_vec());
-            }
-            
-            log::debug!("Loaded embedded fallback font ({} KiB)", EMBEDDED_BYTES.len() / 1024);
-            Ok(())
+            load_embedded(font_system)
         }
     }
 }
@@ -117,29 +110,37 @@ pub async fn load_with_family(font_system: &mut glyphon::FontSystem, font_family
     match super::loader::nerd_font_bytes_async(github_name).await {
         Ok(font_bytes) => {
             font_system.db_mut().load_font_data(font_bytes);
-            log::info!("Downloaded and loaded {github_name} Nerd Font from GitHub");
+            log::info!("Loaded {github_name} Nerd Font (cache or GitHub)");
             Ok(())
         }
         Err(e) => {
             log::warn!("Failed to download {github_name} from GitHub: {e}, using embedded fallback");
             
             // Fallback to embedded font
-            #[cfg(feature = "zstd")]
-            {
-                let bytes = decode_all(&*EMBEDDED_BYTES)?;
-                font_system.db_mut().load_font_data(bytes);
-            }
-            #[cfg(not(feature = "zstd"))]
-            {
-                font_system.db_mut().load_font_data(EMBEDDED_BYTES.to_vec());
-            }
-            
-            log::debug!("Loaded embedded fallback font ({} KiB)", EMBEDDED_BYTES.len() / 1024);
-            Ok(())
+            load_embedded(font_system)
         }
     }
 }
 
+/// Uncompressed bytes of the embedded fallback face.
+pub fn embedded_font_bytes() -> Result<Vec<u8>, FontLoadError> {
+    #[cfg(feature = "zstd")]
+    {
+        Ok(decode_all(EMBEDDED_BYTES)?)
+    }
+    #[cfg(not(feature = "zstd"))]
+    {
+        Ok(EMBEDDED_BYTES.to_vec())
+    }
+}
+
+/// Loads the embedded fallback face (`EMBEDDED_FAMILY`) into a `FontSystem`.
+pub fn load_embedded(font_system: &mut glyphon::FontSystem) -> Result<(), FontLoadError> {
+    font_system.db_mut().load_font_data(embedded_font_bytes()?);
+    log::debug!("Loaded embedded fallback font ({} KiB packed)", EMBEDDED_BYTES.len() / 1024);
+    Ok(())
+}
+
 /// Load arbitrary font bytes into a `FontSystem`.
 pub fn load_font_bytes(font_system: &mut glyphon::FontSystem, bytes: &[u8]) {
     font_system.db_mut().load_font_data(bytes.to_vec());
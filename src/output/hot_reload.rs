@@ -0,0 +1,141 @@
+//! File watching for hot reload in the live preview
+
+use crate::error::CodeSkewError;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use winit::event_loop::EventLoopProxy;
+
+/// Watches the files a preview was built from and wakes the event loop when one changes
+///
+/// Directories are watched rather than the files themselves, because editors often save
+/// by writing a new file and renaming it over the old one.
+pub struct ReloadWatcher {
+    watcher: RecommendedWatcher,
+    files: Arc<Mutex<HashSet<PathBuf>>>,
+    dirs: HashSet<PathBuf>,
+    changed: Arc<AtomicBool>,
+}
+
+impl ReloadWatcher {
+    /// Create a watcher that sends a user event through `proxy` on every relevant change
+    pub fn new(proxy: EventLoopProxy<()>) -> Result<Self, CodeSkewError> {
+        Self::with_wake(move || {
+            let _ = proxy.send_event(());
+        })
+    }
+
+    /// Create a watcher that calls `wake` on every relevant change
+    fn with_wake(wake: impl Fn() + Send + 'static) -> Result<Self, CodeSkewError> {
+        let files: Arc<Mutex<HashSet<PathBuf>>> = Arc::default();
+        let changed = Arc::new(AtomicBool::new(false));
+
+        let watched = files.clone();
+        let flag = changed.clone();
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let Ok(event) = event else { return };
+            if matches!(event.kind, EventKind::Access(_)) {
+                return;
+            }
+            let watched = watched.lock().unwrap();
+            if event.paths.iter().any(|path| watched.contains(path)) {
+                flag.store(true, Ordering::Release);
+                wake();
+            }
+        })
+        .map_err(|e| CodeSkewError::RenderingError(format!("Failed to start file watcher: {e}")))?;
+
+        Ok(Self {
+            watcher,
+            files,
+            dirs: HashSet::new(),
+            changed,
+        })
+    }
+
+    /// Replace the set of watched files; files that don't exist yet are watched for creation
+    pub fn watch<I, P>(&mut self, paths: I)
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let files: HashSet<PathBuf> = paths.into_iter().filter_map(|p| absolute(p.as_ref())).collect();
+
+        for dir in files.iter().filter_map(|file| file.parent()) {
+            if !self.dirs.contains(dir) {
+                match self.watcher.watch(dir, RecursiveMode::NonRecursive) {
+                    Ok(()) => {
+                        self.dirs.insert(dir.to_path_buf());
+                    }
+                    Err(e) => println!("⚠️  Cannot watch {}: {}", dir.display(), e),
+                }
+            }
+        }
+
+        *self.files.lock().unwrap() = files;
+    }
+
+    /// Whether a watched file changed since the last call
+    pub fn take_changed(&self) -> bool {
+        self.changed.swap(false, Ordering::AcqRel)
+    }
+}
+
+/// The path notify reports for `path`: canonical directory plus file name
+fn absolute(path: &Path) -> Option<PathBuf> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    Some(parent.canonicalize().ok()?.join(path.file_name()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    /// Whether the watcher reports a change within a second, clearing it
+    fn changes_soon(watcher: &ReloadWatcher) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while Instant::now() < deadline {
+            if watcher.take_changed() {
+                // Let the rest of the save's events arrive, so they don't count for the next one
+                std::thread::sleep(Duration::from_millis(100));
+                watcher.take_changed();
+                return true;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        false
+    }
+
+    #[test]
+    fn watched_files_trigger_on_writes_and_rename_over_saves() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let shader = dir.path().join("shader.wgsl");
+        std::fs::write(&shader, "// v1").expect("shader");
+
+        let mut watcher = ReloadWatcher::with_wake(|| {}).expect("watcher");
+        watcher.watch([&shader]);
+        assert!(!watcher.take_changed());
+
+        // Another file in the same directory is not a reason to reload
+        std::fs::write(dir.path().join("notes.txt"), "unrelated").expect("unrelated file");
+        assert!(!changes_soon(&watcher));
+
+        std::fs::write(&shader, "// v2").expect("write");
+        assert!(changes_soon(&watcher));
+
+        // Editors write a new file and rename it over the old one
+        let swap = dir.path().join(".shader.wgsl.swp");
+        std::fs::write(&swap, "// v3").expect("swap file");
+        std::fs::rename(&swap, &shader).expect("rename");
+        assert!(changes_soon(&watcher));
+
+        std::fs::write(dir.path().join("notes.txt"), "still unrelated").expect("unrelated file");
+        assert!(!changes_soon(&watcher));
+    }
+}
@@ -1,5 +1,6 @@
 //! Output module for CodeSkew rendering
 
+pub mod hot_reload;
 pub mod output_generator;
 pub mod save_methods;
 
@@ -1,41 +1,25 @@
 //! Core OutputGenerator for high-performance code rendering with zero allocation
 
-use crate::cli::OutputFormat;
+use crate::cli::{OutputFormat, SvgBackground};
 use crate::config::Config;
 use crate::error::CodeSkewError;
-use crate::highlight::SyntaxHighlighter;
+use crate::gradient::GradientGenerator;
+use crate::highlight::{StyledLine, SyntaxHighlighter, wrap_lines};
 use crate::layout::{LayoutEngine, PositionedLine};
 use crate::output::SaveMethods;
-use crate::toy::{WgpuToyRenderer, init_wgpu};
+use crate::shader_library::{LibraryFile, ShaderLibrary, ShaderMetadata};
+use crate::toy::{MAX_DEBUG_RECORDS, WgpuToyRenderer, init_wgpu_headless};
 use anyhow::Result;
 use image::RgbaImage;
+use std::path::PathBuf;
 use std::time::Instant;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 use crate::glyphon::GlyphonTextureRenderer;
 use crate::nerdfont;
 use crate::glyphon::font_system::create_font_system_with_nerd_font;
 
-/// Shader metadata structure matching wgpu-compute-toy JSON format
-#[derive(Debug, Deserialize, Serialize)]
-struct ShaderMetadata {
-    uniforms: Vec<ShaderUniform>,
-    textures: Vec<ShaderTexture>,
-    #[serde(rename = "float32Enabled")]
-    float32_enabled: Option<bool>,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-struct ShaderUniform {
-    name: String,
-    value: f32,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-struct ShaderTexture {
-    img: String,
-    thumb: Option<String>,
-    url: Option<String>,
-}
+/// Quiet time after the last change to a watched file before the live preview reloads
+const RELOAD_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(50);
 
 /// Template data for MiniJinja WGSL generation
 #[derive(Debug)]
@@ -46,6 +30,27 @@ struct TemplateData {
     max_chars_per_line: usize,
 }
 
+/// Parse `.wgsl.json` metadata
+fn read_shader_metadata(file: &LibraryFile) -> Option<ShaderMetadata> {
+    println!("🔧 DEBUG: Found JSON metadata at {}", file.display());
+    ShaderMetadata::parse(file).ok()
+}
+
+/// A shader template and its MiniJinja rendering, kept together for error mapping
+struct RenderedShader {
+    file: String,
+    /// The template on disk, `None` for embedded shaders
+    path: Option<PathBuf>,
+    template: String,
+    wgsl: String,
+}
+
+/// The code rendered by Glyphon, with the row height the blur pass focuses by
+struct TextTexture {
+    texture: wgpu::Texture,
+    line_height: f32,
+}
+
 #[derive(Debug, Serialize)]
 struct PositionData {
     line_idx: usize,
@@ -61,6 +66,7 @@ pub struct OutputGenerator {
     config: Config,
     layout_engine: LayoutEngine,
     highlighter: SyntaxHighlighter,
+    library: ShaderLibrary,
 
     // Pre-allocated buffers for zero-allocation operation in hot paths
     rgba_buffer: Vec<u8>,
@@ -69,6 +75,9 @@ pub struct OutputGenerator {
     pixel_count: usize,
     buffer_size: usize,
 
+    // Shader file and its includes from the last compile, watched by the live preview
+    shader_files: Vec<PathBuf>,
+
     // Pre-calculated values for performance
     width_f32: f32,
     height_f32: f32,
@@ -82,6 +91,7 @@ impl OutputGenerator {
     pub fn new(config: Config) -> Result<Self, CodeSkewError> {
         let layout_engine = LayoutEngine::new(&config);
         let highlighter = SyntaxHighlighter::new();
+        let library = ShaderLibrary::new(&config.shader_dirs);
 
         // Pre-calculate dimensions for optimal performance
         let width_u32 = config.width;
@@ -104,11 +114,13 @@ impl OutputGenerator {
             config,
             layout_engine,
             highlighter,
+            library,
             rgba_buffer,
             temp_buffer,
             shader_buffer,
             pixel_count,
             buffer_size,
+            shader_files: Vec::new(),
             width_f32,
             height_f32,
             width_u32,
@@ -116,19 +128,43 @@ impl OutputGenerator {
         })
     }
 
-    /// Generate output using toy renderer with blazing performance and zero allocation
-    #[inline]
-    pub async fn generate(&mut self, code: &str) -> Result<(), CodeSkewError> {
-        let start_time = Instant::now();
-
+    /// Highlight the input code and lay it out, applying `--max-lines` and `--wrap`
+    fn highlight_and_layout(
+        &mut self,
+        code: &str,
+    ) -> Result<(Vec<StyledLine>, Vec<PositionedLine>), CodeSkewError> {
         // Syntax highlighting - optimized hot path with zero allocation
-        let highlighted_code = self
+        let mut highlighted_code = self
             .highlighter
             .highlight(code, &self.config.input, &self.config.theme)
             .map_err(|e| CodeSkewError::SyntaxError(format!("Failed to highlight code: {e}")))?;
 
+        // Explicit line policy: nothing is clipped unless --max-lines asks for it
+        if let Some(max_lines) = self.config.max_lines
+            && highlighted_code.len() > max_lines
+        {
+            println!(
+                "⚠️  Truncating input from {} to {} lines (--max-lines)",
+                highlighted_code.len(),
+                max_lines
+            );
+            highlighted_code.truncate(max_lines);
+        }
+        if let Some(wrap) = self.config.wrap {
+            highlighted_code = wrap_lines(highlighted_code, wrap);
+        }
+
         // Layout generation - pre-allocated structures
         let layout = self.layout_engine.layout(&highlighted_code)?;
+        Ok((highlighted_code, layout))
+    }
+
+    /// Generate output using toy renderer with blazing performance and zero allocation
+    #[inline]
+    pub async fn generate(&mut self, code: &str) -> Result<(), CodeSkewError> {
+        let start_time = Instant::now();
+
+        let (highlighted_code, layout) = self.highlight_and_layout(code)?;
 
         // Handle live preview separately - no layered rendering needed
         if self.config.format == OutputFormat::Wgpu {
@@ -137,39 +173,38 @@ impl OutputGenerator {
             return Ok(());
         }
 
-        // For static outputs, render using toy renderer
-        let wgpu_context = init_wgpu(self.width_u32, self.height_u32, "")
-            .await
-            .map_err(|e| {
-                CodeSkewError::RenderingError(format!("Failed to create WGPU context: {e}"))
-            })?;
-        
-        // Render with toy renderer (optionally with text if shader supports it)
-        let buffer_data = self.render_with_toy(wgpu_context, &layout).await?;
-        
-        // Convert buffer to image
-        let image = RgbaImage::from_raw(
-            self.width_u32,
-            self.height_u32,
-            buffer_data,
-        )
-        .ok_or_else(|| {
-            CodeSkewError::RenderingError(
-                "Failed to create RgbaImage from buffer".to_string(),
-            )
-        })?;
-
-        // Save output with optimized format handling
-        let save_methods = SaveMethods::new(&self.config);
+        // Animations go through the same text texture + shader pipeline, one render per frame
+        let animated_webp = self.config.format == OutputFormat::Webp && self.config.animate;
         match self.config.format {
-            OutputFormat::Png => save_methods.save_png_optimized(image).await?,
-            OutputFormat::Svg => {
-                return Err(CodeSkewError::ConfigError(
-                    "SVG not supported with WGSL shaders".to_string(),
-                ));
+            OutputFormat::Svg => self.generate_svg(&highlighted_code, &layout).await?,
+            OutputFormat::Gif => {
+                let frames = self.render_animation(&layout).await?;
+                SaveMethods::new(&self.config)
+                    .save_gif_animation_optimized(frames)
+                    .await?;
+            }
+            OutputFormat::Webp if animated_webp => {
+                let frames = self.render_animation(&layout).await?;
+                SaveMethods::new(&self.config)
+                    .save_webp_animation_optimized(frames)
+                    .await?;
+            }
+            OutputFormat::Png | OutputFormat::Webp => {
+                // Render with toy renderer (optionally with text if shader supports it)
+                let wgpu_context = self.create_headless_context().await?;
+                let buffer_data = self.render_with_toy(wgpu_context, &layout).await?;
+
+                // Convert buffer to image
+                let image = self.buffer_to_image(buffer_data)?;
+
+                // Save output with optimized format handling
+                let save_methods = SaveMethods::new(&self.config);
+                if self.config.format == OutputFormat::Png {
+                    save_methods.save_png_optimized(image).await?;
+                } else {
+                    save_methods.save_webp_optimized(image).await?;
+                }
             }
-            OutputFormat::Gif => save_methods.save_gif_animation_optimized(&layout).await?,
-            OutputFormat::Webp => save_methods.save_webp_optimized(image).await?,
             OutputFormat::Wgpu => unreachable!(), // Already handled above
         }
 
@@ -183,6 +218,67 @@ impl OutputGenerator {
     }
 
 
+    /// Static outputs never need a window - render offscreen so this works without a display
+    async fn create_headless_context(&self) -> Result<crate::toy::WgpuContext, CodeSkewError> {
+        init_wgpu_headless(self.width_u32, self.height_u32, self.config.backend)
+            .await
+            .map_err(|e| {
+                CodeSkewError::RenderingError(format!("Failed to create WGPU context: {e}"))
+            })
+    }
+
+    /// Render every frame of an animated output
+    async fn render_animation(
+        &mut self,
+        layout: &[PositionedLine],
+    ) -> Result<Vec<RgbaImage>, CodeSkewError> {
+        let wgpu_context = self.create_headless_context().await?;
+        let mut toy_renderer = self.prepare_toy_renderer(wgpu_context, layout, true).await?;
+        self.render_animation_frames(&mut toy_renderer).await
+    }
+
+    /// Write the code as selectable SVG text over a shader or gradient background
+    async fn generate_svg(
+        &mut self,
+        highlighted_code: &[StyledLine],
+        layout: &[PositionedLine],
+    ) -> Result<(), CodeSkewError> {
+        let font_size = self.layout_engine.layout_font_size(highlighted_code);
+
+        // The text is emitted as vector <text>, so the shader frame is rendered without it
+        let background = match self.config.svg_background {
+            SvgBackground::Image => {
+                let wgpu_context = self.create_headless_context().await?;
+                let mut toy_renderer = self.prepare_toy_renderer(wgpu_context, layout, false).await?;
+                let buffer_data = self.render_still(&mut toy_renderer).await?;
+                Some(self.buffer_to_image(buffer_data)?)
+            }
+            SvgBackground::Gradient => None,
+        };
+
+        SaveMethods::new(&self.config)
+            .save_svg_optimized(layout, font_size, background)
+            .await
+    }
+
+    /// Render `shader` behind `code` as a still image, as `--shader <name>` would
+    pub async fn render_shader_preview(&mut self, shader: &str, code: &str) -> Result<RgbaImage, CodeSkewError> {
+        self.config.shader = shader.to_string();
+        let (_, layout) = self.highlight_and_layout(code)?;
+        let wgpu_context = self.create_headless_context().await?;
+        let buffer_data = self.render_with_toy(wgpu_context, &layout).await?;
+        self.buffer_to_image(buffer_data)
+    }
+
+    /// Template, preprocess and compile `shader` for `code` without rendering a frame
+    pub async fn check_shader(&mut self, shader: &str, code: &str) -> Result<(), CodeSkewError> {
+        self.config.shader = shader.to_string();
+        let (_, layout) = self.highlight_and_layout(code)?;
+        let wgpu_context = self.create_headless_context().await?;
+        self.prepare_toy_renderer(wgpu_context, &layout, false).await?;
+        Ok(())
+    }
+
     /// Render using toy shaders with optimized performance
     #[inline]
     async fn render_with_toy(
@@ -190,11 +286,155 @@ impl OutputGenerator {
         wgpu_context: crate::toy::WgpuContext,
         layout: &[PositionedLine],
     ) -> Result<Vec<u8>, CodeSkewError> {
+        let mut toy_renderer = self.prepare_toy_renderer(wgpu_context, layout, true).await?;
+
+        // Render background to buffer with optimized staging buffer handling
+        let background_data = self.render_still(&mut toy_renderer).await?;
+        
+        println!("🔧 DEBUG: Background data length: {}", background_data.len());
+        if background_data.len() >= 16 {
+            println!("🔧 DEBUG: First 16 bytes: {:?}", &background_data[0..16]);
+        }
+        
+        // Check for non-zero data
+        let non_zero_count = background_data.iter().filter(|&&x| x != 0).count();
+        println!("🔧 DEBUG: Non-zero bytes in background: {}/{}", non_zero_count, background_data.len());
+        
+        Ok(background_data)
+    }
+
+    /// Render the still frame at `--time`, after `--warmup-frames` and averaged over `--accumulate`
+    ///
+    /// Warm-up frames step at `1/fps` and end at the capture time (never before 0).
+    async fn render_still(&self, toy_renderer: &mut WgpuToyRenderer) -> Result<Vec<u8>, CodeSkewError> {
+        let frame_delta = 1.0 / self.config.fps;
+        let capture_time = self.config.time;
+        let warmup_frames = self.config.warmup_frames;
+
+        if warmup_frames > 0 {
+            println!("🔥 Warming up shader for {warmup_frames} frames");
+        }
+        for frame_idx in 0..warmup_frames {
+            let remaining = (warmup_frames - frame_idx) as f32;
+            toy_renderer.step_at((capture_time - remaining * frame_delta).max(0.0), frame_delta);
+        }
+
+        let result = if self.config.accumulate > 1 {
+            println!("🎞️ Accumulating {} frames", self.config.accumulate);
+            toy_renderer
+                .render_accumulated_at(capture_time, frame_delta, self.config.accumulate)
+                .await
+        } else {
+            toy_renderer.render_to_buffer_at(capture_time, frame_delta).await
+        };
+        let buffer_data = result
+            .map_err(|e| CodeSkewError::RenderingError(format!("Background render failed: {e}")))?;
+
+        self.check_shader_asserts(toy_renderer)?;
+        self.report_shader_debug(toy_renderer)?;
+        Ok(buffer_data)
+    }
+
+    /// Print and/or dump the `debugLog` records of the rendered frames
+    fn report_shader_debug(&self, toy_renderer: &mut WgpuToyRenderer) -> Result<(), CodeSkewError> {
+        let log = toy_renderer.take_debug_log();
+
+        if self.config.shader_debug {
+            for record in &log.records {
+                let [x, y, z, w] = record.value;
+                println!("🐛 frame {} id {}: ({x}, {y}, {z}, {w})", record.frame, record.id);
+            }
+        }
+        if log.dropped > 0 {
+            println!(
+                "⚠️ {} debugLog calls were dropped; each frame keeps the first {MAX_DEBUG_RECORDS}",
+                log.dropped
+            );
+        }
+
+        if let Some(path) = &self.config.shader_debug_json {
+            let json = serde_json::to_string_pretty(&log).map_err(|e| {
+                CodeSkewError::OutputError(format!("Failed to encode shader debug log: {e}"))
+            })?;
+            std::fs::write(path, json).map_err(|e| {
+                CodeSkewError::OutputError(format!(
+                    "Failed to write shader debug log {}: {e}",
+                    path.display()
+                ))
+            })?;
+            println!("🐛 Shader debug log written to: {}", path.display());
+        }
+
+        Ok(())
+    }
+
+    /// Report `#assert` failures from the rendered frames, failing with `--fail-on-assert`
+    fn check_shader_asserts(&self, toy_renderer: &mut WgpuToyRenderer) -> Result<(), CodeSkewError> {
+        let mut errors = toy_renderer
+            .take_assert_failures()
+            .iter()
+            .map(|failure| toy_renderer.assert_error(failure))
+            .collect::<Vec<_>>();
+        for error in &errors {
+            println!("⚠️ {error}");
+        }
+
+        if self.config.fail_on_assert && !errors.is_empty() {
+            return Err(errors.swap_remove(0));
+        }
+        Ok(())
+    }
+
+    /// Render `duration * fps` frames, advancing `Time.elapsed` by `1/fps` each frame
+    async fn render_animation_frames(
+        &self,
+        toy_renderer: &mut WgpuToyRenderer,
+    ) -> Result<Vec<RgbaImage>, CodeSkewError> {
+        let frame_delta = 1.0 / self.config.fps;
+        let frame_count = (self.config.duration * self.config.fps).round().max(1.0) as u32;
+        log::debug!("Rendering {frame_count} animation frames at {} fps", self.config.fps);
+
+        let mut frames = Vec::with_capacity(frame_count as usize);
+        for frame_idx in 0..frame_count {
+            let buffer_data = toy_renderer
+                .render_to_buffer_at(frame_idx as f32 * frame_delta, frame_delta)
+                .await
+                .map_err(|e| {
+                    CodeSkewError::AnimationError(format!("Frame {frame_idx} render failed: {e}"))
+                })?;
+            frames.push(self.buffer_to_image(buffer_data)?);
+        }
+
+        self.check_shader_asserts(toy_renderer)?;
+        self.report_shader_debug(toy_renderer)?;
+        Ok(frames)
+    }
+
+    /// Wrap a `render_to_buffer` readback in an image of the output size
+    fn buffer_to_image(&self, buffer_data: Vec<u8>) -> Result<RgbaImage, CodeSkewError> {
+        RgbaImage::from_raw(self.width_u32, self.height_u32, buffer_data).ok_or_else(|| {
+            CodeSkewError::RenderingError("Failed to create RgbaImage from buffer".to_string())
+        })
+    }
+
+    /// Create the toy renderer with text texture, channel textures and compiled shader
+    ///
+    /// With `render_text` unset the text channel stays empty, leaving only the background.
+    async fn prepare_toy_renderer(
+        &mut self,
+        wgpu_context: crate::toy::WgpuContext,
+        layout: &[PositionedLine],
+        render_text: bool,
+    ) -> Result<WgpuToyRenderer, CodeSkewError> {
         println!("🔧 DEBUG: Starting toy renderer");
         let mut toy_renderer = WgpuToyRenderer::new(wgpu_context);
+        toy_renderer.set_shader_library(self.library.clone());
+        toy_renderer.set_shader_debug(self.config.shader_debug || self.config.shader_debug_json.is_some());
 
         // Render text using glyphon texture renderer
-        self.render_glyphon_to_texture(&mut toy_renderer, layout).await?;
+        if render_text {
+            self.render_glyphon_to_texture(&mut toy_renderer, layout).await?;
+        }
 
         // Load actual texture file into channel0
         if let Err(e) = self.load_shader_textures(&mut toy_renderer, &self.config.shader).await {
@@ -211,36 +451,95 @@ impl OutputGenerator {
             println!("🔧 DEBUG: Successfully loaded shader textures");
         }
 
-        // Process shader through unified MiniJinja template system
-        let rendered_wgsl = self.process_shader_template(layout).await?;
-        println!("🔧 DEBUG: Built unified shader, length: {}", rendered_wgsl.len());
+        // Shader parameters shape `struct Custom`, so they must be set before compiling
+        self.apply_shader_params(&mut toy_renderer, &self.config.params)?;
 
-        // Compile shader with efficient error handling
-        if let Some(source_map) = toy_renderer.preprocess_async(&rendered_wgsl).await {
-            println!("🔧 DEBUG: Shader preprocessing successful");
-            toy_renderer.compile(source_map);
-            println!("🔧 DEBUG: Shader compilation successful");
-        } else {
-            println!("🔧 DEBUG: Shader preprocessing FAILED");
-            return Err(CodeSkewError::RenderingError("Shader compilation failed".to_string()));
+        // Process shader through unified MiniJinja template system and compile it
+        self.compile_shader(&mut toy_renderer, layout).await?;
+        println!("🔧 DEBUG: Shader compilation successful");
+
+        Ok(toy_renderer)
+    }
+
+    /// Fill the `custom` uniform from `.wgsl.json` defaults and `overrides` from the config
+    /// file and `--param`, and the `codeskew_camera` uniform from the perspective flags
+    fn apply_shader_params(
+        &self,
+        toy_renderer: &mut WgpuToyRenderer,
+        overrides: &indexmap::IndexMap<String, f32>,
+    ) -> Result<(), CodeSkewError> {
+        toy_renderer.set_camera(self.config.camera());
+
+        if let Some(gradient) = &self.config.gradient {
+            let image =
+                GradientGenerator::new(gradient)?.generate(self.width_u32, self.height_u32)?;
+            toy_renderer.set_background(&image);
         }
 
-        // Render background to buffer with optimized staging buffer handling
-        let background_data = toy_renderer
-            .render_to_buffer()
-            .await
-            .map_err(|e| CodeSkewError::RenderingError(format!("Background render failed: {e}")))?;
-        
-        println!("🔧 DEBUG: Background data length: {}", background_data.len());
-        if background_data.len() >= 16 {
-            println!("🔧 DEBUG: First 16 bytes: {:?}", &background_data[0..16]);
+        let mut params: indexmap::IndexMap<String, f32> = self
+            .shader_metadata()
+            .and_then(|file| read_shader_metadata(&file))
+            .map(|metadata| metadata.uniforms.into_iter().map(|u| (u.name, u.value)).collect())
+            .unwrap_or_default();
+        params.extend(overrides.iter().map(|(name, value)| (name.clone(), *value)));
+
+        if !params.is_empty() {
+            let summary: Vec<String> = params.iter().map(|(name, value)| format!("{name}={value}")).collect();
+            println!("🎛️ Shader params: {}", summary.join(", "));
+        }
+
+        let (names, values) = params.into_iter().unzip();
+        toy_renderer
+            .set_custom_floats(names, values)
+            .map_err(CodeSkewError::ConfigError)
+    }
+
+    /// The `.wgsl.json` next to the shader, holding its uniform defaults
+    fn shader_metadata(&self) -> Option<LibraryFile> {
+        if self.config.input.extension().is_some_and(|ext| ext == "wgsl") {
+            let path = PathBuf::from(format!("{}.json", self.config.input.display()));
+            let contents = std::fs::read_to_string(&path).ok()?;
+            Some(LibraryFile {
+                name: path.display().to_string(),
+                path: Some(path),
+                contents: contents.into(),
+            })
+        } else {
+            self.library.read_metadata(&self.config.shader)
         }
-        
-        // Check for non-zero data
-        let non_zero_count = background_data.iter().filter(|&&x| x != 0).count();
-        println!("🔧 DEBUG: Non-zero bytes in background: {}/{}", non_zero_count, background_data.len());
-        
-        Ok(background_data)
+    }
+
+    /// Render the shader template, preprocess it and compile it into `toy_renderer`
+    ///
+    /// Compile errors point at lines of the shader file the user wrote, not the rendered WGSL.
+    async fn compile_shader(
+        &mut self,
+        toy_renderer: &mut WgpuToyRenderer,
+        layout: &[PositionedLine],
+    ) -> Result<(), CodeSkewError> {
+        let shader = self.process_shader_template(layout).await?;
+        println!("🔧 DEBUG: Built unified shader, length: {}", shader.wgsl.len());
+        self.shader_files = shader.path.iter().cloned().collect();
+
+        let mut source_map = toy_renderer.try_preprocess_async(&shader.wgsl).await.map_err(|e| {
+            // Preprocessor lines count the rendered template; report the line the user wrote
+            let line = crate::toy::pp::template_line_map(&shader.template, &shader.wgsl)
+                .get(e.line().saturating_sub(1))
+                .copied()
+                .unwrap_or(e.line());
+            CodeSkewError::RenderingError(format!(
+                "Failed to preprocess shader {}:{}: {}",
+                shader.file,
+                line,
+                e.summary()
+            ))
+        })?;
+        self.shader_files
+            .extend(source_map.includes.iter().filter_map(|name| self.library.find(&format!("include/{name}.wgsl"))));
+        source_map.remap_through_template(&shader.template, &shader.wgsl);
+        source_map.file = shader.file;
+
+        toy_renderer.compile(source_map).await
     }
 
     /// Build background shader with zero allocation string operations
@@ -254,13 +553,12 @@ impl OutputGenerator {
     /// Load any WGSL shader by name
     fn load_shader(&mut self, shader_name: &str) {
         self.shader_buffer.clear();
-        let shader_path = format!("wgsl/{}.wgsl", shader_name);
-        match std::fs::read_to_string(&shader_path) {
-            Ok(shader_content) => {
-                self.shader_buffer.push_str(&shader_content);
+        match self.library.read_shader(shader_name) {
+            Ok(shader) => {
+                self.shader_buffer.push_str(&shader.contents);
             },
-            Err(_) => {
-                eprintln!("Warning: Could not load shader {}, using default", shader_path);
+            Err(e) => {
+                eprintln!("Warning: {}, using default", e);
                 // Default simple shader
                 self.shader_buffer.push_str(&format!(r#"
 @compute @workgroup_size(16, 16)
@@ -287,23 +585,22 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
     async fn launch_live_preview(&mut self, layout: &[PositionedLine]) -> Result<(), CodeSkewError> {
         use winit::{
             event::{ElementState, Event, KeyEvent, WindowEvent},
+            event_loop::ControlFlow,
             keyboard::{KeyCode, PhysicalKey},
         };
 
         println!("🎮 Launching CodeSkew live WGPU preview...");
 
         // Create WGPU context using the exact same approach as wgpu-compute-toy
-        let wgpu_context = crate::toy::init_wgpu(self.width_u32, self.height_u32, "").await
+        let wgpu_context = crate::toy::init_wgpu(self.width_u32, self.height_u32, "", self.config.backend).await
             .map_err(|e| CodeSkewError::RenderingError(format!("Failed to create WGPU context: {e}")))?;
         
         let mut wgputoy = crate::toy::WgpuToyRenderer::new(wgpu_context);
+        wgputoy.set_shader_library(self.library.clone());
 
         // Render text using glyphon texture renderer
         self.render_glyphon_to_texture(&mut wgputoy, layout).await?;
 
-        // Process all shaders through unified MiniJinja template system
-        let rendered_wgsl = self.process_shader_template(layout).await?;
-
         // Load textures for all shaders (unified path)
         if let Err(e) = wgputoy.load_procedural_texture(0, 256, 256) {
             println!("🔧 WARNING: Failed to load texture: {}", e);
@@ -313,17 +610,19 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
 
         // Compile the unified rendered shader
         println!("🔧 Compiling unified shader template");
-        if let Some(source_map) = wgputoy.preprocess_async(&rendered_wgsl).await {
-            println!("🔧 Unified shader preprocessing successful");
-            wgputoy.compile(source_map);
-            println!("🔧 Unified shader compilation successful");
-        } else {
-            return Err(CodeSkewError::RenderingError("Failed to preprocess unified shader".to_string()));
-        }
+        self.apply_shader_params(&mut wgputoy, &self.config.params)?;
+        self.compile_shader(&mut wgputoy, layout).await?;
+        println!("🔧 Unified shader compilation successful");
 
-        wgputoy.wgpu.window.set_title("CodeSkew Live Preview");
-        let screen_size = wgputoy.wgpu.window.inner_size();
+        let window = wgputoy.wgpu.window.clone().ok_or_else(|| {
+            CodeSkewError::RenderingError("Live preview requires a window-backed WGPU context".to_string())
+        })?;
+        window.set_title("CodeSkew Live Preview");
+        let screen_size = window.inner_size();
         let event_loop = std::mem::take(&mut wgputoy.wgpu.event_loop).unwrap();
+
+        let mut watcher = crate::output::hot_reload::ReloadWatcher::new(event_loop.create_proxy())?;
+        watcher.watch(self.watched_files());
         
         // Create device clone for polling (copied from wgpu-compute-toy)
         let device_clone = wgputoy.wgpu.device.clone();
@@ -336,91 +635,142 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
         let mut paused = false;
         let mut current_instant = std::time::Instant::now();
         let mut reference_time = 0.0;
+        // When to reload after the last file change
+        let mut reload_at: Option<std::time::Instant> = None;
 
         println!("🚀 Live preview ready! Press ESC to exit, SPACE to pause, BACKSPACE to reset time.");
+        println!("⌨️  Key presses are forwarded to the shader's keyDown()");
+        println!("👀 Watching the shader, its includes, the input and the config file for changes");
 
         // Main event loop - copied verbatim from wgpu-compute-toy
         let _ = event_loop.run(move |event, elwt| {
             match event {
-                Event::WindowEvent { event, .. } => match event {
-                    WindowEvent::CloseRequested
-                    | WindowEvent::KeyboardInput {
-                        device_id: _,
-                        event:
-                            KeyEvent {
-                                state: ElementState::Released,
-                                physical_key: PhysicalKey::Code(KeyCode::Escape),
-                                ..
-                            },
-                        ..
-                    } => {
-                        close_requested = true;
-                    }
-                    WindowEvent::KeyboardInput {
-                        device_id: _,
-                        event:
-                            KeyEvent {
-                                state: ElementState::Released,
-                                physical_key: PhysicalKey::Code(KeyCode::Backspace),
-                                ..
-                            },
+                Event::WindowEvent { event, .. } => {
+                    // Every key goes to the shader's `keyDown` bitmap, including the control keys below
+                    if let WindowEvent::KeyboardInput {
+                        event: KeyEvent { state, physical_key: PhysicalKey::Code(code), .. },
                         ..
-                    } => {
-                        // reset time
-                        paused = false;
-                        reference_time = 0.0;
-                        current_instant = std::time::Instant::now();
-                        wgputoy.reset();
-                        println!("🔄 Time reset");
+                    } = &event
+                        && let Some(keycode) = crate::toy::keys::keycode(*code)
+                    {
+                        wgputoy.set_keydown(keycode, *state == ElementState::Pressed);
                     }
-                    WindowEvent::KeyboardInput {
-                        device_id: _,
-                        event:
-                            KeyEvent {
-                                state: ElementState::Released,
-                                physical_key: PhysicalKey::Code(KeyCode::Space),
-                                ..
-                            },
-                        ..
-                    } => {
-                        // toggle pause
-                        paused = !paused;
-                        if !paused {
+
+                    match event {
+                        WindowEvent::CloseRequested
+                        | WindowEvent::KeyboardInput {
+                            device_id: _,
+                            event:
+                                KeyEvent {
+                                    state: ElementState::Released,
+                                    physical_key: PhysicalKey::Code(KeyCode::Escape),
+                                    ..
+                                },
+                            ..
+                        } => {
+                            close_requested = true;
+                        }
+                        WindowEvent::KeyboardInput {
+                            device_id: _,
+                            event:
+                                KeyEvent {
+                                    state: ElementState::Released,
+                                    physical_key: PhysicalKey::Code(KeyCode::Backspace),
+                                    ..
+                                },
+                            ..
+                        } => {
+                            // reset time
+                            paused = false;
+                            reference_time = 0.0;
                             current_instant = std::time::Instant::now();
-                            wgputoy.wgpu.window.set_title("CodeSkew Live Preview");
-                        } else {
-                            reference_time = reference_time + current_instant.elapsed().as_secs_f32();
-                            wgputoy.wgpu.window.set_title("CodeSkew Live Preview - Paused");
+                            wgputoy.reset();
+                            println!("🔄 Time reset");
                         }
-                        println!("⏯️  Paused: {}", paused);
-                    }
-                    WindowEvent::CursorMoved { position, .. } => {
-                        wgputoy.set_mouse_pos(
-                            position.x as f32 / screen_size.width as f32,
-                            position.y as f32 / screen_size.height as f32,
-                        );
-                    }
-                    WindowEvent::MouseInput { state, .. } => {
-                        wgputoy.set_mouse_click(state == ElementState::Pressed);
-                    }
-                    WindowEvent::Resized(size) => {
-                        if size.width != 0 && size.height != 0 {
-                            wgputoy.resize(size.width, size.height, 1.);
+                        WindowEvent::KeyboardInput {
+                            device_id: _,
+                            event:
+                                KeyEvent {
+                                    state: ElementState::Released,
+                                    physical_key: PhysicalKey::Code(KeyCode::Space),
+                                    ..
+                                },
+                            ..
+                        } => {
+                            // toggle pause
+                            paused = !paused;
+                            if !paused {
+                                current_instant = std::time::Instant::now();
+                                window.set_title("CodeSkew Live Preview");
+                            } else {
+                                reference_time = reference_time + current_instant.elapsed().as_secs_f32();
+                                window.set_title("CodeSkew Live Preview - Paused");
+                            }
+                            println!("⏯️  Paused: {}", paused);
                         }
-                    }
-                    WindowEvent::RedrawRequested => {
-                        if !paused {
-                            let time = reference_time + current_instant.elapsed().as_secs_f32();
-                            wgputoy.set_time_elapsed(time);
+                        WindowEvent::CursorMoved { position, .. } => {
+                            wgputoy.set_mouse_pos(
+                                position.x as f32 / screen_size.width as f32,
+                                position.y as f32 / screen_size.height as f32,
+                            );
                         }
-                        let future = wgputoy.render_async();
-                        pollster::block_on(future);
+                        WindowEvent::MouseInput { state, .. } => {
+                            wgputoy.set_mouse_click(state == ElementState::Pressed);
+                        }
+                        WindowEvent::Focused(false) => {
+                            wgputoy.release_all_keys();
+                        }
+                        WindowEvent::Resized(size) => {
+                            if size.width != 0 && size.height != 0 {
+                                wgputoy.resize(size.width, size.height, 1.);
+                            }
+                        }
+                        WindowEvent::RedrawRequested => {
+                            if !paused {
+                                let time = reference_time + current_instant.elapsed().as_secs_f32();
+                                wgputoy.set_time_elapsed(time);
+                            }
+                            let future = wgputoy.render_async();
+                            pollster::block_on(future);
+                        }
+                        _ => (),
                     }
-                    _ => (),
-                },
+                }
+                Event::UserEvent(()) => {
+                    // Editors touch a file several times per save; reload once they are done
+                    let at = std::time::Instant::now() + RELOAD_DEBOUNCE;
+                    reload_at = Some(at);
+                    elwt.set_control_flow(ControlFlow::WaitUntil(at));
+                }
                 Event::AboutToWait => {
+                    if let Some(at) = reload_at
+                        && std::time::Instant::now() >= at
+                    {
+                        reload_at = None;
+                        elwt.set_control_flow(ControlFlow::Wait);
+                        if watcher.take_changed() {
+                            match pollster::block_on(self.reload_live_preview(&mut wgputoy)) {
+                                Ok(()) => {
+                                    window.set_title(if paused {
+                                        "CodeSkew Live Preview - Paused"
+                                    } else {
+                                        "CodeSkew Live Preview"
+                                    });
+                                    println!("🔁 Reloaded");
+                                }
+                                Err(e) => {
+                                    eprintln!("❌ Reload failed, keeping the last good shader:\n{e}");
+                                    let summary = e.to_string();
+                                    let summary = summary.lines().next().unwrap_or_default();
+                                    window.set_title(&format!("CodeSkew Live Preview - {summary}"));
+                                }
+                            }
+                            watcher.watch(self.watched_files());
+                            window.request_redraw();
+                        }
+                    }
                     if !paused {
-                        wgputoy.wgpu.window.request_redraw();
+                        window.request_redraw();
                     }
                     if close_requested {
                         println!("🎉 Live preview window closed");
@@ -434,6 +784,41 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
         Ok(())
     }
 
+    /// Rebuild the text texture and shader after a watched file changed
+    ///
+    /// Nothing is applied until the new shader compiles, so on error the preview keeps its
+    /// text, parameters and pipelines.
+    async fn reload_live_preview(&mut self, toy_renderer: &mut WgpuToyRenderer) -> Result<(), CodeSkewError> {
+        let params = self.config.read_params()?;
+
+        let code = std::fs::read_to_string(&self.config.input).map_err(|e| {
+            CodeSkewError::RenderingError(format!("Failed to read {}: {e}", self.config.input.display()))
+        })?;
+        let (_, layout) = self.highlight_and_layout(&code)?;
+        let text = self.render_text_texture(toy_renderer, &layout).await?;
+
+        // The new parameters shape `struct Custom`, so they are compiled against and put
+        // back if compiling fails
+        let (names, values) = toy_renderer.custom_floats();
+        self.apply_shader_params(toy_renderer, &params)?;
+        if let Err(e) = self.compile_shader(toy_renderer, &layout).await {
+            toy_renderer.set_custom_floats(names, values).map_err(CodeSkewError::ConfigError)?;
+            return Err(e);
+        }
+
+        self.config.params = params;
+        self.set_text_texture(toy_renderer, text)
+    }
+
+    /// Files whose changes trigger a live preview reload
+    fn watched_files(&self) -> Vec<PathBuf> {
+        let mut files = self.shader_files.clone();
+        files.push(self.config.input.clone());
+        files.extend(self.shader_metadata().and_then(|file| file.path));
+        files.extend(self.config.config_file.clone());
+        files
+    }
+
     /// Load textures for a specific shader based on JSON metadata or defaults
     async fn load_shader_textures(
         &self,
@@ -441,12 +826,8 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
         shader_name: &str,
     ) -> Result<(), CodeSkewError> {
         // Try to load JSON metadata first
-        let json_path = format!("wgsl/{}.wgsl.json", shader_name);
-        if let Ok(json_content) = std::fs::read_to_string(&json_path) {
-            println!("🔧 DEBUG: Found JSON metadata at {}", json_path);
-            if let Ok(metadata) = serde_json::from_str::<ShaderMetadata>(&json_content) {
-                return self.load_textures_from_metadata(toy_renderer, &metadata).await;
-            }
+        if let Some(metadata) = self.library.read_metadata(shader_name).and_then(|file| read_shader_metadata(&file)) {
+            return self.load_textures_from_metadata(toy_renderer, &metadata).await;
         }
 
         // No JSON metadata, use shader-specific defaults
@@ -472,10 +853,12 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
                     CodeSkewError::RenderingError(format!("Texture file not found: {}", local_path))
                 })?
             } else {
-                // Relative path in wgsl directory
-                let local_path = format!("wgsl/{}", texture_info.img);
-                std::fs::read(&local_path).map_err(|_| {
-                    CodeSkewError::RenderingError(format!("Texture file not found: {}", local_path))
+                // Relative to a shader library directory
+                let local_path = self.library.find(&texture_info.img).ok_or_else(|| {
+                    CodeSkewError::RenderingError(format!("Texture file not found in shader library: {}", texture_info.img))
+                })?;
+                std::fs::read(&local_path).map_err(|e| {
+                    CodeSkewError::RenderingError(format!("Failed to read texture {}: {e}", local_path.display()))
                 })?
             };
 
@@ -658,16 +1041,15 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
     #[inline]
     fn calculate_perspective_font_size(&self) -> f32 {
         // Use config parameters for 3D perspective
-        let fold_point = 0.67; // 2/3 down where text is largest (fixed)
         let fold_strength = self.config.fold; // CLI configurable fold strength
         let perspective_strength = self.config.scale; // CLI configurable scale factor
         let target_chars = 100.0; // Target character count at largest point
         
-        // Calculate effective width at the largest point (2/3 down, left side)
-        // At fold_point, vertical_scale = 1.0 + fold_strength = 1.4
+        // Calculate effective width at the largest point
+        // At --fold-point, vertical_scale peaks at 1.0 + --fold
         let max_vertical_scale = 1.0 + fold_strength;
         
-        // At left side (x=0), horizontal_depth = 1.0 + perspective_strength = 1.6
+        // At the magnified edge, horizontal_depth peaks at 1.0 + --scale
         let max_horizontal_scale = 1.0 + perspective_strength;
         
         // Combined maximum scale factor
@@ -694,32 +1076,65 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
         toy_renderer: &mut WgpuToyRenderer,
         layout: &[PositionedLine],
     ) -> Result<(), CodeSkewError> {
+        let text = self.render_text_texture(toy_renderer, layout).await?;
+        self.set_text_texture(toy_renderer, text)
+    }
+
+    /// Render the code into a texture for `toy_renderer`, without handing it over yet
+    async fn render_text_texture(
+        &self,
+        toy_renderer: &WgpuToyRenderer,
+        layout: &[PositionedLine],
+    ) -> Result<TextTexture, CodeSkewError> {
         println!("🔤 Starting ratagpu-based Glyphon text rendering...");
         
         // Calculate optimal font size for 3D perspective text rendering
-        // Algorithm: Size to fit ~100 chars at largest point (2/3 down) with perspective scaling
-        let font_size = self.calculate_perspective_font_size();
+        // Algorithm: Size to fit ~100 chars at largest point (2/3 down) with perspective scaling,
+        // shrinking further when the grid would not fit the texture
+        let cols = layout.iter().map(|line| line.chars.len()).max().unwrap_or(0);
+        let rows = layout.len();
+        let font_size = self.calculate_perspective_font_size().min(
+            GlyphonTextureRenderer::fit_font_size(cols, rows, self.width_u32, self.height_u32),
+        );
         
-        // Create ratagpu texture renderer (80x30 is standard terminal size)  
-        let mut renderer = GlyphonTextureRenderer::<80, 30>::new(
+        // Create ratagpu texture renderer; the cell grid is sized from the layout
+        let mut renderer = GlyphonTextureRenderer::new(
             toy_renderer.wgpu.device.clone(),
             toy_renderer.wgpu.queue.clone(),
             toy_renderer.wgpu.surface_config.clone(),
             font_size, // Dynamically calculated font size based on window
-        ).await.map_err(|e| CodeSkewError::RenderingError(format!("Failed to create Glyphon renderer: {}", e)))?;
+            &self.config.font,
+            self.config.font_file.as_deref(),
+        ).await.map_err(|e| match e.downcast::<CodeSkewError>() {
+            Ok(font_error @ CodeSkewError::FontError(_)) => font_error,
+            Ok(other) => CodeSkewError::RenderingError(format!("Failed to create Glyphon renderer: {}", other)),
+            Err(e) => CodeSkewError::RenderingError(format!("Failed to create Glyphon renderer: {}", e)),
+        })?;
+        println!("🔤 Shaping text with font family '{}'", renderer.font_family());
         
         // Load layout data into the renderer's cell grid
         renderer.load_layout(layout);
         
         // Render to texture using ratagpu's zero-allocation pipeline
-        let text_texture = renderer.render_to_texture(self.width_u32, self.height_u32)
+        let texture = renderer.render_to_texture(self.width_u32, self.height_u32)
             .map_err(|e| CodeSkewError::RenderingError(format!("Failed to render text to texture: {}", e)))?;
         
-        // Set the rendered texture into channel1 for the compute shader
-        toy_renderer.set_channel_texture(1, text_texture)
-            .map_err(|e| CodeSkewError::RenderingError(format!("Failed to set text texture: {}", e)))?;
-        
         println!("🔤 ratagpu Glyphon text rendered to storage texture successfully!");
+        Ok(TextTexture { texture, line_height: renderer.line_height() })
+    }
+
+    /// Hand a rendered text texture to the shader or the --surface pass
+    fn set_text_texture(&self, toy_renderer: &mut WgpuToyRenderer, text: TextTexture) -> Result<(), CodeSkewError> {
+        // Set the rendered texture into channel1 for the compute shader, unless a
+        // --surface raster pass draws it over the shader instead
+        match self.config.surface {
+            Some(surface) => toy_renderer.set_surface(surface, text.texture),
+            None => toy_renderer.set_channel_texture(1, text.texture)
+                .map_err(|e| CodeSkewError::RenderingError(format!("Failed to set text texture: {}", e)))?,
+        }
+
+        // --focus-line is a row of the grid, so the blur pass is set up with its line height
+        toy_renderer.set_depth_of_field(self.config.depth_of_field(text.line_height));
         Ok(())
     }
 
@@ -754,10 +1169,7 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
             downsampled_width => downsampled_width,
             downsampled_height => downsampled_height,
             downsample_factor => downsample_factor,
-            fold => self.config.fold,
-            skew => self.config.skew_angle,
-            scale => self.config.scale,
-            perspective => self.config.perspective,
+            camera => self.config.camera(),
             font_size => self.calculate_perspective_font_size(),
             characters => template_data.characters,
             colors => template_data.colors,
@@ -776,28 +1188,32 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
     }
 
     /// Process shader template through unified MiniJinja system
-    async fn process_shader_template(&mut self, layout: &[PositionedLine]) -> Result<String, CodeSkewError> {
+    async fn process_shader_template(&mut self, layout: &[PositionedLine]) -> Result<RenderedShader, CodeSkewError> {
         use minijinja::Environment;
         
         // Determine shader template source
-        let (shader_template, shader_source) = if self.config.input.extension().map_or(false, |ext| ext == "wgsl") {
+        let (shader, shader_source) = if self.config.input.extension().map_or(false, |ext| ext == "wgsl") {
             // Load WGSL file as template
             let wgsl_content = std::fs::read_to_string(&self.config.input)
                 .map_err(|e| CodeSkewError::RenderingError(format!("Failed to read WGSL file: {e}")))?;
             println!("🔧 Loading WGSL shader from: {}", self.config.input.display());
-            (wgsl_content, "input file".to_string())
+            let shader = LibraryFile {
+                path: Some(self.config.input.clone()),
+                name: self.config.input.display().to_string(),
+                contents: wgsl_content.into(),
+            };
+            (shader, "input file".to_string())
         } else {
             // Load background shader as template
-            let shader_path = format!("wgsl/{}.wgsl", self.config.shader);
-            let wgsl_content = std::fs::read_to_string(&shader_path)
-                .map_err(|e| CodeSkewError::RenderingError(format!("Failed to read background shader {}: {e}", shader_path)))?;
-            println!("🔧 Loading background shader: {}", self.config.shader);
-            (wgsl_content, self.config.shader.clone())
+            let shader = self.library.read_shader(&self.config.shader)?;
+            println!("🔧 Loading background shader: {} from {}", self.config.shader, shader.display());
+            (shader, self.config.shader.clone())
         };
+        let shader_template = &shader.contents;
         
         // Create MiniJinja environment
         let mut env = Environment::new();
-        env.add_template("shader", &shader_template)
+        env.add_template("shader", shader_template)
             .map_err(|e| CodeSkewError::RenderingError(format!("Template error: {}", e)))?;
         
         // Build template context
@@ -810,7 +1226,12 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
         let rendered_wgsl = tmpl.render(template_context)
             .map_err(|e| CodeSkewError::RenderingError(format!("Template render error: {}", e)))?;
         
-        Ok(rendered_wgsl)
+        Ok(RenderedShader {
+            file: shader.display(),
+            template: shader_template.to_string(),
+            path: shader.path,
+            wgsl: rendered_wgsl,
+        })
     }
 
     /// Build template context for MiniJinja rendering
@@ -837,10 +1258,7 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {{
             downsampled_width => downsampled_width,
             downsampled_height => downsampled_height,
             downsample_factor => downsample_factor,
-            fold => self.config.fold,
-            skew => self.config.skew_angle,
-            scale => self.config.scale,
-            perspective => self.config.perspective,
+            camera => self.config.camera(),
             font_size => self.calculate_perspective_font_size(),
             line_count => layout.len(),
             shader_source => shader_source,
@@ -945,34 +1363,6 @@ fn unpack_color(packed: u32) -> float4 {
     let a = f32(packed & 0xFFu) / 255.0;
     return float4(r, g, b, a);
 }
-
-// 3D perspective transformation matching bandwidth shader's grid system
-fn apply_3d_perspective(pos: CharPosition, screen_uv: float2, t: float) -> float2 {
-    // Use shader's actual screen dimensions
-    let screen_size = float2(textureDimensions(screen));
-    let char_uv = float2(pos.x, pos.y) / screen_size;
-    
-    // Match bandwidth shader's grid perspective system
-    let vanishing_x = 0.3;      // Same as bandwidth shader
-    let skew_strength = 0.4;    // Same as bandwidth shader
-    let grid_horizon = 0.4;     // Grid horizon line
-    let grid_nearest = 0.67;    // Nearest grid point
-    
-    // Position text in upper area (above grid horizon at y < 0.4)
-    let text_y = clamp(char_uv.y * 0.25 + 0.05, 0.05, 0.35);  // Ensure text stays in upper area: 0.05 to 0.35
-    
-    // Calculate depth based on Y position relative to grid system
-    let normalized_y = (text_y - 0.05) / 0.25;  // 0.0 = top, 1.0 = bottom
-    let z_depth = 1.0 / (normalized_y * 0.5 + 0.1);  // Perspective depth
-    
-    // Apply skew based on depth (farther = more skew toward vanishing point)
-    let base_x = char_uv.x * 0.8 + 0.1;  // Center horizontally with margins
-    let depth_factor = 1.0 - (1.0 / z_depth);  // 0.0 = near, approaching 1.0 = far
-    let x_offset = (vanishing_x - base_x) * depth_factor * skew_strength;
-    let skewed_x = clamp(base_x + x_offset, 0.02, 0.98);  // Ensure text stays within screen bounds
-    
-    return float2(skewed_x, text_y);
-}
 "#);
         
         
@@ -981,6 +1371,10 @@ fn apply_3d_perspective(pos: CharPosition, screen_uv: float2, t: float) -> float
 
 // Sample text from glyphon texture (channel1)
 fn sample_text_texture(uv: float2) -> float4 {
+    // Off the text plane, including where the camera sees none of it
+    if (any(uv < float2(0.0)) || any(uv >= float2(1.0))) {
+        return float4(0.0);
+    }
     let text_size = textureDimensions(channel1);
     let texel_coord = uint2(uv * float2(text_size));
     
@@ -1007,13 +1401,18 @@ fn sample_text_texture(uv: float2) -> float4 {
         return float4(0.0);
     }
     
-    return textureLoad(channel1, texel_coord, 0);
+    // Straight syntax colors, not the premultiplied sRGB texels
+    return codeskewTextColor(textureLoad(channel1, texel_coord, 0));
 }
 
 // Main text rendering function
 fn render_text_layer(uv: float2, t: float) -> float4 {
-    // Sample text directly from glyphon texture in channel1
-    let text_sample = sample_text_texture(uv);
+    // Sample the glyphon texture in channel1 through the perspective flags, at pixel centers
+    let screen_uv = uv + 0.5 / float2(textureDimensions(screen));
+    var text_sample = sample_text_texture(codeskewTextUv(screen_uv));
+
+    // Nearer text is more opaque, by --depth
+    text_sample.a *= mix(1.0 - codeskew_camera.depth * 0.5, 1.0, 1.0 / codeskewCameraDepth(screen_uv));
     
     // DEBUG: If no text, show a debug pattern in upper area to verify the code is running
     if (text_sample.a < 0.01 && uv.y < 0.4 && uv.x < 0.5) {
@@ -1196,3 +1595,84 @@ fn render_text_layer(uv: float2, t: float) -> float4 {
         Ok((atlas_data, uv_coords))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Cli;
+    use clap::Parser;
+
+    /// Counts its frames in `pass_in`, a tenth per frame
+    const FEEDBACK: &str = "@compute @workgroup_size(16, 16)\n\
+        fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+            let count = passLoad(0, int2(id.xy), 0).x + 0.1;\n\
+            passStore(0, int2(id.xy), float4(count));\n\
+            textureStore(screen, id.xy, float4(count, 0.0, 0.0, 1.0));\n\
+        }\n";
+
+    /// The still `shader` renders with `flags`, `None` to skip where there is no software adapter
+    async fn still(shader: &str, flags: &[&str]) -> Option<Vec<u8>> {
+        let args = ["codeskew", "sample.rs", "--backend", "cpu", "-w", "16", "-H", "16"];
+        let cli = Cli::try_parse_from(args.iter().chain(flags)).expect("flags");
+        let generator = OutputGenerator::new(Config::from_cli(&cli).expect("config")).expect("generator");
+        let wgpu = match generator.create_headless_context().await {
+            Ok(wgpu) => wgpu,
+            Err(e) => {
+                eprintln!("skipping: {e}");
+                return None;
+            }
+        };
+        let mut toy = WgpuToyRenderer::new(wgpu);
+        let source = toy.preprocess_async(shader).await.expect("preprocess");
+        toy.compile(source).await.expect("compile");
+        Some(generator.render_still(&mut toy).await.expect("render"))
+    }
+
+    #[test]
+    fn warmup_frames_run_feedback_shaders_before_the_still() {
+        pollster::block_on(async {
+            let Some(cold) = still(FEEDBACK, &[]).await else {
+                return;
+            };
+            let warm = still(FEEDBACK, &["--warmup-frames", "3"]).await.expect("adapter");
+            let frames = |pixels: &[u8]| (pixels[0] as f32 / 25.5).round();
+            assert_eq!(frames(&cold), 1.0, "{}", cold[0]);
+            assert_eq!(frames(&warm), 4.0, "{}", warm[0]);
+        });
+    }
+
+    #[test]
+    fn time_sets_the_elapsed_time_of_the_still() {
+        let clock = "@compute @workgroup_size(16, 16)\n\
+            fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                textureStore(screen, id.xy, float4(time.elapsed / 10.0, 0.0, 0.0, 1.0));\n\
+            }\n";
+        pollster::block_on(async {
+            let Some(start) = still(clock, &[]).await else {
+                return;
+            };
+            assert_eq!(start[0], 0);
+            // Warm-up frames end at the capture time rather than moving it
+            for flags in [&["--time", "5"][..], &["--time", "5", "--warmup-frames", "4"]] {
+                let later = still(clock, flags).await.expect("adapter");
+                assert!((126..=128).contains(&later[0]), "{flags:?}: {}", later[0]);
+            }
+        });
+    }
+
+    #[test]
+    fn accumulate_averages_frames() {
+        let flicker = "@compute @workgroup_size(16, 16)\n\
+            fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                textureStore(screen, id.xy, float4(f32(time.frame % 2u), 0.0, 0.0, 1.0));\n\
+            }\n";
+        pollster::block_on(async {
+            let Some(single) = still(flicker, &[]).await else {
+                return;
+            };
+            assert!(single[0] == 0 || single[0] == 255, "{}", single[0]);
+            let averaged = still(flicker, &["--accumulate", "16"]).await.expect("adapter");
+            assert!((126..=129).contains(&averaged[0]), "{}", averaged[0]);
+        });
+    }
+}
@@ -1,11 +1,22 @@
 //! Save methods for different output formats
 
-use crate::config::Config;
+use crate::cli::GifPalette;
+use crate::config::{Config, TELEGRAM_MAX_BYTES};
 use crate::error::CodeSkewError;
+use crate::gradient::{GradientGenerator, GradientProvider, GradientShape};
 use crate::layout::PositionedLine;
+use crate::transform::TextSheet;
+use color_quant::NeuQuant;
 use image::RgbaImage;
+use nalgebra::{Matrix3, Vector2};
 use std::fs::File;
 
+/// Stops sampled from a gradient for SVG, which interpolates in sRGB rather than OKLab
+const SVG_GRADIENT_STOPS: usize = 16;
+
+/// Lowest quality tried when shrinking a Telegram sticker to fit the size limit
+const MIN_TELEGRAM_WEBP_QUALITY: f32 = 10.0;
+
 pub struct SaveMethods<'a> {
     pub config: &'a Config,
 }
@@ -38,9 +49,12 @@ impl<'a> SaveMethods<'a> {
         }
 
         // High-performance WebP encoding
-        let webp_data =
-            webp::Encoder::from_rgba(image.as_raw(), self.config.width, self.config.height)
-                .encode(95.0);
+        let encoder = webp::Encoder::from_rgba(image.as_raw(), self.config.width, self.config.height);
+        let webp_data = if self.config.webp_lossless {
+            encoder.encode_lossless()
+        } else {
+            encoder.encode(self.config.webp_quality)
+        };
 
         std::fs::write(&self.config.output, &*webp_data)
             .map_err(|e| CodeSkewError::OutputError(format!("Failed to save WebP: {e}")))?;
@@ -48,193 +62,320 @@ impl<'a> SaveMethods<'a> {
         Ok(())
     }
 
-    /// Generate animated GIF with composite layers and zero allocation
-    #[inline]
-    pub async fn save_gif_animation_optimized(
+    /// Write the layout as selectable SVG text over a background
+    ///
+    /// Each line becomes a `<text>` with one `<tspan>` per color run, drawn through the
+    /// tangent of the same warped sheet shaders draw the text on. A rendered shader frame is
+    /// embedded as a PNG `<image>`; without one the configured gradient is drawn instead,
+    /// rasterized the same way when it is conic.
+    pub async fn save_svg_optimized(
         &self,
         layout: &[PositionedLine],
+        font_size: f32,
+        background: Option<RgbaImage>,
     ) -> Result<(), CodeSkewError> {
-        use gif::{Encoder, Frame, Repeat};
+        use base64::Engine;
+        use svg::Document;
+        use svg::node::Blob;
+        use svg::node::element::{
+            Circle, ClipPath, Definitions, Group, Image, LinearGradient, RadialGradient, Rectangle,
+            Stop,
+        };
 
-        let mut file = File::create(&self.config.output)
-            .map_err(|e| CodeSkewError::OutputError(format!("Failed to create GIF file: {e}")))?;
+        let width = self.config.width;
+        let height = self.config.height;
+        let mut definitions = Definitions::new();
+        let mut content = Group::new();
+
+        let gradient = GradientGenerator::new(&self.config.gradient.clone().unwrap_or_default())?;
+        let png_image = |image: &RgbaImage| -> Result<Image, CodeSkewError> {
+            let mut png = Vec::new();
+            image
+                .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+                .map_err(|e| {
+                    CodeSkewError::OutputError(format!("Failed to encode SVG background: {e}"))
+                })?;
+            let href = format!(
+                "data:image/png;base64,{}",
+                base64::engine::general_purpose::STANDARD.encode(&png)
+            );
+            Ok(Image::new()
+                .set("width", width)
+                .set("height", height)
+                .set("preserveAspectRatio", "none")
+                .set("href", href))
+        };
+        let stops = || {
+            (0..=SVG_GRADIENT_STOPS).map(|i| {
+                let offset = i as f32 / SVG_GRADIENT_STOPS as f32;
+                let [r, g, b] = gradient.get_rgb_at(offset);
+                Stop::new()
+                    .set("offset", offset)
+                    .set("stop-color", format!("#{r:02x}{g:02x}{b:02x}"))
+            })
+        };
+        let gradient_fill = Rectangle::new()
+            .set("width", width)
+            .set("height", height)
+            .set("fill", "url(#background)");
+        let (w, h) = (width as f32, height as f32);
+
+        match (background, gradient.shape()) {
+            (Some(image), _) => content = content.add(png_image(&image)?),
+            // SVG has no conic gradient, so conic ones are rasterized
+            (None, GradientShape::Conic { .. }) => {
+                content = content.add(png_image(&gradient.generate(width, height)?)?);
+            }
+            (None, GradientShape::Linear { angle }) => {
+                // Same gradient line as `GradientGenerator::position`
+                let (sin, cos) = angle.to_radians().sin_cos();
+                let half = ((w * sin).abs() + (h * cos).abs()) / 2.0;
+                definitions = definitions.add(
+                    stops().fold(
+                        LinearGradient::new()
+                            .set("id", "background")
+                            .set("gradientUnits", "userSpaceOnUse")
+                            .set("x1", w / 2.0 - sin * half)
+                            .set("y1", h / 2.0 + cos * half)
+                            .set("x2", w / 2.0 + sin * half)
+                            .set("y2", h / 2.0 - cos * half),
+                        |element, stop| element.add(stop),
+                    ),
+                );
+                content = content.add(gradient_fill);
+            }
+            (None, GradientShape::Radial { center }) => {
+                let (cx, cy) = (center[0] * w, center[1] * h);
+                definitions = definitions.add(
+                    stops().fold(
+                        RadialGradient::new()
+                            .set("id", "background")
+                            .set("gradientUnits", "userSpaceOnUse")
+                            .set("cx", cx)
+                            .set("cy", cy)
+                            .set("r", cx.max(w - cx).hypot(cy.max(h - cy))),
+                        |element, stop| element.add(stop),
+                    ),
+                );
+                content = content.add(gradient_fill);
+            }
+        }
 
-        let mut encoder = Encoder::new(
-            &mut file,
-            self.config.width as u16,
-            self.config.height as u16,
-            &[],
-        )
-        .map_err(|e| CodeSkewError::OutputError(format!("Failed to create GIF encoder: {e}")))?;
+        // The sheet shaders draw the text on, so SVG text lands where raster output puts it
+        let sheet = TextSheet::from_config(self.config);
+        // A --font-file is embedded so the SVG renders with it on machines without the font
+        if let Some(font_file) = &self.config.font_file {
+            let font_bytes = std::fs::read(font_file).map_err(|e| {
+                CodeSkewError::FontError(format!(
+                    "Failed to read font file {}: {e}",
+                    font_file.display()
+                ))
+            })?;
+            let mime = match font_file.extension().and_then(|ext| ext.to_str()) {
+                Some(ext) if ext.eq_ignore_ascii_case("otf") => "font/otf",
+                Some(ext) if ext.eq_ignore_ascii_case("woff2") => "font/woff2",
+                Some(ext) if ext.eq_ignore_ascii_case("woff") => "font/woff",
+                _ => "font/ttf",
+            };
+            let style = format!(
+                "@font-face {{ font-family: {}; src: url(data:{mime};base64,{}); }}",
+                css_string(&self.config.font),
+                base64::engine::general_purpose::STANDARD.encode(&font_bytes)
+            );
+            definitions = definitions.add(Blob::new(format!(
+                "<style>{}</style>",
+                svg::node::Text::new(style)
+            )));
+        }
 
-        encoder
-            .set_repeat(Repeat::Infinite)
-            .map_err(|e| CodeSkewError::OutputError(format!("Failed to set GIF repeat: {e}")))?;
+        let mut text_group = Group::new()
+            .set("font-family", format!("{}, monospace", css_string(&self.config.font)))
+            .set("font-size", font_size)
+            .set("dominant-baseline", "central")
+            .set("xml:space", "preserve");
+
+        for line in layout {
+            // Lines where the sheet is out of view are left out
+            let Some(tangent) = sheet.linearize(line.x, line.y) else {
+                continue;
+            };
+            let m = tangent
+                * Matrix3::new_nonuniform_scaling(&Vector2::new(line.scale, line.scale))
+                    .append_translation(&Vector2::new(line.x, line.y));
+
+            // One tspan per run of identically colored characters. The svg crate puts
+            // child elements on separate lines, which `xml:space="preserve"` would render
+            // as extra spaces, so each line's markup is assembled by hand.
+            let mut spans = String::new();
+            for run in line.chars.chunk_by(|a, b| {
+                (a.color.r, a.color.g, a.color.b) == (b.color.r, b.color.g, b.color.b)
+            }) {
+                let color = &run[0].color;
+                let run_text: String = run.iter().map(|styled| styled.char).collect();
+                spans.push_str(&format!(
+                    r##"<tspan fill="#{:02x}{:02x}{:02x}">{}</tspan>"##,
+                    color.r,
+                    color.g,
+                    color.b,
+                    svg::node::Text::new(run_text)
+                ));
+            }
 
-        // Pre-allocate frame data buffer
-        let pixel_count = (self.config.width * self.config.height) as usize;
-        let mut frame_data = vec![0u8; pixel_count * 4]; // RGBA buffer
+            text_group = text_group.add(Blob::new(format!(
+                r#"<text transform="matrix({} {} {} {} {} {})">{spans}</text>"#,
+                m[(0, 0)], m[(1, 0)], m[(0, 1)], m[(1, 1)], m[(0, 2)], m[(1, 2)]
+            )));
+        }
+        content = content.add(text_group);
 
-        // Generate animation frames with text reveal effect
-        let total_frames = 60; // 2 seconds at 30fps
-        let frame_delay = 3; // ~33ms per frame
+        // Telegram stickers are round
+        if self.config.telegram {
+            definitions = definitions.add(
+                ClipPath::new().set("id", "sticker").add(
+                    Circle::new()
+                        .set("cx", width as f32 / 2.0)
+                        .set("cy", height as f32 / 2.0)
+                        .set("r", width.min(height) as f32 / 2.0 * 0.95),
+                ),
+            );
+            content = content.set("clip-path", "url(#sticker)");
+        }
 
-        for frame_idx in 0..total_frames {
-            let time_factor = frame_idx as f32 / total_frames as f32;
+        let document = Document::new()
+            .set("width", width)
+            .set("height", height)
+            .set("viewBox", (0, 0, width, height))
+            .add(definitions)
+            .add(content);
 
-            // Clear frame buffer
-            frame_data.fill(0);
-
-            // Animated background
-            for y in 0..self.config.height {
-                for x in 0..self.config.width {
-                    let uv_x = x as f32 / self.config.width as f32;
-                    let uv_y = y as f32 / self.config.height as f32;
-
-                    // Animated gradient background
-                    let wave1 = (uv_x * 12.0 + time_factor * std::f32::consts::TAU).sin() * 0.15;
-                    let wave2 = (uv_y * 8.0 + time_factor * 4.0).cos() * 0.1;
-
-                    let r = ((0.1 + wave1) * 255.0).clamp(0.0, 255.0) as u8;
-                    let g = ((0.2 + wave2) * 255.0).clamp(0.0, 255.0) as u8;
-                    let b =
-                        ((0.6 + (time_factor * std::f32::consts::PI).sin() * 0.2) * 255.0).clamp(0.0, 255.0) as u8;
-
-                    let pixel_idx = ((y * self.config.width + x) * 4) as usize;
-                    if pixel_idx + 3 < frame_data.len() {
-                        frame_data[pixel_idx] = r;
-                        frame_data[pixel_idx + 1] = g;
-                        frame_data[pixel_idx + 2] = b;
-                        frame_data[pixel_idx + 3] = 255; // Alpha
-                    }
-                }
-            }
+        svg::save(&self.config.output, &document)
+            .map_err(|e| CodeSkewError::OutputError(format!("Failed to save SVG: {e}")))?;
 
-            // Render text with typewriter effect
-            let total_chars = layout.iter().map(|line| line.chars.len()).sum::<usize>();
-            let chars_to_show = ((total_chars as f32 * time_factor) as usize).min(total_chars);
-            let mut char_count = 0;
-
-            for line in layout {
-                // Strict bounds checking for line position
-                if line.y < 0.0
-                    || line.y >= self.config.height as f32
-                    || line.x < 0.0
-                    || line.x >= self.config.width as f32
-                {
-                    continue;
-                }
+        Ok(())
+    }
 
-                for (char_idx, styled_char) in line.chars.iter().enumerate() {
-                    if char_count >= chars_to_show {
-                        break;
-                    }
+    /// Encode rendered animation frames as a looping animated WebP
+    ///
+    /// Telegram stickers must stay under 256KB, so those are re-encoded at decreasing
+    /// quality until they fit.
+    pub async fn save_webp_animation_optimized(
+        &self,
+        mut frames: Vec<RgbaImage>,
+    ) -> Result<(), CodeSkewError> {
+        if frames.is_empty() {
+            return Err(CodeSkewError::AnimationError(
+                "No frames rendered for WebP animation".to_string(),
+            ));
+        }
 
-                    // Calculate character position with proper scaling
-                    let char_width = self.config.fontsize * 0.6 * line.scale;
-                    let char_height = self.config.fontsize * line.scale;
-                    let char_x = line.x + (char_idx as f32 * char_width);
-                    let char_y = line.y;
-
-                    // Strict bounds checking for character position
-                    if char_x < 0.0
-                        || char_y < 0.0
-                        || char_x + char_width > self.config.width as f32
-                        || char_y + char_height > self.config.height as f32
-                    {
-                        char_count += 1;
-                        continue;
-                    }
+        if self.config.telegram {
+            for frame in &mut frames {
+                self.apply_telegram_mask_optimized(frame)?;
+            }
+        }
 
-                    // Proper character rasterization - render as a filled rectangle
-                    let start_x = char_x.max(0.0) as u32;
-                    let start_y = char_y.max(0.0) as u32;
-                    let end_x = (char_x + char_width).min(self.config.width as f32) as u32;
-                    let end_y = (char_y + char_height).min(self.config.height as f32) as u32;
-
-                    // Only render visible characters (not whitespace)
-                    if !styled_char.char.is_whitespace() {
-                        for y in start_y..end_y {
-                            for x in start_x..end_x {
-                                let pixel_idx = ((y * self.config.width + x) * 4) as usize;
-
-                                // Triple bounds checking for pixel buffer access
-                                if pixel_idx + 3 < frame_data.len()
-                                    && x < self.config.width
-                                    && y < self.config.height
-                                {
-                                    // Anti-aliased character edges
-                                    let edge_factor = self.calculate_character_alpha(
-                                        (x as f32, y as f32),
-                                        (char_x, char_y, char_width, char_height),
-                                        styled_char.char,
-                                    );
-
-                                    if edge_factor > 0.0 {
-                                        // Alpha blend with background
-                                        let bg_r = frame_data[pixel_idx] as f32;
-                                        let bg_g = frame_data[pixel_idx + 1] as f32;
-                                        let bg_b = frame_data[pixel_idx + 2] as f32;
-
-                                        let text_r = styled_char.color.r as f32;
-                                        let text_g = styled_char.color.g as f32;
-                                        let text_b = styled_char.color.b as f32;
-
-                                        frame_data[pixel_idx] = (bg_r * (1.0 - edge_factor)
-                                            + text_r * edge_factor)
-                                            as u8;
-                                        frame_data[pixel_idx + 1] = (bg_g * (1.0 - edge_factor)
-                                            + text_g * edge_factor)
-                                            as u8;
-                                        frame_data[pixel_idx + 2] = (bg_b * (1.0 - edge_factor)
-                                            + text_b * edge_factor)
-                                            as u8;
-                                        frame_data[pixel_idx + 3] = 255;
-                                    }
-                                }
-                            }
-                        }
-                    }
+        let encode = |quality, lossless| self.encode_webp_frames_optimized(&frames, quality, lossless);
+        let webp_data = if self.config.telegram {
+            Self::fit_telegram_limit(self.config.webp_quality, self.config.webp_lossless, encode)?
+        } else {
+            encode(self.config.webp_quality, self.config.webp_lossless)?
+        };
 
-                    char_count += 1;
-                }
-                if char_count >= chars_to_show {
-                    break;
-                }
+        std::fs::write(&self.config.output, &*webp_data)
+            .map_err(|e| CodeSkewError::OutputError(format!("Failed to save WebP: {e}")))?;
+        Ok(())
+    }
+
+    /// Call `encode(quality, lossless)` until its output fits a Telegram sticker, switching
+    /// from lossless to lossy and then lowering the quality by 10 down to the minimum
+    fn fit_telegram_limit<T: std::ops::Deref<Target = [u8]>>(
+        mut quality: f32,
+        mut lossless: bool,
+        mut encode: impl FnMut(f32, bool) -> Result<T, CodeSkewError>,
+    ) -> Result<T, CodeSkewError> {
+        loop {
+            let webp_data = encode(quality, lossless)?;
+            if webp_data.len() <= TELEGRAM_MAX_BYTES {
+                return Ok(webp_data);
             }
 
-            // Convert RGBA to indexed color for GIF
-            let mut indexed_data = Vec::with_capacity(pixel_count);
-            for i in 0..pixel_count {
-                let rgba_idx = i * 4;
-                if rgba_idx + 2 < frame_data.len() {
-                    let r = frame_data[rgba_idx] as u16;
-                    let g = frame_data[rgba_idx + 1] as u16;
-                    let b = frame_data[rgba_idx + 2] as u16;
-                    // Convert to grayscale for GIF
-                    let gray = ((r * 77 + g * 151 + b * 28) >> 8) as u8;
-                    indexed_data.push(gray);
-                } else {
-                    indexed_data.push(0);
-                }
+            if lossless {
+                // Lossless output is too large for Telegram at any effort; switch to lossy
+                lossless = false;
+            } else if quality > MIN_TELEGRAM_WEBP_QUALITY {
+                quality = (quality - 10.0).max(MIN_TELEGRAM_WEBP_QUALITY);
+            } else {
+                return Err(CodeSkewError::OutputError(format!(
+                    "Telegram sticker is {} bytes at minimum quality, limit is {TELEGRAM_MAX_BYTES}; \
+                     try a shorter --duration or lower --fps",
+                    webp_data.len()
+                )));
             }
 
-            // Create frame
-            let mut frame = Frame::from_indexed_pixels(
-                self.config.width as u16,
-                self.config.height as u16,
-                indexed_data,
-                None,
+            log::warn!(
+                "WebP is {} bytes, over the Telegram limit of {TELEGRAM_MAX_BYTES}; re-encoding at quality {quality}",
+                webp_data.len()
             );
-            frame.delay = frame_delay;
+        }
+    }
+
+    /// Encode frames into an animated WebP spaced `1/fps` apart
+    fn encode_webp_frames_optimized(
+        &self,
+        frames: &[RgbaImage],
+        quality: f32,
+        lossless: bool,
+    ) -> Result<webp_animation::WebPData, CodeSkewError> {
+        use webp_animation::{
+            Encoder, EncoderOptions, EncodingConfig, EncodingType, LossyEncodingConfig,
+        };
+
+        let encoding_type = if lossless {
+            EncodingType::Lossless
+        } else {
+            EncodingType::Lossy(LossyEncodingConfig::default())
+        };
+
+        let mut encoder = Encoder::new_with_options(
+            (self.config.width, self.config.height),
+            EncoderOptions {
+                encoding_config: Some(EncodingConfig {
+                    encoding_type,
+                    quality,
+                    method: 4,
+                }),
+                ..Default::default()
+            },
+        )
+        .map_err(|e| CodeSkewError::OutputError(format!("Failed to create WebP encoder: {e:?}")))?;
 
-            encoder.write_frame(&frame).map_err(|e| {
-                CodeSkewError::OutputError(format!("Failed to write GIF frame: {e}"))
+        let frame_ms = 1000.0 / self.config.fps;
+        for (frame_idx, frame) in frames.iter().enumerate() {
+            let timestamp = (frame_idx as f32 * frame_ms).round() as i32;
+            encoder.add_frame(frame.as_raw(), timestamp).map_err(|e| {
+                CodeSkewError::OutputError(format!("Failed to add WebP frame: {e:?}"))
             })?;
         }
 
-        drop(encoder);
-        Ok(())
+        let end_timestamp = (frames.len() as f32 * frame_ms).round() as i32;
+        encoder
+            .finalize(end_timestamp)
+            .map_err(|e| CodeSkewError::OutputError(format!("Failed to finalize WebP: {e:?}")))
+    }
+
+    /// Encode rendered animation frames as a looping GIF
+    #[inline]
+    pub async fn save_gif_animation_optimized(
+        &self,
+        frames: Vec<RgbaImage>,
+    ) -> Result<(), CodeSkewError> {
+        if frames.is_empty() {
+            return Err(CodeSkewError::AnimationError(
+                "No frames rendered for GIF animation".to_string(),
+            ));
+        }
+
+        self.encode_gif_frames_optimized(frames).await
     }
 
     /// Save advanced GIF with all optimization features enabled
@@ -264,8 +405,6 @@ impl<'a> SaveMethods<'a> {
                 *pixel = image::Rgba([color, color, color, 255]);
             }
             
-            // Apply dithering for superior quality
-            self.apply_dithering_optimized(&mut frame_image);
             frames.push(frame_image);
         }
         
@@ -275,79 +414,6 @@ impl<'a> SaveMethods<'a> {
         Ok(())
     }
 
-    /// Calculate anti-aliased alpha value for character rendering
-    #[inline]
-    fn calculate_character_alpha(
-        &self,
-        pixel_pos: (f32, f32),
-        char_bounds: (f32, f32, f32, f32), // (x, y, width, height)
-        character: char,
-    ) -> f32 {
-        let (pixel_x, pixel_y) = pixel_pos;
-        let (char_x, char_y, char_width, char_height) = char_bounds;
-        
-        // Normalize position within character bounds
-        let rel_x = (pixel_x - char_x) / char_width;
-        let rel_y = (pixel_y - char_y) / char_height;
-
-        // Basic character shape determination
-        let alpha: f32 = match character {
-            // Letters and numbers get solid rectangles with anti-aliased edges
-            'a'..='z' | 'A'..='Z' | '0'..='9' => {
-                if (0.1..=0.9).contains(&rel_x) && (0.1..=0.9).contains(&rel_y) {
-                    1.0
-                } else if (0.05..=0.95).contains(&rel_x) && (0.05..=0.95).contains(&rel_y) {
-                    0.7
-                } else if (0.0..=1.0).contains(&rel_x) && (0.0..=1.0).contains(&rel_y) {
-                    0.3
-                } else {
-                    0.0
-                }
-            }
-            // Punctuation
-            '.' | ',' | ';' | ':' => {
-                if (0.3..=0.7).contains(&rel_x) && (0.6..=0.9).contains(&rel_y) {
-                    1.0
-                } else {
-                    0.0
-                }
-            }
-            // Brackets
-            '(' | ')' | '[' | ']' | '{' | '}' => {
-                if (0.1..=0.3).contains(&rel_x) || (0.7..=0.9).contains(&rel_x) {
-                    if (0.1..=0.9).contains(&rel_y) {
-                        1.0
-                    } else {
-                        0.5
-                    }
-                } else {
-                    0.0
-                }
-            }
-            // Operators
-            '+' | '-' | '*' | '/' | '=' | '<' | '>' => {
-                let horizontal_bar = (0.1..=0.9).contains(&rel_x) && (0.4..=0.6).contains(&rel_y);
-                let vertical_bar = character == '+' && (0.4..=0.6).contains(&rel_x) && (0.1..=0.9).contains(&rel_y);
-                
-                if horizontal_bar || vertical_bar {
-                    1.0
-                } else {
-                    0.0
-                }
-            }
-            // Default for other characters
-            _ => {
-                if (0.1..=0.9).contains(&rel_x) && (0.1..=0.9).contains(&rel_y) {
-                    0.8
-                } else {
-                    0.0
-                }
-            }
-        };
-
-        alpha.clamp(0.0, 1.0)
-    }
-
     /// Apply Telegram circular mask with zero allocation
     #[inline]
     fn apply_telegram_mask_optimized(&self, image: &mut RgbaImage) -> Result<(), CodeSkewError> {
@@ -375,26 +441,46 @@ impl<'a> SaveMethods<'a> {
         Ok(())
     }
 
-    /// Encode GIF frames with ultra-high performance and zero allocation
+    /// Encode GIF frames with palette quantization and frame-delta optimization
+    ///
+    /// Frames after the first only carry the rectangle that changed since the previous
+    /// frame; unchanged pixels inside it use a reserved transparent palette slot.
     async fn encode_gif_frames_optimized(
         &self,
         frames: Vec<RgbaImage>,
     ) -> Result<(), CodeSkewError> {
-        use gif::{Encoder, Frame, Repeat};
+        use gif::{DisposalMethod, Encoder, Frame, Repeat};
         use rayon::prelude::*;
 
+        let width = self.config.width as usize;
+        let height = self.config.height as usize;
+
+        // Delta frames take one of the --gif-colors palette slots for transparency
+        let delta_frames = frames.len() > 1;
+        let colors = if delta_frames {
+            self.config.gif_colors as usize - 1
+        } else {
+            self.config.gif_colors as usize
+        };
+        let transparent = delta_frames.then_some(colors as u8);
+
+        let global_quantizer = match self.config.gif_palette {
+            GifPalette::Global => Some(self.generate_optimized_palette(&frames, colors)),
+            GifPalette::PerFrame => None,
+        };
+        let global_palette = global_quantizer
+            .as_ref()
+            .map(|quantizer| Self::palette_bytes(quantizer, transparent))
+            .unwrap_or_default();
+
         let file = File::create(&self.config.output)
             .map_err(|e| CodeSkewError::OutputError(format!("Failed to create GIF: {e}")))?;
 
-        // Generate optimized palette for all frames
-        let empty_frames = Vec::new();
-        let optimal_palette = self.generate_optimized_palette(&empty_frames);
-        
         let mut encoder = Encoder::new(
             file,
             self.config.width as u16,
             self.config.height as u16,
-            &optimal_palette,
+            &global_palette,
         )
         .map_err(|e| CodeSkewError::OutputError(format!("Failed to create encoder: {e}")))?;
 
@@ -402,34 +488,92 @@ impl<'a> SaveMethods<'a> {
             .set_repeat(Repeat::Infinite)
             .map_err(|e| CodeSkewError::OutputError(format!("Failed to set repeat: {e}")))?;
 
-        // Process frames in parallel batches for maximum throughput
+        // GIF delays are in centiseconds; most viewers clamp anything below 2
+        let frame_delay = (100.0 / self.config.fps).round().max(2.0) as u16;
+
+        // Colors currently shown on the canvas, used to diff the next frame against
+        let mut canvas: Option<Vec<[u8; 3]>> = None;
+
+        // Quantize frames in parallel batches for maximum throughput
         let batch_size = 8;
         for batch in frames.chunks(batch_size) {
-            let processed_frames: Vec<_> = batch
+            let quantized_frames: Vec<(Vec<u8>, Option<Vec<u8>>)> = batch
                 .par_iter()
-                .map(|frame| {
-                    // Convert RGBA to indexed color with optimized quantization
-                    let mut indexed_data = Vec::with_capacity(frame.len());
-                    for pixel in frame.pixels() {
-                        // Ultra-fast luminance-based quantization
-                        let luma =
-                            (pixel[0] as u16 * 77 + pixel[1] as u16 * 151 + pixel[2] as u16 * 28)
-                                >> 8;
-                        indexed_data.push(luma as u8);
+                .map(|frame| match &global_quantizer {
+                    Some(quantizer) => (self.quantize_frame(frame, quantizer), None),
+                    None => {
+                        let quantizer =
+                            self.generate_optimized_palette(std::slice::from_ref(frame), colors);
+                        let palette = Self::palette_bytes(&quantizer, transparent);
+                        (self.quantize_frame(frame, &quantizer), Some(palette))
                     }
-                    indexed_data
                 })
                 .collect();
 
-            // Write frames sequentially to maintain order
-            for indexed_data in processed_frames {
-                let mut frame = Frame::from_indexed_pixels(
-                    self.config.width as u16,
-                    self.config.height as u16,
-                    indexed_data,
-                    None,
-                );
-                frame.delay = 5; // 50ms per frame
+            // Diff and write frames sequentially to maintain order
+            for (indices, local_palette) in quantized_frames {
+                let palette = local_palette.as_deref().unwrap_or(&global_palette);
+                let color_of = |index: u8| {
+                    let offset = index as usize * 3;
+                    [palette[offset], palette[offset + 1], palette[offset + 2]]
+                };
+
+                let mut frame = match (&mut canvas, transparent) {
+                    (Some(canvas), Some(transparent)) => {
+                        // Bounding box of pixels whose displayed color changes
+                        let (mut min_x, mut min_y, mut max_x, mut max_y) = (width, height, 0, 0);
+                        for (i, &index) in indices.iter().enumerate() {
+                            if canvas[i] != color_of(index) {
+                                let (x, y) = (i % width, i / width);
+                                min_x = min_x.min(x);
+                                min_y = min_y.min(y);
+                                max_x = max_x.max(x);
+                                max_y = max_y.max(y);
+                            }
+                        }
+                        if min_x > max_x {
+                            // Nothing changed: emit a single transparent pixel to keep timing
+                            (min_x, min_y, max_x, max_y) = (0, 0, 0, 0);
+                        }
+
+                        let mut delta = Vec::with_capacity((max_x - min_x + 1) * (max_y - min_y + 1));
+                        for y in min_y..=max_y {
+                            for x in min_x..=max_x {
+                                let i = y * width + x;
+                                let color = color_of(indices[i]);
+                                if canvas[i] == color {
+                                    delta.push(transparent);
+                                } else {
+                                    canvas[i] = color;
+                                    delta.push(indices[i]);
+                                }
+                            }
+                        }
+
+                        let mut frame = Frame::from_indexed_pixels(
+                            (max_x - min_x + 1) as u16,
+                            (max_y - min_y + 1) as u16,
+                            delta,
+                            Some(transparent),
+                        );
+                        frame.left = min_x as u16;
+                        frame.top = min_y as u16;
+                        frame
+                    }
+                    _ => {
+                        canvas = Some(indices.iter().map(|&index| color_of(index)).collect());
+                        Frame::from_indexed_pixels(
+                            self.config.width as u16,
+                            self.config.height as u16,
+                            indices,
+                            None,
+                        )
+                    }
+                };
+
+                frame.palette = local_palette;
+                frame.dispose = DisposalMethod::Keep;
+                frame.delay = frame_delay;
 
                 encoder.write_frame(&frame).map_err(|e| {
                     CodeSkewError::OutputError(format!("Failed to write frame: {e}"))
@@ -440,76 +584,311 @@ impl<'a> SaveMethods<'a> {
         Ok(())
     }
 
-    /// Generate optimized color palette for GIF with zero allocation
-    fn generate_optimized_palette(&self, _frames: &[RgbaImage]) -> Vec<u8> {
-        // Ultra-fast palette generation using luminance-based approach
-        let mut palette = Vec::with_capacity(768); // 256 colors * 3 components
+    /// Build a NeuQuant palette of `colors` entries from pixels sampled across `frames`
+    fn generate_optimized_palette(&self, frames: &[RgbaImage], colors: usize) -> NeuQuant {
+        // NeuQuant subsamples on its own; cap the input so long animations stay cheap
+        const MAX_SAMPLE_PIXELS: usize = 1 << 20;
+        // 1 is best quality, 30 is fastest; 10 is the usual trade-off
+        const SAMPLE_FACTOR: i32 = 10;
 
-        // Generate grayscale palette for maximum compatibility
-        for i in 0..256 {
-            palette.push(i as u8); // R
-            palette.push(i as u8); // G
-            palette.push(i as u8); // B
+        let total_pixels: usize = frames.iter().map(|frame| frame.pixels().len()).sum();
+        let stride = total_pixels.div_ceil(MAX_SAMPLE_PIXELS).max(1);
+
+        let mut samples = Vec::with_capacity((total_pixels / stride + 1) * 4);
+        for pixel in frames.iter().flat_map(|frame| frame.pixels()).step_by(stride) {
+            samples.extend_from_slice(&[pixel[0], pixel[1], pixel[2], 255]);
         }
 
+        NeuQuant::new(SAMPLE_FACTOR, colors, &samples)
+    }
+
+    /// RGB palette bytes for the encoder, with the transparent slot appended if requested
+    fn palette_bytes(quantizer: &NeuQuant, transparent: Option<u8>) -> Vec<u8> {
+        let mut palette = quantizer.color_map_rgb();
+        if transparent.is_some() {
+            palette.extend_from_slice(&[0, 0, 0]);
+        }
         palette
     }
 
-    /// Apply dithering for better GIF quality with zero allocation
-    fn apply_dithering_optimized(&self, image: &mut RgbaImage) {
-        let width = self.config.width as usize;
-        let height = self.config.height as usize;
+    /// Map a frame to palette indices, dithering if `--dither` was given
+    fn quantize_frame(&self, image: &RgbaImage, quantizer: &NeuQuant) -> Vec<u8> {
+        if self.config.dither {
+            return self.apply_dithering_optimized(image, quantizer);
+        }
+
+        image
+            .pixels()
+            .map(|pixel| quantizer.index_of(&[pixel[0], pixel[1], pixel[2], 255]) as u8)
+            .collect()
+    }
+
+    /// Floyd–Steinberg dithering against the quantizer palette, returning palette indices
+    fn apply_dithering_optimized(&self, image: &RgbaImage, quantizer: &NeuQuant) -> Vec<u8> {
+        let width = image.width() as usize;
+        let height = image.height() as usize;
+        let mut indices = Vec::with_capacity(width * height);
+
+        // Error diffused into the current and next row, padded by one pixel on each side
+        let mut current_row = vec![[0.0f32; 3]; width + 2];
+        let mut next_row = vec![[0.0f32; 3]; width + 2];
 
-        // Floyd-Steinberg dithering for superior quality
         for y in 0..height {
             for x in 0..width {
                 let pixel = image.get_pixel(x as u32, y as u32);
-                let gray =
-                    (pixel[0] as u16 * 77 + pixel[1] as u16 * 151 + pixel[2] as u16 * 28) >> 8;
-
-                let quantized = if gray > 128 { 255 } else { 0 };
-                let error = gray as i16 - quantized as i16;
-
-                // Distribute error to neighboring pixels
-                if x + 1 < width {
-                    let next_pixel = image.get_pixel_mut((x + 1) as u32, y as u32);
-                    let new_val = (next_pixel[0] as i16 + error * 7 / 16).clamp(0, 255) as u8;
-                    next_pixel[0] = new_val;
-                    next_pixel[1] = new_val;
-                    next_pixel[2] = new_val;
+                let error = current_row[x + 1];
+                let wanted: [f32; 3] =
+                    std::array::from_fn(|c| (pixel[c] as f32 + error[c]).clamp(0.0, 255.0));
+
+                let index = quantizer.index_of(&[
+                    wanted[0].round() as u8,
+                    wanted[1].round() as u8,
+                    wanted[2].round() as u8,
+                    255,
+                ]);
+                let chosen = quantizer.lookup(index).unwrap_or([0, 0, 0, 255]);
+
+                for c in 0..3 {
+                    let error = wanted[c] - chosen[c] as f32;
+                    current_row[x + 2][c] += error * 7.0 / 16.0;
+                    next_row[x][c] += error * 3.0 / 16.0;
+                    next_row[x + 1][c] += error * 5.0 / 16.0;
+                    next_row[x + 2][c] += error / 16.0;
                 }
 
-                if y + 1 < height {
-                    if x > 0 {
-                        let next_pixel = image.get_pixel_mut((x - 1) as u32, (y + 1) as u32);
-                        let new_val = (next_pixel[0] as i16 + error * 3 / 16).clamp(0, 255) as u8;
-                        next_pixel[0] = new_val;
-                        next_pixel[1] = new_val;
-                        next_pixel[2] = new_val;
-                    }
+                indices.push(index as u8);
+            }
 
-                    let next_pixel = image.get_pixel_mut(x as u32, (y + 1) as u32);
-                    let new_val = (next_pixel[0] as i16 + error * 5 / 16).clamp(0, 255) as u8;
-                    next_pixel[0] = new_val;
-                    next_pixel[1] = new_val;
-                    next_pixel[2] = new_val;
-
-                    if x + 1 < width {
-                        let next_pixel = image.get_pixel_mut((x + 1) as u32, (y + 1) as u32);
-                        let new_val = (next_pixel[0] as i16 + error / 16).clamp(0, 255) as u8;
-                        next_pixel[0] = new_val;
-                        next_pixel[1] = new_val;
-                        next_pixel[2] = new_val;
-                    }
-                }
+            std::mem::swap(&mut current_row, &mut next_row);
+            next_row.fill([0.0; 3]);
+        }
 
-                // Set current pixel to quantized value
-                let current_pixel = image.get_pixel_mut(x as u32, y as u32);
-                current_pixel[0] = quantized as u8;
-                current_pixel[1] = quantized as u8;
-                current_pixel[2] = quantized as u8;
+        indices
+    }
+}
+
+/// `value` as a quoted CSS string
+///
+/// `"` is written as a hex escape so the result also fits in a double-quoted attribute.
+fn css_string(value: &str) -> String {
+    let mut quoted = String::from("'");
+    for c in value.chars() {
+        match c {
+            '\\' | '\'' => {
+                quoted.push('\\');
+                quoted.push(c);
             }
+            '"' => quoted.push_str("\\22 "),
+            '\n' => quoted.push_str("\\a "),
+            _ => quoted.push(c),
         }
     }
+    quoted.push('\'');
+    quoted
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    fn config(args: &[&str]) -> Config {
+        let cli = crate::cli::Cli::try_parse_from(["codeskew", "sample.rs"].iter().chain(args)).expect("flags");
+        Config::from_cli(&cli).expect("config")
+    }
+
+    #[test]
+    fn gif_frames_share_a_small_palette_and_only_carry_changes() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let output = dir.path().join("out.gif");
+        let config = config(&["-w", "16", "-H", "8", "--gif-colors", "8", "-o", output.to_str().unwrap()]);
+
+        // Four flat quadrants, then two pixels on the left turn white like the bottom right
+        let first = RgbaImage::from_fn(16, 8, |x, y| match (x < 8, y < 4) {
+            (true, true) => image::Rgba([255, 0, 0, 255]),
+            (false, true) => image::Rgba([0, 255, 0, 255]),
+            (true, false) => image::Rgba([0, 0, 255, 255]),
+            (false, false) => image::Rgba([255, 255, 255, 255]),
+        });
+        let mut second = first.clone();
+        second.put_pixel(2, 2, image::Rgba([255, 255, 255, 255]));
+        second.put_pixel(5, 5, image::Rgba([255, 255, 255, 255]));
+        pollster::block_on(SaveMethods::new(&config).save_gif_animation_optimized(vec![first, second]))
+            .expect("gif");
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(File::open(&output).expect("gif file")).expect("gif header");
+        let palette = decoder.global_palette().expect("global palette");
+        assert!(palette.len() / 3 <= 8, "{} colors", palette.len() / 3);
+
+        decoder.read_next_frame().expect("frame 1").expect("frame 1");
+        let frame = decoder.read_next_frame().expect("frame 2").expect("frame 2");
+        let transparent = frame.transparent.expect("transparent index");
+        assert_eq!((frame.left, frame.top, frame.width, frame.height), (2, 2, 4, 4));
+        for y in 0..4 {
+            for x in 0..4 {
+                let changed = (x, y) == (0, 0) || (x, y) == (3, 3);
+                let index = frame.buffer[y * 4 + x];
+                assert_eq!(index == transparent, !changed, "pixel ({x}, {y}) is {index}");
+            }
+        }
+    }
+
+    #[test]
+    fn telegram_webp_steps_down_until_it_fits() {
+        // Output shrinks with quality; lossless never fits
+        let size = |quality: f32, lossless: bool| {
+            if lossless { 4 * TELEGRAM_MAX_BYTES } else { quality as usize * 4096 }
+        };
+        let mut tried = Vec::new();
+        let webp = SaveMethods::fit_telegram_limit(90.0, true, |quality, lossless| {
+            tried.push((quality, lossless));
+            Ok(vec![0u8; size(quality, lossless)])
+        })
+        .expect("fits");
+        assert!(webp.len() <= TELEGRAM_MAX_BYTES);
+        assert_eq!(tried, [(90.0, true), (90.0, false), (80.0, false), (70.0, false), (60.0, false)]);
+
+        // Too big at every quality: stops at the minimum instead of going below it
+        let mut tried = Vec::new();
+        let error = SaveMethods::fit_telegram_limit(35.0, false, |quality, _| {
+            tried.push(quality);
+            Ok(vec![0u8; TELEGRAM_MAX_BYTES + 1])
+        })
+        .unwrap_err();
+        assert_eq!(tried, [35.0, 25.0, 15.0, MIN_TELEGRAM_WEBP_QUALITY]);
+        assert!(error.to_string().contains("minimum quality"), "{error}");
+    }
+
+    /// Tags of the saved SVG as (name, attributes), with tspan and style text as a `text` attribute
+    fn svg_tags(path: &std::path::Path) -> Vec<(String, std::collections::HashMap<String, String>)> {
+        use svg::parser::Event;
+        use svg::node::element::tag::Type;
+
+        let mut source = String::new();
+        let mut tags: Vec<(String, std::collections::HashMap<String, String>)> = Vec::new();
+        for event in svg::open(path, &mut source).expect("svg file") {
+            match event {
+                Event::Tag(name, Type::Start | Type::Empty, attributes) => tags.push((
+                    name.to_string(),
+                    attributes.iter().map(|(key, value)| (key.clone(), value.to_string())).collect(),
+                )),
+                Event::Text(text) => {
+                    if let Some((name, attributes)) = tags.last_mut()
+                        && (name == "tspan" || name == "style")
+                    {
+                        attributes.insert("text".into(), text.to_string());
+                    }
+                }
+                Event::Error(error) => panic!("invalid SVG: {error}"),
+                _ => {}
+            }
+        }
+        tags
+    }
+
+    #[test]
+    fn svg_lines_keep_their_colors_over_the_chosen_background() {
+        use crate::layout::{CharColor, StyledChar};
+
+        let line = |text: &str, colors: &[(u8, u8, u8)], y: f32| PositionedLine {
+            chars: text
+                .chars()
+                .zip(colors.iter().cycle())
+                .map(|(char, &(r, g, b))| StyledChar { char, color: CharColor { r, g, b } })
+                .collect(),
+            x: 10.0,
+            y,
+            scale: 1.0,
+        };
+        let red = (0xff, 0x00, 0x00);
+        let blue = (0x00, 0x00, 0xff);
+        let layout = [
+            line("a<b", &[red, red, red], 20.0),
+            line("&&c", &[red, red, blue], 40.0),
+        ];
+
+        let dir = tempfile::tempdir().expect("temp dir");
+        let output = dir.path().join("out.svg");
+        let output = output.to_str().unwrap();
+
+        let image_config = config(&["-w", "64", "-H", "48", "-F", "svg", "--svg-background", "image", "-o", output]);
+        let frame = RgbaImage::from_pixel(64, 48, image::Rgba([0, 128, 0, 255]));
+        pollster::block_on(SaveMethods::new(&image_config).save_svg_optimized(&layout, 12.0, Some(frame)))
+            .expect("svg");
+        let tags = svg_tags(output.as_ref());
+
+        let texts: Vec<_> = tags.iter().filter(|(name, _)| name == "text").collect();
+        assert_eq!(texts.len(), 2);
+        for (_, attributes) in &texts {
+            let transform = &attributes["transform"];
+            let values: Vec<f32> = transform
+                .strip_prefix("matrix(")
+                .and_then(|rest| rest.strip_suffix(')'))
+                .expect("matrix transform")
+                .split(' ')
+                .map(|value| value.parse().expect("matrix value"))
+                .collect();
+            assert_eq!(values.len(), 6, "{transform}");
+        }
+        let spans: Vec<_> = tags
+            .iter()
+            .filter(|(name, _)| name == "tspan")
+            .map(|(_, attributes)| (attributes["fill"].as_str(), attributes["text"].as_str()))
+            .collect();
+        assert_eq!(
+            spans,
+            [("#ff0000", "a&lt;b"), ("#ff0000", "&amp;&amp;"), ("#0000ff", "c")]
+        );
+        assert!(tags.iter().any(|(name, attributes)| name == "image"
+            && attributes["href"].starts_with("data:image/png;base64,")));
+        assert!(!tags.iter().any(|(name, _)| name.ends_with("Gradient")));
+
+        for (spec, element) in [("linear(90deg, red, blue)", "linearGradient"), ("radial(red, blue)", "radialGradient")] {
+            let config = config(&[
+                "-w", "64", "-H", "48", "-F", "svg", "--svg-background", "gradient", "--gradient", spec, "-o", output,
+            ]);
+            pollster::block_on(SaveMethods::new(&config).save_svg_optimized(&layout, 12.0, None)).expect("svg");
+            let tags = svg_tags(output.as_ref());
+            assert!(tags.iter().any(|(name, _)| name == element), "{spec}");
+            assert!(!tags.iter().any(|(name, _)| name == "image"), "{spec}");
+            assert_eq!(tags.iter().filter(|(name, _)| name == "text").count(), 2);
+        }
+    }
+
+    #[test]
+    fn svg_font_family_is_escaped() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let font_file = dir.path().join("font.ttf");
+        std::fs::write(&font_file, b"not really a font").expect("font file");
+        let output = dir.path().join("out.svg");
+        let config = config(&[
+            "-w", "64", "-H", "48", "-F", "svg", "--svg-background", "gradient",
+            "--font", r#"O'Neil <Mono> & \"Co\""#,
+            "--font-file", font_file.to_str().unwrap(),
+            "-o", output.to_str().unwrap(),
+        ]);
+        pollster::block_on(SaveMethods::new(&config).save_svg_optimized(&[], 12.0, None)).expect("svg");
+
+        // Quotes and backslashes are CSS-escaped, then the markup is XML-escaped
+        let family = r#"'O\'Neil <Mono> & \\\22 Co\\\22 '"#;
+        let unescape = |xml: &str| {
+            xml.replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&apos;", "'")
+                .replace("&quot;", "\"")
+                .replace("&amp;", "&")
+        };
+        let tags = svg_tags(&output);
+        let style = tags.iter().find(|(name, _)| name == "style").expect("font-face style");
+        assert!(!style.1["text"].contains('<'), "{}", style.1["text"]);
+        assert!(
+            unescape(&style.1["text"]).contains(&format!("font-family: {family};")),
+            "{}",
+            style.1["text"]
+        );
+        let group = tags.iter().find(|(_, attributes)| attributes.contains_key("font-family")).expect("text group");
+        assert_eq!(unescape(&group.1["font-family"]), format!("{family}, monospace"));
+    }
+}
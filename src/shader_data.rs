@@ -3,16 +3,15 @@
 
 use crate::layout::PositionedLine;
 
-pub const TERMINAL_ROWS: usize = 30;
-pub const TERMINAL_COLS: usize = 80;
-
-/// ASCII grid for terminal characters
-pub type TerminalGrid = [[u32; TERMINAL_COLS]; TERMINAL_ROWS];
+/// ASCII grid for terminal characters, one row per line
+pub type TerminalGrid = Vec<Vec<u32>>;
 
 /// Color grid for syntax highlighting (packed RGB)
-pub type ColorGrid = [[u32; TERMINAL_COLS]; TERMINAL_ROWS];
+pub type ColorGrid = Vec<Vec<u32>>;
 
 /// Shader data containing ASCII characters and colors for GPU rendering
+///
+/// The grid is sized from the layout, so every row has `cols_used` entries.
 #[derive(Debug, Clone)]
 pub struct ShaderTextData {
     pub terminal_grid: TerminalGrid,
@@ -24,27 +23,26 @@ pub struct ShaderTextData {
 impl ShaderTextData {
     /// Create new empty shader text data
     pub fn new() -> Self {
+        Self::with_size(0, 0)
+    }
+
+    /// Create blank shader text data with room for `rows` x `cols` characters
+    pub fn with_size(rows: usize, cols: usize) -> Self {
         Self {
-            terminal_grid: [[0; TERMINAL_COLS]; TERMINAL_ROWS],
-            color_grid: [[0xFFFFFF; TERMINAL_COLS]; TERMINAL_ROWS], // Default white
-            rows_used: 0,
-            cols_used: 0,
+            terminal_grid: vec![vec![0; cols]; rows],
+            color_grid: vec![vec![0xFFFFFF; cols]; rows], // Default white
+            rows_used: rows,
+            cols_used: cols,
         }
     }
 
     /// Generate shader data from positioned lines (syntax-highlighted layout)
     pub fn from_layout(layout: &[PositionedLine]) -> Self {
-        let mut data = Self::new();
-        let mut row = 0;
-
-        for line in layout.iter().take(TERMINAL_ROWS) {
-            let mut col = 0;
-            
-            for styled_char in &line.chars {
-                if col >= TERMINAL_COLS {
-                    break; // Line too long for terminal
-                }
+        let cols = layout.iter().map(|line| line.chars.len()).max().unwrap_or(0);
+        let mut data = Self::with_size(layout.len(), cols);
 
+        for (row, line) in layout.iter().enumerate() {
+            for (col, styled_char) in line.chars.iter().enumerate() {
                 // Store ASCII character
                 data.terminal_grid[row][col] = styled_char.char as u32;
 
@@ -53,15 +51,8 @@ impl ShaderTextData {
                 let g = (styled_char.color.g as u32) << 8;
                 let b = styled_char.color.b as u32;
                 data.color_grid[row][col] = r | g | b;
-
-                col += 1;
             }
-
-            data.cols_used = data.cols_used.max(col);
-            row += 1;
         }
-
-        data.rows_used = row;
         
         println!("🔤 Generated shader text data: {}x{} characters", data.rows_used, data.cols_used);
         
@@ -70,29 +61,17 @@ impl ShaderTextData {
 
     /// Convert to flat buffer for GPU upload
     pub fn to_terminal_buffer(&self) -> Vec<u32> {
-        let mut buffer = Vec::with_capacity(TERMINAL_ROWS * TERMINAL_COLS);
-        
-        for row in &self.terminal_grid {
-            buffer.extend_from_slice(row);
-        }
-        
-        buffer
+        self.terminal_grid.concat()
     }
 
     /// Convert to flat color buffer for GPU upload
     pub fn to_color_buffer(&self) -> Vec<u32> {
-        let mut buffer = Vec::with_capacity(TERMINAL_ROWS * TERMINAL_COLS);
-        
-        for row in &self.color_grid {
-            buffer.extend_from_slice(row);
-        }
-        
-        buffer
+        self.color_grid.concat()
     }
 
     /// Get buffer sizes for GPU allocation
-    pub fn buffer_size() -> usize {
-        TERMINAL_ROWS * TERMINAL_COLS * std::mem::size_of::<u32>()
+    pub fn buffer_size(&self) -> usize {
+        self.rows_used * self.cols_used * std::mem::size_of::<u32>()
     }
 }
 
@@ -105,24 +84,24 @@ impl Default for ShaderTextData {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::layout::{PositionedLine, StyledChar};
-    use crate::gradient::ColorRgb;
+    use crate::layout::{CharColor, PositionedLine, StyledChar};
 
     #[test]
     fn test_shader_data_generation() {
-        let mut line = PositionedLine {
+        let line = PositionedLine {
             chars: vec![
                 StyledChar {
                     char: 'f',
-                    color: ColorRgb { r: 255, g: 100, b: 50 }, // Orange
+                    color: CharColor { r: 255, g: 100, b: 50 }, // Orange
                 },
                 StyledChar {
                     char: 'n',
-                    color: ColorRgb { r: 100, g: 255, b: 100 }, // Green
+                    color: CharColor { r: 100, g: 255, b: 100 }, // Green
                 },
             ],
             x: 0.0,
             y: 0.0,
+            scale: 1.0,
         };
 
         let layout = vec![line];
@@ -135,4 +114,30 @@ mod tests {
         assert_eq!(data.rows_used, 1);
         assert_eq!(data.cols_used, 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_shader_data_is_sized_from_layout() {
+        let line = |len: usize| PositionedLine {
+            chars: (0..len)
+                .map(|i| StyledChar {
+                    char: if i % 2 == 0 { 'x' } else { 'y' },
+                    color: CharColor { r: 1, g: 2, b: 3 },
+                })
+                .collect(),
+            x: 0.0,
+            y: 0.0,
+            scale: 1.0,
+        };
+
+        let mut layout: Vec<_> = (0..200).map(|_| line(10)).collect();
+        layout[199] = line(140);
+        let data = ShaderTextData::from_layout(&layout);
+
+        assert_eq!(data.rows_used, 200);
+        assert_eq!(data.cols_used, 140);
+        assert_eq!(data.terminal_grid[199][139], 'y' as u32);
+        assert_eq!(data.terminal_grid[0][10], 0);
+        assert_eq!(data.to_terminal_buffer().len(), 200 * 140);
+        assert_eq!(data.buffer_size(), 200 * 140 * 4);
+    }
+}
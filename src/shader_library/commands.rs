@@ -0,0 +1,276 @@
+//! `codeskew shaders` subcommand: list, check and preview the shader library
+
+use super::{ShaderLibrary, ShaderMetadata};
+use crate::cli::{Cli, ShadersArgs};
+use crate::config::Config;
+use crate::output::OutputGenerator;
+use crate::error::CodeSkewError;
+use anyhow::{Context, Result};
+use clap::Parser;
+use futures::FutureExt;
+use image::{Rgba, RgbaImage, imageops};
+use std::ffi::OsStr;
+use std::io::Write;
+use std::panic::AssertUnwindSafe;
+use std::path::Path;
+
+/// Code drawn over the thumbnails when no `--input` is given
+const SAMPLE_CODE: &str = r#"fn main() {
+    let library = ShaderLibrary::new(&[]);
+    for name in library.shader_names() {
+        println!("{name}");
+    }
+}
+"#;
+
+/// Contact sheet tile for shaders that failed to render
+const FAILED_TILE: Rgba<u8> = Rgba([96, 16, 16, 255]);
+
+/// What was found out about one shader
+struct ShaderReport {
+    name: String,
+    location: String,
+    metadata: Option<Result<ShaderMetadata, String>>,
+    /// `None` when the shader was not compiled
+    status: Option<Result<(), String>>,
+}
+
+/// Run `codeskew shaders`
+pub async fn run(args: &ShadersArgs) -> Result<()> {
+    run_to(args, &mut std::io::stdout()).await
+}
+
+/// Run `codeskew shaders`, listing each shader to `out` as soon as it is checked
+async fn run_to(args: &ShadersArgs, out: &mut impl Write) -> Result<()> {
+    if args.thumb_size == 0 {
+        anyhow::bail!("--thumb-size must be at least 1 pixel");
+    }
+    if let Some(input) = &args.input
+        && input.extension().is_some_and(|ext| ext == "wgsl")
+    {
+        anyhow::bail!("--input is the code drawn over each shader, not a shader: {}", input.display());
+    }
+
+    let library = ShaderLibrary::new(&args.shader_dirs);
+    let names = library.shader_names();
+    let code = match &args.input {
+        Some(path) => std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?,
+        None => SAMPLE_CODE.to_string(),
+    };
+
+    let compile = !args.no_check || args.contact_sheet.is_some();
+    let mut generator = if compile {
+        Some(OutputGenerator::new(preview_config(args)?)?)
+    } else {
+        None
+    };
+
+    let dirs: Vec<String> = library.dirs().iter().map(|dir| dir.display().to_string()).collect();
+    writeln!(out, "Search path: {}, then the embedded library", dirs.join(", "))?;
+
+    let mut failures = 0;
+    let mut thumbnails = Vec::new();
+    for (index, name) in names.iter().enumerate() {
+        let location = match library.read_shader(name) {
+            Ok(shader) => shader.display(),
+            Err(e) => e.to_string(),
+        };
+        let metadata = library
+            .read_metadata(name)
+            .map(|file| ShaderMetadata::parse(&file).map_err(|e| e.to_string()));
+
+        let status = match generator.as_mut() {
+            Some(generator) if args.contact_sheet.is_some() => {
+                let preview = isolated(generator.render_shader_preview(name, &code)).await;
+                thumbnails.push(preview.as_ref().ok().cloned());
+                Some(preview.map(|_| ()))
+            }
+            Some(generator) => Some(isolated(generator.check_shader(name, &code)).await),
+            None => None,
+        };
+        if let Some(Err(e)) = &status {
+            failures += 1;
+            // A panic can leave the generator half way through a shader, so start afresh
+            if e.starts_with(PANICKED) {
+                generator = Some(OutputGenerator::new(preview_config(args)?)?);
+            }
+        }
+
+        let report = ShaderReport {
+            name: name.clone(),
+            location,
+            metadata,
+            status,
+        };
+        print_report(out, index, &report)?;
+        out.flush()?;
+    }
+
+    if let Some(path) = &args.contact_sheet {
+        let sheet = contact_sheet(&thumbnails, args.thumb_size);
+        sheet
+            .save(path)
+            .with_context(|| format!("Failed to write contact sheet {}", path.display()))?;
+        writeln!(
+            out,
+            "🖼️  Contact sheet with {} shaders, numbered left to right → {}",
+            thumbnails.len(),
+            path.display()
+        )?;
+    }
+
+    if failures > 0 {
+        anyhow::bail!("{failures} of {} shaders failed to compile", names.len());
+    }
+    writeln!(out, "{} shaders", names.len())?;
+    Ok(())
+}
+
+/// Start of the error reported for a shader whose check panicked
+const PANICKED: &str = "panicked: ";
+
+/// Check or render one shader, reporting a panic as that shader's error
+async fn isolated<T>(work: impl Future<Output = Result<T, CodeSkewError>>) -> Result<T, String> {
+    match AssertUnwindSafe(work).catch_unwind().await {
+        Ok(result) => result.map_err(|e| e.to_string()),
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("{PANICKED}{message}"))
+        }
+    }
+}
+
+/// Render settings for thumbnails: the defaults, at `--thumb-size`
+fn preview_config(args: &ShadersArgs) -> Result<Config> {
+    let input = args.input.as_deref().unwrap_or(Path::new("sample.rs"));
+    let cli = Cli::try_parse_from([OsStr::new("codeskew"), input.as_os_str()])?;
+    let mut config = Config::from_cli(&cli)?;
+    config.width = args.thumb_size;
+    config.height = args.thumb_size;
+    config.shader_dirs = args.shader_dirs.clone();
+    config.backend = args.backend;
+    Ok(config)
+}
+
+fn print_report(out: &mut impl Write, index: usize, report: &ShaderReport) -> std::io::Result<()> {
+    let marker = match report.status {
+        Some(Ok(())) => "✅",
+        Some(Err(_)) => "❌",
+        None => "•",
+    };
+    writeln!(out, "{index:>3} {marker} {:<40} {}", report.name, report.location)?;
+
+    match &report.metadata {
+        Some(Ok(metadata)) => {
+            if !metadata.uniforms.is_empty() {
+                let uniforms: Vec<String> =
+                    metadata.uniforms.iter().map(|u| format!("{}={}", u.name, u.value)).collect();
+                writeln!(out, "        uniforms: {}", uniforms.join(", "))?;
+            }
+            if !metadata.textures.is_empty() {
+                let textures: Vec<&str> = metadata.textures.iter().map(|t| t.img.as_str()).collect();
+                writeln!(out, "        textures: {}", textures.join(", "))?;
+            }
+            if let Some(enabled) = metadata.float32_enabled {
+                writeln!(out, "        float32Enabled: {enabled}")?;
+            }
+        }
+        Some(Err(e)) => writeln!(out, "        ⚠️  {e}")?,
+        None => {}
+    }
+
+    if let Some(Err(e)) = &report.status {
+        for line in e.lines() {
+            writeln!(out, "        {line}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Lay the thumbnails out in a square-ish grid, failed renders as dark red tiles
+fn contact_sheet(thumbnails: &[Option<RgbaImage>], size: u32) -> RgbaImage {
+    let count = thumbnails.len().max(1) as u32;
+    let columns = (count as f32).sqrt().ceil() as u32;
+    let rows = count.div_ceil(columns);
+
+    let mut sheet = RgbaImage::from_pixel(columns * size, rows * size, Rgba([0, 0, 0, 255]));
+    let failed = RgbaImage::from_pixel(size, size, FAILED_TILE);
+    for (index, thumbnail) in thumbnails.iter().enumerate() {
+        let index = index as u32;
+        let (x, y) = ((index % columns) * size, (index / columns) * size);
+        imageops::replace(&mut sheet, thumbnail.as_ref().unwrap_or(&failed), x as i64, y as i64);
+    }
+    sheet
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contact_sheets_tile_thumbnails_in_a_square_grid() {
+        let tile = |value| Some(RgbaImage::from_pixel(4, 4, Rgba([value, value, value, 255])));
+        let thumbnails = [tile(10), None, tile(30), tile(40), tile(50)];
+        let sheet = contact_sheet(&thumbnails, 4);
+
+        // Five tiles fill two of three rows of three, the rest stays black
+        assert_eq!(sheet.dimensions(), (12, 8));
+        assert_eq!(*sheet.get_pixel(1, 1), Rgba([10, 10, 10, 255]));
+        assert_eq!(*sheet.get_pixel(5, 2), FAILED_TILE);
+        assert_eq!(*sheet.get_pixel(11, 3), Rgba([30, 30, 30, 255]));
+        assert_eq!(*sheet.get_pixel(0, 4), Rgba([40, 40, 40, 255]));
+        assert_eq!(*sheet.get_pixel(7, 7), Rgba([50, 50, 50, 255]));
+        assert_eq!(*sheet.get_pixel(8, 4), Rgba([0, 0, 0, 255]));
+
+        assert_eq!(contact_sheet(&[], 4).dimensions(), (4, 4));
+    }
+
+    #[test]
+    fn no_check_lists_shader_dirs_before_the_embedded_library() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        std::fs::create_dir_all(dir.path().join("mine")).expect("shader dir");
+        std::fs::create_dir_all(dir.path().join("include")).expect("include dir");
+        std::fs::write(dir.path().join("mine/glow.wgsl"), "// glow").expect("shader");
+        std::fs::write(
+            dir.path().join("mine/glow.wgsl.json"),
+            r#"{"uniforms": [{"name": "speed", "value": 0.5}], "textures": [], "float32Enabled": true}"#,
+        )
+        .expect("metadata");
+        std::fs::write(dir.path().join("include/helpers.wgsl"), "// helpers").expect("include");
+        std::fs::write(dir.path().join("hash.wgsl"), "// my hash").expect("override");
+        std::fs::write(dir.path().join("hash.wgsl.json"), "not json").expect("bad metadata");
+
+        let args = ShadersArgs {
+            no_check: true,
+            contact_sheet: None,
+            input: None,
+            thumb_size: 256,
+            shader_dirs: vec![dir.path().to_path_buf()],
+            backend: Default::default(),
+        };
+        let mut out = Vec::new();
+        pollster::block_on(run_to(&args, &mut out)).expect("listing");
+        let out = String::from_utf8(out).expect("utf-8");
+        let line = |name: &str| {
+            out.lines()
+                .find(|line| line.split_whitespace().nth(2) == Some(name))
+                .unwrap_or_else(|| panic!("{name} not listed:\n{out}"))
+        };
+
+        assert!(out.starts_with(&format!("Search path: {}", dir.path().display())), "{out}");
+        assert!(line("mine/glow").ends_with(&dir.path().join("mine/glow.wgsl").display().to_string()));
+        assert!(out.contains("        uniforms: speed=0.5\n        float32Enabled: true\n"), "{out}");
+        assert!(line("hash").ends_with(&dir.path().join("hash.wgsl").display().to_string()));
+        let bad_metadata = format!("Invalid shader metadata {}", dir.path().join("hash.wgsl.json").display());
+        assert!(out.contains(&bad_metadata), "{out}");
+        assert!(line("bandwidth").ends_with("<embedded>/bandwidth.wgsl"));
+        assert!(!out.contains("helpers"), "{out}");
+        assert!(out.lines().all(|line| !line.contains('✅') && !line.contains('❌')), "{out}");
+        assert!(out.trim_end().ends_with(" shaders"), "{out}");
+    }
+}
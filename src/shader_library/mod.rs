@@ -0,0 +1,195 @@
+//! Shader library lookup for `--shader` names, `#include`s and shader metadata
+//!
+//! Files are searched for in `--shader-dir`, `$CODESKEW_SHADER_PATH`, the XDG data
+//! directory and finally the copy of `wgsl/` embedded in the binary, so an installed
+//! binary works from any directory.
+
+pub mod commands;
+
+use crate::error::CodeSkewError;
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// Environment variable with extra shader directories, separated like `$PATH`
+pub const SHADER_PATH_ENV: &str = "CODESKEW_SHADER_PATH";
+
+/// The `wgsl/` directory as it was at build time, keyed by path relative to it
+static EMBEDDED: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/embedded_shaders.rs"));
+
+/// Directories searched for shaders, before the shaders embedded in the binary
+#[derive(Debug, Clone, Default)]
+pub struct ShaderLibrary {
+    dirs: Vec<PathBuf>,
+}
+
+/// A file found in the shader library
+#[derive(Debug, Clone)]
+pub struct LibraryFile {
+    /// Location on disk, `None` for embedded files
+    pub path: Option<PathBuf>,
+    /// Path relative to the library root, e.g. `davidar/caustics.wgsl`
+    pub name: String,
+    pub contents: Cow<'static, str>,
+}
+
+/// Shader metadata structure matching wgpu-compute-toy JSON format
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ShaderMetadata {
+    pub uniforms: Vec<ShaderUniform>,
+    pub textures: Vec<ShaderTexture>,
+    #[serde(rename = "float32Enabled")]
+    pub float32_enabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ShaderUniform {
+    pub name: String,
+    pub value: f32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ShaderTexture {
+    pub img: String,
+    pub thumb: Option<String>,
+    pub url: Option<String>,
+}
+
+impl ShaderMetadata {
+    /// Parse a `.wgsl.json` file
+    pub fn parse(file: &LibraryFile) -> Result<Self, CodeSkewError> {
+        serde_json::from_str(&file.contents).map_err(|e| {
+            CodeSkewError::ConfigError(format!("Invalid shader metadata {}: {e}", file.display()))
+        })
+    }
+}
+
+impl LibraryFile {
+    /// Where the file came from, for messages
+    pub fn display(&self) -> String {
+        match &self.path {
+            Some(path) => path.display().to_string(),
+            None => format!("<embedded>/{}", self.name),
+        }
+    }
+}
+
+impl ShaderLibrary {
+    /// The standard search path, with `shader_dirs` from `--shader-dir` searched first
+    pub fn new(shader_dirs: &[PathBuf]) -> Self {
+        let mut dirs = shader_dirs.to_vec();
+        if let Some(path) = std::env::var_os(SHADER_PATH_ENV) {
+            dirs.extend(std::env::split_paths(&path).filter(|dir| !dir.as_os_str().is_empty()));
+        }
+        if let Some(project) = ProjectDirs::from("ai", "cyrup", "codeskew") {
+            dirs.push(project.data_dir().join("shaders"));
+        }
+        Self { dirs }
+    }
+
+    /// Directories searched, in order; embedded shaders come after all of them
+    pub fn dirs(&self) -> &[PathBuf] {
+        &self.dirs
+    }
+
+    /// Read the shader `name`, e.g. `bandwidth` or `davidar/caustics`
+    pub fn read_shader(&self, name: &str) -> Result<LibraryFile, CodeSkewError> {
+        if !is_shader_name(name) {
+            return Err(CodeSkewError::ConfigError(format!("Invalid shader name '{name}'")));
+        }
+        self.read(&format!("{name}.wgsl")).ok_or_else(|| {
+            let dirs: Vec<String> = self.dirs.iter().map(|dir| dir.display().to_string()).collect();
+            CodeSkewError::ConfigError(format!(
+                "Shader '{name}' not found in {} or the embedded shaders",
+                dirs.join(", ")
+            ))
+        })
+    }
+
+    /// Read the `.wgsl.json` metadata of the shader `name`
+    pub fn read_metadata(&self, name: &str) -> Option<LibraryFile> {
+        is_shader_name(name).then(|| self.read(&format!("{name}.wgsl.json")))?
+    }
+
+    /// Read the file an `#include` names, e.g. `std/math` for `#include <math>`
+    pub fn read_include(&self, name: &str) -> Option<LibraryFile> {
+        is_shader_name(name).then(|| self.read(&format!("include/{name}.wgsl")))?
+    }
+
+    /// The file on disk for a library-relative path, such as a texture next to a shader
+    pub fn find(&self, name: &str) -> Option<PathBuf> {
+        self.dirs.iter().map(|dir| dir.join(name)).find(|path| path.is_file())
+    }
+
+    /// Names of every shader in the search path and the embedded library, sorted
+    ///
+    /// `include/` holds `#include` files rather than shaders and is skipped.
+    pub fn shader_names(&self) -> Vec<String> {
+        let mut names = BTreeSet::new();
+        for dir in &self.dirs {
+            collect_shader_names(dir, dir, &mut names);
+        }
+        names.extend(
+            EMBEDDED
+                .iter()
+                .filter_map(|(name, _)| name.strip_suffix(".wgsl"))
+                .filter(|name| !name.starts_with("include/"))
+                .map(str::to_string),
+        );
+        names.into_iter().collect()
+    }
+
+    /// The first copy of `name` on disk, else the embedded one
+    fn read(&self, name: &str) -> Option<LibraryFile> {
+        if let Some(path) = self.find(name)
+            && let Ok(contents) = std::fs::read_to_string(&path)
+        {
+            return Some(LibraryFile {
+                path: Some(path),
+                name: name.to_string(),
+                contents: Cow::Owned(contents),
+            });
+        }
+
+        EMBEDDED
+            .iter()
+            .find(|(embedded, _)| *embedded == name)
+            .map(|(_, contents)| LibraryFile {
+                path: None,
+                name: name.to_string(),
+                contents: Cow::Borrowed(*contents),
+            })
+    }
+}
+
+/// Shader names are relative paths without extension; `..` and absolute paths are rejected
+pub fn is_shader_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('/').all(|part| {
+            !part.is_empty()
+                && part != "."
+                && part != ".."
+                && part.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        })
+}
+
+/// Add the names of the `.wgsl` files below `dir` to `names`, relative to `root`
+fn collect_shader_names(root: &Path, dir: &Path, names: &mut BTreeSet<String>) {
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(relative) = path.strip_prefix(root) else { continue };
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if path.is_dir() {
+            if relative != "include" {
+                collect_shader_names(root, &path, names);
+            }
+        } else if let Some(name) = relative.strip_suffix(".wgsl")
+            && is_shader_name(name)
+        {
+            names.insert(name.to_string());
+        }
+    }
+}
@@ -1,10 +1,20 @@
 use super::context::WgpuContext;
+use crate::transform::ViewCamera;
+use nalgebra::Matrix4;
 use bitvec::prelude::*;
 use std::mem::size_of;
 
 const NUM_KEYCODES: usize = 256;
-const MAX_CUSTOM_PARAMS: usize = 32;
+/// Fields the `custom` uniform (`struct Custom`) can hold
+pub const MAX_CUSTOM_PARAMS: usize = 32;
 pub const NUM_ASSERT_COUNTERS: usize = 10;
+/// Records `debugLog` can write per frame; later calls are counted but dropped
+pub const MAX_DEBUG_RECORDS: usize = 4096;
+/// `DebugLog` layout: a 16-byte header holding the record count, then 32-byte records
+pub const DEBUG_HEADER_BYTES: usize = 16;
+pub const DEBUG_RECORD_BYTES: usize = 32;
+/// Dispatch ids available to `#dispatch_count`, one `OFFSET_ALIGNMENT` slot each
+pub const MAX_DISPATCH_COUNT: u32 = 256;
 const USER_DATA_BYTES: usize = 4096;
 pub const OFFSET_ALIGNMENT: usize = 256;
 
@@ -130,15 +140,71 @@ pub struct Mouse {
     pub click: i32,
 }
 
+/// Text plane warp read as `codeskew_camera.*`, filled from the perspective flags
+#[derive(Clone, Copy, Debug, PartialEq, bytemuck::Zeroable, bytemuck::Pod, serde::Serialize)]
+#[repr(C)]
+pub struct Camera {
+    /// `--skew`, converted to radians
+    pub skew: f32,
+    /// `--depth`: how much nearer text is shaded brighter and more opaque
+    pub depth: f32,
+    /// `--perspective`: viewing distance in pixels
+    pub perspective: f32,
+    /// `--fold`: extra vertical scale at `fold_point`
+    pub fold: f32,
+    /// `--fold-point`: fraction of the height where text is largest
+    pub fold_point: f32,
+    /// `--skew-angle`: how far the top of the text leans right, as a fraction of the width
+    pub skew_angle: f32,
+    /// `--scale`: how much larger the left edge is than the right
+    pub scale: f32,
+    /// Text plane height in pixels, the unit of `perspective`
+    pub height: f32,
+    /// Text plane pixel coordinates to clip space, see [`ViewCamera::view_projection`]
+    pub view_proj: [[f32; 4]; 4],
+    /// Output uv to text uv as a `mat3x3f`, whose columns are padded to four floats
+    pub text_from_screen: [[f32; 4]; 3],
+}
+
+impl Default for Camera {
+    /// The CLI defaults, looking straight at the text
+    fn default() -> Self {
+        Self {
+            skew: 15f32.to_radians(),
+            depth: 0.5,
+            perspective: 1000.,
+            fold: 0.4,
+            fold_point: 0.67,
+            skew_angle: 0.15,
+            scale: 0.6,
+            height: 800.,
+            view_proj: Matrix4::identity().into(),
+            text_from_screen: [[1., 0., 0., 0.], [0., 1., 0., 0.], [0., 0., 1., 0.]],
+        }
+    }
+}
+
+impl Camera {
+    /// Take the matrices from a host-side camera
+    pub fn with_view(mut self, view: &ViewCamera) -> Self {
+        self.view_proj = view.view_projection().into();
+        let m = view.text_from_screen_uv();
+        self.text_from_screen = std::array::from_fn(|c| [m[(0, c)], m[(1, c)], m[(2, c)], 0.]);
+        self
+    }
+}
+
 pub struct Bindings {
     pub time: BufferBinding<Time>,
     pub mouse: BufferBinding<Mouse>,
+    pub camera: BufferBinding<Camera>,
     pub keys: BufferBinding<BitArr!(for NUM_KEYCODES, in u8, Lsb0)>,
     pub custom: BufferBinding<(Vec<String>, Vec<f32>)>,
     pub user_data: BufferBinding<indexmap::IndexMap<String, Vec<u32>>>,
 
     pub storage1: BufferBinding<()>,
     pub storage2: BufferBinding<()>,
+    pub assert_counts: BufferBinding<()>,
     pub debug_buffer: BufferBinding<()>,
     pub dispatch_info: BufferBinding<()>,
 
@@ -147,6 +213,8 @@ pub struct Bindings {
     pub tex_write: TextureBinding,
     pub channels: Vec<TextureBinding>,
     pub font_atlas: TextureBinding,
+    /// `--gradient` at screen size, sRGB-encoded like `screen`; transparent black without one
+    pub background: TextureBinding,
 
     nearest: SamplerBinding,
     bilinear: SamplerBinding,
@@ -262,7 +330,11 @@ impl Bindings {
         });
         let channel0 = wgpu.device.create_texture(&blank);
         let channel1 = wgpu.device.create_texture(&blank);
-        Bindings {
+        let background = wgpu.device.create_texture(&wgpu::TextureDescriptor {
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            ..blank
+        });
+        let bindings = Bindings {
             time: BufferBinding {
                 host: Time {
                     frame: 0,
@@ -296,6 +368,19 @@ impl Bindings {
                 bind: Box::new(wgpu::Buffer::as_entire_buffer_binding),
                 decl: "var<uniform> mouse: Mouse".to_string(),
             },
+            camera: BufferBinding {
+                host: Camera::default(),
+                serialise: Box::new(|h| bytemuck::bytes_of(h).to_vec()),
+                device: wgpu.device.create_buffer(&wgpu::BufferDescriptor {
+                    label: None,
+                    size: uniform_buffer_size::<Camera>(),
+                    usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
+                    mapped_at_creation: false,
+                }),
+                layout: uniform_buffer,
+                bind: Box::new(wgpu::Buffer::as_entire_buffer_binding),
+                decl: "var<uniform> codeskew_camera: CodeskewCamera".to_string(),
+            },
             keys: BufferBinding {
                 host: bitarr![u8, Lsb0; 0; 256],
                 serialise: Box::new(|h| h.as_raw_slice().to_vec()),
@@ -377,7 +462,7 @@ impl Bindings {
                 bind: Box::new(wgpu::Buffer::as_entire_buffer_binding),
                 decl: "var<storage,read_write> buffer1: array<u32>".to_string(),
             },
-            debug_buffer: BufferBinding {
+            assert_counts: BufferBinding {
                 host: (),
                 serialise: Box::new(|_| vec![]),
                 device: wgpu.device.create_buffer(&wgpu::BufferDescriptor {
@@ -392,12 +477,27 @@ impl Bindings {
                 bind: Box::new(wgpu::Buffer::as_entire_buffer_binding),
                 decl: "var<storage,read_write> _assert_counts: array<atomic<u32>>".to_string(),
             },
+            debug_buffer: BufferBinding {
+                host: (),
+                serialise: Box::new(|_| vec![]),
+                device: wgpu.device.create_buffer(&wgpu::BufferDescriptor {
+                    label: None,
+                    size: (DEBUG_HEADER_BYTES + MAX_DEBUG_RECORDS * DEBUG_RECORD_BYTES) as u64,
+                    usage: wgpu::BufferUsages::STORAGE
+                        | wgpu::BufferUsages::COPY_SRC
+                        | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                }),
+                layout: storage_buffer,
+                bind: Box::new(wgpu::Buffer::as_entire_buffer_binding),
+                decl: "var<storage,read_write> _debug: DebugLog".to_string(),
+            },
             dispatch_info: BufferBinding {
                 host: (),
                 serialise: Box::new(|_| vec![]),
                 device: wgpu.device.create_buffer(&wgpu::BufferDescriptor {
                     label: None,
-                    size: 256 * OFFSET_ALIGNMENT as u64,
+                    size: MAX_DISPATCH_COUNT as u64 * OFFSET_ALIGNMENT as u64,
                     usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
                     mapped_at_creation: false,
                 }),
@@ -496,6 +596,12 @@ impl Bindings {
                     decl: "var font_atlas: texture_2d<f32>".to_string(),
                 }
             },
+            background: TextureBinding {
+                view: background.create_view(&Default::default()),
+                device: background,
+                layout: channel_layout,
+                decl: "var codeskew_background: texture_2d<f32>".to_string(),
+            },
 
             nearest: SamplerBinding {
                 layout: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::NonFiltering),
@@ -545,7 +651,17 @@ impl Bindings {
                 }),
                 decl: "var trilinear_repeat: sampler".to_string(),
             },
+        };
+
+        // `dispatch.id` is read at a dynamic offset, so every slot holds its own index
+        let mut ids = vec![0u8; MAX_DISPATCH_COUNT as usize * OFFSET_ALIGNMENT];
+        for (id, slot) in ids.chunks_exact_mut(OFFSET_ALIGNMENT).enumerate() {
+            slot[..4].copy_from_slice(&(id as u32).to_le_bytes());
         }
+        wgpu.queue
+            .write_buffer(&bindings.dispatch_info.device, 0, &ids);
+
+        bindings
     }
 
     fn to_vec(&self) -> Vec<&dyn Binding> {
@@ -557,6 +673,8 @@ impl Bindings {
             &self.keys,
             &self.custom,
             &self.user_data,
+            &self.camera,
+            &self.assert_counts,
             &self.debug_buffer,
             &self.dispatch_info,
             &self.tex_screen,
@@ -565,6 +683,7 @@ impl Bindings {
             &self.channels[0],
             &self.channels[1],
             &self.font_atlas,
+            &self.background,
             &self.nearest,
             &self.bilinear,
             &self.trilinear,
@@ -670,5 +789,6 @@ impl Bindings {
         self.time.stage(queue);
         self.mouse.stage(queue);
         self.keys.stage(queue);
+        self.camera.stage(queue);
     }
 }
@@ -0,0 +1,222 @@
+use super::bind::Camera;
+use super::context::WgpuContext;
+
+/// Same format as `tex_screen`, so frames read back the same way
+const OUTPUT_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+const WORKGROUP_SIZE: u32 = 16;
+
+/// Depth-of-field settings for the blur pass
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DepthOfField {
+    /// `--blur`: circle of confusion radius in pixels at full defocus
+    pub radius: f32,
+    /// `--focus-depth`: depth kept sharp, as measured by the prelude's `codeskewCameraDepth`.
+    /// `None` focuses on the middle of `focus_band`, or the fold point without one.
+    pub focus_depth: Option<f32>,
+    /// Text uv rows `[top, bottom]` kept sharp whatever their depth, from `--focus-line`
+    pub focus_band: Option<[f32; 2]>,
+}
+
+#[derive(Clone, Copy, bytemuck::Zeroable, bytemuck::Pod)]
+#[repr(C)]
+struct BlurUniforms {
+    radius: f32,
+    focus_depth: f32,
+    focus_band: [f32; 2],
+}
+
+impl From<DepthOfField> for BlurUniforms {
+    fn from(dof: DepthOfField) -> Self {
+        BlurUniforms {
+            radius: dof.radius,
+            focus_depth: dof.focus_depth.unwrap_or(0.0),
+            focus_band: dof.focus_band.unwrap_or([1.0, 0.0]),
+        }
+    }
+}
+
+/// Post-process pass blurring the finished frame by depth, like a tilt-shift photo
+///
+/// Two compute passes run a separable Gaussian, horizontally into `intermediate` and
+/// vertically into `output`, which takes the place of the screen for readback and
+/// presentation.
+pub struct BlurRenderer {
+    settings: DepthOfField,
+    camera: wgpu::Buffer,
+    uniforms: wgpu::Buffer,
+    horizontal: (wgpu::ComputePipeline, wgpu::BindGroup),
+    vertical: (wgpu::ComputePipeline, wgpu::BindGroup),
+    output: wgpu::Texture,
+    output_view: wgpu::TextureView,
+    width: u32,
+    height: u32,
+}
+
+impl BlurRenderer {
+    pub fn new(wgpu: &WgpuContext, settings: DepthOfField, src: &wgpu::TextureView, width: u32, height: u32) -> Self {
+        let device = &wgpu.device;
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("blur"),
+            source: wgpu::ShaderSource::Wgsl(
+                format!("{}{}", include_str!("blur.wgsl"), include_str!("camera.wgsl")).into(),
+            ),
+        });
+
+        let texture = |label| {
+            device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: OUTPUT_FORMAT,
+                usage: wgpu::TextureUsages::STORAGE_BINDING
+                    | wgpu::TextureUsages::TEXTURE_BINDING
+                    | wgpu::TextureUsages::COPY_SRC,
+                view_formats: &[],
+            })
+        };
+        let intermediate = texture("blur_intermediate").create_view(&Default::default());
+        let output = texture("blur_output");
+        let output_view = output.create_view(&Default::default());
+
+        let uniform_buffer = |label, size| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some(label),
+                size,
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
+                mapped_at_creation: false,
+            })
+        };
+        let camera = uniform_buffer("blur_camera", size_of::<Camera>() as u64);
+        let uniforms = uniform_buffer("blur_uniforms", size_of::<BlurUniforms>() as u64);
+
+        let uniform_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("blur"),
+            entries: &[
+                uniform_entry(0),
+                uniform_entry(1),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::StorageTexture {
+                        access: wgpu::StorageTextureAccess::WriteOnly,
+                        format: OUTPUT_FORMAT,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("blur"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let pass = |entry_point, src: &wgpu::TextureView, dst: &wgpu::TextureView| {
+            let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(&pipeline_layout),
+                module: &shader,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            });
+            let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some(entry_point),
+                layout: &layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: camera.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: uniforms.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::TextureView(src),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: wgpu::BindingResource::TextureView(dst),
+                    },
+                ],
+            });
+            (pipeline, bind_group)
+        };
+        let horizontal = pass("blur_x", src, &intermediate);
+        let vertical = pass("blur_y", &intermediate, &output_view);
+
+        BlurRenderer {
+            settings,
+            camera,
+            uniforms,
+            horizontal,
+            vertical,
+            output,
+            output_view,
+            width,
+            height,
+        }
+    }
+
+    pub fn settings(&self) -> DepthOfField {
+        self.settings
+    }
+
+    /// The blurred frame, in the screen's format
+    pub fn output(&self) -> &wgpu::Texture {
+        &self.output
+    }
+
+    pub fn output_view(&self) -> &wgpu::TextureView {
+        &self.output_view
+    }
+
+    /// Stage the camera and settings, then record both blur passes into `output`
+    pub fn encode(&self, queue: &wgpu::Queue, encoder: &mut wgpu::CommandEncoder, camera: &Camera) {
+        queue.write_buffer(&self.camera, 0, bytemuck::bytes_of(camera));
+        queue.write_buffer(&self.uniforms, 0, bytemuck::bytes_of(&BlurUniforms::from(self.settings)));
+
+        for (pipeline, bind_group) in [&self.horizontal, &self.vertical] {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("blur"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, bind_group, &[]);
+            pass.dispatch_workgroups(
+                self.width.div_ceil(WORKGROUP_SIZE),
+                self.height.div_ceil(WORKGROUP_SIZE),
+                1,
+            );
+        }
+    }
+}
@@ -0,0 +1,96 @@
+// Separable Gaussian depth-of-field blur of the finished frame
+//
+// Each pixel is blurred by a circle of confusion that grows with its distance from the
+// focus depth, measured with `codeskewCameraDepth` like the text warp, except in the
+// focus band of text rows. `blur_x` and `blur_y` run one after the other; both work out
+// the circle of confusion at their own pixel. `CodeskewCamera` and the camera helpers
+// come from camera.wgsl.
+
+struct Blur {
+    // Circle of confusion radius in pixels at full defocus
+    radius: f32,
+    // Depth kept sharp, 0 for the depth at the focus band or fold point
+    focus_depth: f32,
+    // Text uv rows kept sharp, top > bottom when there is no focus band
+    focus_band: vec2<f32>,
+}
+
+@group(0) @binding(0) var<uniform> codeskew_camera: CodeskewCamera;
+@group(0) @binding(1) var<uniform> blur: Blur;
+@group(0) @binding(2) var src: texture_2d<f32>;
+@group(0) @binding(3) var dst: texture_storage_2d<rgba16float, write>;
+
+// Largest kernel radius, to bound the cost of huge --blur values
+const MAX_RADIUS = 64;
+
+// codeskewCameraDepth at wherever the camera sees a sheet uv
+fn sheetDepth(sheet: vec2<f32>) -> f32 {
+    // Back through the --skew lean to the plane the camera looks at, see `codeskewSheetUv`
+    let row = (sheet.y - codeskew_camera.fold_point) * codeskew_camera.height;
+    let depth = 1.0 - row * sin(codeskew_camera.skew) / codeskew_camera.perspective;
+    let plane = vec2<f32>(
+        (sheet.x - 0.5) / depth + 0.5,
+        codeskew_camera.fold_point + row * cos(codeskew_camera.skew) / depth / codeskew_camera.height,
+    );
+    // Last row of the inverse homography gives the projective depth without inverting it
+    let m = codeskew_camera.text_from_screen;
+    let z = determinant(m) / dot(cross(m[0], m[1]), vec3<f32>(plane, 1.0));
+    let center = m * vec3<f32>(0.5, 0.5, 1.0);
+    let warp = codeskewWarpScale(sheet);
+    return center.z / z * depth / (warp.x * warp.y);
+}
+
+// Blur radius in pixels at a screen pixel
+fn circleOfConfusion(pos: vec2<u32>, size: vec2<u32>) -> f32 {
+    let uv = (vec2<f32>(pos) + 0.5) / vec2<f32>(size);
+    var focus = blur.focus_depth;
+    var band = 1.0;
+    let has_band = blur.focus_band.x <= blur.focus_band.y;
+    if (has_band) {
+        // Sharp inside the band, fading to full blur over three times its height. The
+        // band is in text texture rows, as warped by the perspective flags
+        let text_y = codeskewTextUv(uv).y;
+        let height = blur.focus_band.y - blur.focus_band.x;
+        let outside = max(blur.focus_band.x - text_y, text_y - blur.focus_band.y);
+        band = smoothstep(0.0, height * 3.0, outside);
+    }
+    if (focus <= 0.0) {
+        var focus_y = codeskew_camera.fold_point;
+        if (has_band) {
+            focus_y = (blur.focus_band.x + blur.focus_band.y) * 0.5;
+        }
+        focus = sheetDepth(vec2<f32>(0.5, focus_y));
+    }
+    // Full blur half the focus distance nearer or farther
+    let defocus = min(abs(codeskewCameraDepth(uv) - focus) / focus * 2.0, 1.0);
+    return blur.radius * defocus * band;
+}
+
+fn gaussian(pos: vec2<u32>, direction: vec2<i32>) {
+    let size = textureDimensions(src);
+    if (pos.x >= size.x || pos.y >= size.y) {
+        return;
+    }
+    let radius = circleOfConfusion(pos, size);
+    let taps = min(i32(ceil(radius)), MAX_RADIUS);
+    let sigma = max(radius * 0.5, 0.001);
+    var sum = vec4<f32>(0.0);
+    var weights = 0.0;
+    for (var i = -taps; i <= taps; i++) {
+        let p = clamp(vec2<i32>(pos) + direction * i, vec2<i32>(0), vec2<i32>(size) - 1);
+        let w = exp(-f32(i * i) / (2.0 * sigma * sigma));
+        sum += textureLoad(src, p, 0) * w;
+        weights += w;
+    }
+    textureStore(dst, pos, sum / weights);
+}
+
+@compute @workgroup_size(16, 16)
+fn blur_x(@builtin(global_invocation_id) id: vec3<u32>) {
+    gaussian(id.xy, vec2<i32>(1, 0));
+}
+
+@compute @workgroup_size(16, 16)
+fn blur_y(@builtin(global_invocation_id) id: vec3<u32>) {
+    gaussian(id.xy, vec2<i32>(0, 1));
+}
@@ -0,0 +1,70 @@
+
+// The perspective flags, as bound to `codeskew_camera` by the prelude and the blur pass
+struct CodeskewCamera {
+    skew: f32, depth: f32, perspective: f32, fold: f32, fold_point: f32, skew_angle: f32, scale: f32, height: f32,
+    view_proj: mat4x4<f32>, text_from_screen: mat3x3<f32>,
+}
+
+// Text uv the camera sees at a screen uv, (-1, -1) where it sees no text plane
+fn codeskewCameraUv(uv: vec2<f32>) -> vec2<f32> {
+    let p = codeskew_camera.text_from_screen * vec3<f32>(uv, 1.0);
+    if (p.z <= 0.0) {
+        return vec2<f32>(-1.0);
+    }
+    return p.xy / p.z;
+}
+
+// The text sheet the camera sees at a screen uv, leaning back by --skew about the fold row
+// as seen from --perspective pixels away. xy is the sheet uv and z its distance relative to
+// the text at the center of the screen, 0 where there is no sheet.
+fn codeskewSheetUv(uv: vec2<f32>) -> vec3<f32> {
+    let p = codeskew_camera.text_from_screen * vec3<f32>(uv, 1.0);
+    let distance = codeskew_camera.perspective;
+    let row = (p.y / p.z - codeskew_camera.fold_point) * codeskew_camera.height;
+    let lean = distance * cos(codeskew_camera.skew) + row * sin(codeskew_camera.skew);
+    // Behind the camera or beyond the horizon
+    if (p.z <= 0.0 || lean <= 0.0) {
+        return vec3<f32>(-1.0, -1.0, 0.0);
+    }
+    let center = codeskew_camera.text_from_screen * vec3<f32>(0.5, 0.5, 1.0);
+    let depth = distance * cos(codeskew_camera.skew) / lean;
+    return vec3<f32>(
+        (p.x / p.z - 0.5) * depth + 0.5,
+        codeskew_camera.fold_point + row * distance / lean / codeskew_camera.height,
+        center.z / p.z * depth,
+    );
+}
+
+// Horizontal and vertical magnification of the --scale and --fold warp at a sheet uv
+fn codeskewWarpScale(uv: vec2<f32>) -> vec2<f32> {
+    var vertical = 1.0;
+    if (uv.y < codeskew_camera.fold_point) {
+        vertical += codeskew_camera.fold * uv.y / codeskew_camera.fold_point;
+    } else {
+        vertical += codeskew_camera.fold * (1.0 - (uv.y - codeskew_camera.fold_point) / (1.0 - codeskew_camera.fold_point));
+    }
+    return vec2<f32>(1.0 + uv.x * codeskew_camera.scale, vertical);
+}
+
+// Text texture uv drawn at a screen uv: the sheet after the --scale and --fold warp, with
+// the top leaning right by --skew-angle. Outside 0..1 where there is no text.
+fn codeskewTextUv(uv: vec2<f32>) -> vec2<f32> {
+    let sheet = codeskewSheetUv(uv);
+    if (sheet.z <= 0.0) {
+        return vec2<f32>(-1.0);
+    }
+    var text = (sheet.xy - 0.5) / codeskewWarpScale(sheet.xy) + 0.5;
+    text.x -= (1.0 - text.y) * codeskew_camera.skew_angle;
+    return text;
+}
+
+// Distance of the text seen at a screen uv, relative to the text at the center of the
+// screen without the warp: magnified text counts as nearer. Infinite off the text sheet.
+fn codeskewCameraDepth(uv: vec2<f32>) -> f32 {
+    let sheet = codeskewSheetUv(uv);
+    if (sheet.z <= 0.0) {
+        return 1e30;
+    }
+    let warp = codeskewWarpScale(sheet.xy);
+    return sheet.z / (warp.x * warp.y);
+}
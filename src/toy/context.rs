@@ -1,4 +1,5 @@
 #![allow(deprecated)]
+use crate::cli::GpuBackend;
 use std::sync::Arc;
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -10,17 +11,31 @@ use raw_window_handle::{
     WebDisplayHandle, WebWindowHandle, WindowHandle,
 };
 
+/// GPU context shared by the toy renderer and the glyphon text pipeline.
+///
+/// Window-backed contexts (from [`init_wgpu`]) carry an event loop, a window and a
+/// presentable surface. Headless contexts (from [`init_wgpu_headless`]) have none of
+/// these and only ever render into offscreen textures; `surface_config` still
+/// describes the target size and colour format in that case.
 pub struct WgpuContext {
     #[cfg(all(not(target_arch = "wasm32"), feature = "winit"))]
     pub event_loop: Option<winit::event_loop::EventLoop<()>>,
     #[cfg(all(not(target_arch = "wasm32"), feature = "winit"))]
-    pub window: Arc<winit::window::Window>,
+    pub window: Option<Arc<winit::window::Window>>,
     pub device: Arc<wgpu::Device>,
     pub queue: wgpu::Queue,
-    pub surface: wgpu::Surface<'static>,
+    pub surface: Option<wgpu::Surface<'static>>,
     pub surface_config: wgpu::SurfaceConfiguration,
 }
 
+impl WgpuContext {
+    /// Whether this context renders offscreen only (no window, no surface)
+    #[inline]
+    pub fn is_headless(&self) -> bool {
+        self.surface.is_none()
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 struct CanvasWindow {
     id: u32,
@@ -89,7 +104,12 @@ fn init_window(
 
 #[cfg(feature = "winit")]
 #[allow(deprecated)]
-pub async fn init_wgpu(width: u32, height: u32, _bind_id: &str) -> Result<WgpuContext, String> {
+pub async fn init_wgpu(
+    width: u32,
+    height: u32,
+    _bind_id: &str,
+    backend: GpuBackend,
+) -> Result<WgpuContext, String> {
     #[cfg(not(target_arch = "wasm32"))]
     let event_loop = winit::event_loop::EventLoop::new().map_err(|e| e.to_string())?;
     #[cfg(not(target_arch = "wasm32"))]
@@ -106,37 +126,19 @@ pub async fn init_wgpu(width: u32, height: u32, _bind_id: &str) -> Result<WgpuCo
     #[cfg(target_arch = "wasm32")]
     let window = init_window(bind_id).map_err(|e| e.to_string())?;
 
-    let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor {
-        backends: wgpu::Backends::PRIMARY,
-        flags: wgpu::InstanceFlags::default(),
-        backend_options: Default::default(),
-    });
+    let instance = create_instance(backend);
 
     let surface = unsafe {
         instance.create_surface_unsafe(wgpu::SurfaceTargetUnsafe::from_window(&window).unwrap())
     }
     .map_err(|e| e.to_string())?;
 
-    let adapter = instance
-        .request_adapter(&wgpu::RequestAdapterOptions {
-            power_preference: wgpu::PowerPreference::HighPerformance,
-            force_fallback_adapter: false,
-            compatible_surface: Some(&surface),
-        })
-        .await
-        .map_err(|e| format!("unable to create adapter: {e:?}"))?;
+    let adapter = request_adapter(&instance, backend, Some(&surface)).await?;
 
     log::info!("adapter.features = {:#?}", adapter.features());
     log::info!("adapter.limits = {:#?}", adapter.limits());
 
-    let (device, queue) = adapter
-        .request_device(&wgpu::DeviceDescriptor {
-            label: Some("GPU Device"),
-            required_features: adapter.features(),
-            ..Default::default()
-        })
-        .await
-        .map_err(|e| e.to_string())?;
+    let (device, queue) = request_device(&adapter, "GPU Device").await?;
 
     let surface_format = preferred_framebuffer_format(&surface.get_capabilities(&adapter).formats);
     let surface_config = wgpu::SurfaceConfiguration {
@@ -158,14 +160,155 @@ pub async fn init_wgpu(width: u32, height: u32, _bind_id: &str) -> Result<WgpuCo
         #[cfg(all(not(target_arch = "wasm32"), feature = "winit"))]
         event_loop: Some(event_loop),
         #[cfg(all(not(target_arch = "wasm32"), feature = "winit"))]
-        window,
+        window: Some(window),
+        device: Arc::new(device),
+        queue,
+        surface: Some(surface),
+        surface_config,
+    })
+}
+
+/// Create a GPU context that never opens a window.
+///
+/// The adapter is requested without a `compatible_surface`, so this works on machines
+/// with no display server (CI, build servers). All rendering goes to offscreen textures
+/// and is read back with `WgpuToyRenderer::render_to_buffer`.
+pub async fn init_wgpu_headless(
+    width: u32,
+    height: u32,
+    backend: GpuBackend,
+) -> Result<WgpuContext, String> {
+    let instance = create_instance(backend);
+    let adapter = request_adapter(&instance, backend, None).await?;
+    let (device, queue) = request_device(&adapter, "Headless GPU Device").await?;
+
+    // No surface to negotiate with: pick the format the text texture and readback path expect
+    let format = wgpu::TextureFormat::Rgba8UnormSrgb;
+    let surface_config = wgpu::SurfaceConfiguration {
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        format,
+        width,
+        height,
+        present_mode: wgpu::PresentMode::Fifo,
+        alpha_mode: wgpu::CompositeAlphaMode::PostMultiplied,
+        view_formats: vec![format.add_srgb_suffix(), format.remove_srgb_suffix()],
+        desired_maximum_frame_latency: 1,
+    };
+
+    Ok(WgpuContext {
+        #[cfg(all(not(target_arch = "wasm32"), feature = "winit"))]
+        event_loop: None,
+        #[cfg(all(not(target_arch = "wasm32"), feature = "winit"))]
+        window: None,
         device: Arc::new(device),
         queue,
-        surface,
+        surface: None,
         surface_config,
     })
 }
 
+/// Create a wgpu instance restricted to the backends allowed by `backend`
+pub fn create_instance(backend: GpuBackend) -> wgpu::Instance {
+    let backends = match backend {
+        GpuBackend::Auto => wgpu::Backends::PRIMARY | wgpu::Backends::GL,
+        GpuBackend::Vulkan => wgpu::Backends::VULKAN,
+        GpuBackend::Gl => wgpu::Backends::GL,
+        // lavapipe is Vulkan, llvmpipe is GL, WARP is DX12: let the device type decide
+        GpuBackend::Cpu => wgpu::Backends::all(),
+    };
+    wgpu::Instance::new(&wgpu::InstanceDescriptor {
+        backends,
+        flags: wgpu::InstanceFlags::default(),
+        backend_options: Default::default(),
+    })
+}
+
+/// Pick an adapter for `backend`, falling back to a software rasterizer when allowed.
+///
+/// `Auto` prefers a hardware adapter and only drops to a CPU adapter when none is
+/// found; `Cpu` always selects a software adapter. The chosen adapter is logged.
+pub async fn request_adapter(
+    instance: &wgpu::Instance,
+    backend: GpuBackend,
+    compatible_surface: Option<&wgpu::Surface<'_>>,
+) -> Result<wgpu::Adapter, String> {
+    let adapter = match backend {
+        GpuBackend::Cpu => request_software_adapter(instance, compatible_surface).await?,
+        _ => match instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                force_fallback_adapter: false,
+                compatible_surface,
+            })
+            .await
+        {
+            Ok(adapter) => adapter,
+            Err(e) if backend == GpuBackend::Auto => {
+                log::warn!("no hardware adapter found ({e}), falling back to a software adapter");
+                request_software_adapter(instance, compatible_surface).await?
+            }
+            Err(e) => return Err(format!("unable to create {backend} adapter: {e:?}")),
+        },
+    };
+
+    let info = adapter.get_info();
+    log::info!(
+        "using adapter \"{}\" ({:?}, {:?} backend, driver: {} {})",
+        info.name,
+        info.device_type,
+        info.backend,
+        info.driver,
+        info.driver_info
+    );
+
+    Ok(adapter)
+}
+
+async fn request_software_adapter(
+    instance: &wgpu::Instance,
+    compatible_surface: Option<&wgpu::Surface<'_>>,
+) -> Result<wgpu::Adapter, String> {
+    if let Ok(adapter) = instance
+        .request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::LowPower,
+            force_fallback_adapter: true,
+            compatible_surface,
+        })
+        .await
+    {
+        return Ok(adapter);
+    }
+
+    // lavapipe/llvmpipe report a CPU device type but are not always flagged as fallback adapters
+    instance
+        .enumerate_adapters(wgpu::Backends::all())
+        .into_iter()
+        .filter(|adapter| compatible_surface.is_none_or(|s| adapter.is_surface_supported(s)))
+        .find(|adapter| adapter.get_info().device_type == wgpu::DeviceType::Cpu)
+        .ok_or_else(|| {
+            "unable to find a software adapter (install Mesa lavapipe or llvmpipe)".to_string()
+        })
+}
+
+/// Request a device with everything the adapter supports.
+///
+/// Using the adapter's own limits keeps software and downlevel adapters working, where
+/// `Limits::default()` would ask for more than they offer.
+async fn request_device(
+    adapter: &wgpu::Adapter,
+    label: &str,
+) -> Result<(wgpu::Device, wgpu::Queue), String> {
+    adapter
+        .request_device(&wgpu::DeviceDescriptor {
+            label: Some(label),
+            required_features: adapter.features(),
+            required_limits: adapter.limits(),
+            ..Default::default()
+        })
+        .await
+        .map_err(|e| e.to_string())
+}
+
 fn preferred_framebuffer_format(formats: &[wgpu::TextureFormat]) -> wgpu::TextureFormat {
     // Prioritize Rgba8UnormSrgb for compatibility with render targets
     for &format in formats {
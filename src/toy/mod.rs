@@ -1,25 +1,57 @@
 pub mod bind;
 pub mod blit;
+pub mod blur;
 pub mod context;
+pub mod keys;
 pub mod pp;
+pub mod surface;
 pub mod utils;
 
 pub use bind::*;
 pub use blit::*;
-pub use context::{WgpuContext, init_wgpu};
+pub use blur::{BlurRenderer, DepthOfField};
+pub use context::{WgpuContext, init_wgpu, init_wgpu_headless};
 pub use pp::{SourceMap, WGSLError};
+pub use surface::SurfaceRenderer;
 pub use utils::*;
 
+use crate::error::CodeSkewError;
+use crate::shader_library::ShaderLibrary;
 use lazy_regex::regex;
 use wasm_bindgen::prelude::*;
 
 struct ComputePipeline {
-    _name: String,
+    name: String,
     pipeline: wgpu::ComputePipeline,
     workgroup_size: [u32; 3],
     workgroup_count: Option<[u32; 3]>,
-    _dispatch_once: bool,
-    _dispatch_count: u32,
+    dispatch_once: bool,
+    dispatch_count: u32,
+}
+
+/// Invocations that failed one `assert` during the frames read back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssertFailure {
+    /// Counter index passed to `assert(index, ...)`
+    pub index: usize,
+    /// Source line of the `#assert` directive, if the assert came from one
+    pub line: Option<usize>,
+    pub invocations: u64,
+}
+
+/// One `debugLog(id, value)` call made by a shader
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct DebugRecord {
+    pub frame: u32,
+    pub id: u32,
+    pub value: [f32; 4],
+}
+
+/// `debugLog` records from the frames read back, plus how many overflowed the buffer
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct ShaderDebugLog {
+    pub records: Vec<DebugRecord>,
+    pub dropped: u64,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -47,7 +79,13 @@ pub struct WgpuToyRenderer {
     _screen_blitter: blit::Blitter,
     _query_set: Option<wgpu::QuerySet>,
     _last_stats: instant::Instant,
+    assert_totals: [u64; NUM_ASSERT_COUNTERS],
+    shader_debug: bool,
+    debug_log: ShaderDebugLog,
     source: SourceMap,
+    library: ShaderLibrary,
+    surface: Option<SurfaceRenderer>,
+    blur: Option<BlurRenderer>,
 }
 
 impl WgpuToyRenderer {
@@ -81,16 +119,96 @@ impl WgpuToyRenderer {
             _pass_f32: false,
             _query_set: None,
             _last_stats: instant::Instant::now(),
+            assert_totals: [0; NUM_ASSERT_COUNTERS],
+            shader_debug: false,
+            debug_log: ShaderDebugLog::default(),
             source: SourceMap::new(),
+            library: ShaderLibrary::default(),
+            surface: None,
+            blur: None,
         }
     }
 
-    /// Compile shader source into compute pipeline - the missing piece!
-    pub fn compile(&mut self, source: pp::SourceMap) {
+    /// Where `#include`s are looked up; only the embedded library is searched by default
+    pub fn set_shader_library(&mut self, library: ShaderLibrary) {
+        self.library = library;
+    }
+
+    /// Draw `text` on 3D geometry over the shader's screen, through the `codeskew_camera` matrix
+    ///
+    /// Frames read back or presented are then the surface pass output. The text should
+    /// not also be in a channel, or the shader draws it a second time.
+    pub fn set_surface(&mut self, surface: crate::cli::Surface, text: wgpu::Texture) {
+        self.surface = Some(SurfaceRenderer::new(
+            &self.wgpu,
+            surface,
+            text,
+            self.bindings.tex_screen.view(),
+            self.screen_width,
+            self.screen_height,
+        ));
+        // The blur pass reads the old frame
+        if let Some(blur) = &self.blur {
+            self.set_depth_of_field(Some(blur.settings()));
+        }
+    }
+
+    /// Blur the finished frame by depth, `None` to turn the blur pass off
+    ///
+    /// Runs after the compute passes and the surface pass, so set it after `set_surface`.
+    pub fn set_depth_of_field(&mut self, settings: Option<DepthOfField>) {
+        self.blur = settings.map(|settings| {
+            BlurRenderer::new(
+                &self.wgpu,
+                settings,
+                &self.frame_view(false),
+                self.screen_width,
+                self.screen_height,
+            )
+        });
+    }
+
+    /// Record the surface and blur passes that are set, after the compute passes
+    fn encode_post_passes(&self, encoder: &mut wgpu::CommandEncoder) {
+        let camera = &self.bindings.camera.host;
+        if let Some(surface) = &self.surface {
+            surface.encode(&self.wgpu.queue, encoder, camera.view_proj, self.bindings.time.host.elapsed);
+        }
+        if let Some(blur) = &self.blur {
+            blur.encode(&self.wgpu.queue, encoder, camera);
+        }
+    }
+
+    /// The texture holding the finished frame
+    fn output_texture(&self) -> &wgpu::Texture {
+        if let Some(blur) = &self.blur {
+            return blur.output();
+        }
+        self.surface
+            .as_ref()
+            .map_or(self.bindings.tex_screen.texture(), SurfaceRenderer::output)
+    }
+
+    /// View of the frame after every pass, or before the blur pass when `blurred` is false
+    fn frame_view(&self, blurred: bool) -> wgpu::TextureView {
+        match (&self.blur, &self.surface) {
+            (Some(blur), _) if blurred => blur.output_view().clone(),
+            (_, Some(surface)) => surface.output_view().clone(),
+            _ => self.bindings.tex_screen.view().clone(),
+        }
+    }
+
+    /// Compile shader source into compute pipelines
+    ///
+    /// Validation errors are traced back through the prelude and `source.map` to the
+    /// user's file and returned as `CodeSkewError::ShaderError`. The previously
+    /// compiled pipelines are kept when compilation fails.
+    pub async fn compile(&mut self, source: pp::SourceMap) -> Result<(), CodeSkewError> {
         let now = instant::Instant::now();
 
         // Generate prelude with binding declarations
         let prelude = self.prelude();
+        let prelude_lines = prelude.lines().count();
         let wgsl = format!("{}{}", prelude, source.source);
 
         // Parse entry points from the complete WGSL
@@ -104,14 +222,26 @@ impl WgpuToyRenderer {
             })
             .collect();
 
+        // Capture validation errors instead of letting the uncaptured handler panic
+        self.wgpu.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let compute_shader = self.wgpu.device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Compute Shader"),
+            source: wgpu::ShaderSource::Wgsl(wgsl.into()),
+        });
+
+        let compilation_info = compute_shader.get_compilation_info().await;
+        if let Some(message) = compilation_info
+            .messages
+            .iter()
+            .find(|m| m.message_type == wgpu::CompilationMessageType::Error)
+        {
+            let _ = self.wgpu.device.pop_error_scope().await;
+            return Err(shader_error(&source, prelude_lines, message));
+        }
+
         // Create compute pipelines from entry points
-        self.compute_pipelines.clear();
+        let mut compute_pipelines = Vec::with_capacity(entry_points.len());
         for (entry_point, workgroup_size) in entry_points {
-            let compute_shader = self.wgpu.device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                label: Some(&format!("Compute Shader - {}", entry_point)),
-                source: wgpu::ShaderSource::Wgsl(wgsl.clone().into()),
-            });
-
             let compute_pipeline = self.wgpu.device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
                 label: Some(&format!("Compute Pipeline - {}", entry_point)),
                 layout: Some(&self.compute_pipeline_layout),
@@ -121,21 +251,34 @@ impl WgpuToyRenderer {
                 cache: None,
             });
 
-            self.compute_pipelines.push(ComputePipeline {
-                _name: entry_point.clone(),
+            compute_pipelines.push(ComputePipeline {
+                name: entry_point.clone(),
                 pipeline: compute_pipeline,
                 workgroup_size,
                 workgroup_count: source.workgroup_count.get(&entry_point).cloned(),
-                _dispatch_once: *source.dispatch_once.get(&entry_point).unwrap_or(&false),
-                _dispatch_count: *source.dispatch_count.get(&entry_point).unwrap_or(&1),
+                dispatch_once: *source.dispatch_once.get(&entry_point).unwrap_or(&false),
+                dispatch_count: *source.dispatch_count.get(&entry_point).unwrap_or(&1),
             });
         }
 
+        // Pipeline errors (e.g. binding mismatches) carry no source location
+        if let Some(error) = self.wgpu.device.pop_error_scope().await {
+            return Err(CodeSkewError::ShaderError {
+                file: source.file.clone(),
+                line: 0,
+                col: 0,
+                message: error.to_string(),
+                snippet: String::new(),
+            });
+        }
+
+        self.compute_pipelines = compute_pipelines;
         println!("🔧 DEBUG: Compiled {} compute pipelines in {:.3}s",
                  self.compute_pipelines.len(),
                  now.elapsed().as_secs_f32());
 
         self.source = source;
+        Ok(())
     }
 
     /// Generate prelude with all binding declarations
@@ -162,7 +305,12 @@ impl WgpuToyRenderer {
 struct Time { frame: uint, elapsed: float, delta: float }
 struct Mouse { pos: uint2, click: int }
 struct DispatchInfo { id: uint }
+struct DebugRecord { id: uint, value: float4 }
 "#);
+        s.push_str(&format!(
+            "const MAX_DEBUG_RECORDS = {MAX_DEBUG_RECORDS}u;\n\
+             struct DebugLog {{ len: atomic<u32>, records: array<DebugRecord, MAX_DEBUG_RECORDS> }}\n"
+        ));
 
         // Custom struct (dynamic generation)
         s.push_str("struct Custom {\n");
@@ -183,7 +331,10 @@ struct DispatchInfo { id: uint }
         // All binding declarations
         s.push_str(&self.bindings.to_wgsl());
 
-        // Helper functions
+        // Helper functions, the camera ones shared with the blur pass and the text one with
+        // the surface pass
+        s.push_str(include_str!("camera.wgsl"));
+        s.push_str(include_str!("text.wgsl"));
         s.push_str(r#"
 fn keyDown(keycode: uint) -> bool {
     return ((_keyboard[keycode / 128u][(keycode % 128u) / 32u] >> (keycode % 32u)) & 1u) == 1u;
@@ -195,6 +346,13 @@ fn assert(index: int, success: bool) {
     }
 }
 
+fn debugLog(id: uint, value: float4) {
+    let index = atomicAdd(&_debug.len, 1u);
+    if (index < MAX_DEBUG_RECORDS) {
+        _debug.records[index] = DebugRecord(id, value);
+    }
+}
+
 fn passStore(pass_index: int, coord: int2, value: float4) {
     textureStore(pass_out, coord, pass_index, value);
 }
@@ -211,12 +369,64 @@ fn passSampleLevelBilinearRepeat(pass_index: int, uv: float2, lod: float) -> flo
         s
     }
 
+    /// Record every compute pass for the current frame - wgpu-compute-toy semantics
+    ///
+    /// `#dispatch_once` entry points only run on frame 0, and `#dispatch_count N`
+    /// runs an entry point N times in sequence with `dispatch.id` set to 0..N.
+    fn encode_compute_passes(&self, encoder: &mut wgpu::CommandEncoder) {
+        let frame = self.bindings.time.host.frame;
+        for pipeline in &self.compute_pipelines {
+            if pipeline.dispatch_once && frame > 0 {
+                continue;
+            }
+
+            let workgroup_count = pipeline.workgroup_count.unwrap_or([
+                self.screen_width.div_ceil(pipeline.workgroup_size[0]),
+                self.screen_height.div_ceil(pipeline.workgroup_size[1]),
+                1,
+            ]);
+
+            for dispatch_id in 0..pipeline.dispatch_count {
+                // Separate passes so each dispatch sees the previous one's writes
+                let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: Some(&pipeline.name),
+                    timestamp_writes: None,
+                });
+                compute_pass.set_pipeline(&pipeline.pipeline);
+                compute_pass.set_bind_group(
+                    0,
+                    &self.compute_bind_group,
+                    &[dispatch_id * bind::OFFSET_ALIGNMENT as u32],
+                );
+                compute_pass.dispatch_workgroups(
+                    workgroup_count[0],
+                    workgroup_count[1],
+                    workgroup_count[2],
+                );
+                drop(compute_pass);
+
+                // What a pass stores in `pass_out` is what later passes and frames read from `pass_in`
+                let pass_out = self.bindings.tex_write.texture();
+                encoder.copy_texture_to_texture(
+                    pass_out.as_image_copy(),
+                    self.bindings.tex_read.texture().as_image_copy(),
+                    pass_out.size(),
+                );
+            }
+        }
+    }
+
     pub async fn preprocess_async(&mut self, shader: &str) -> Option<SourceMap> {
+        self.try_preprocess_async(shader).await.map_err(|e| e.submit()).ok()
+    }
+
+    /// Preprocess `shader`, returning the directive or include error if there is one
+    pub async fn try_preprocess_async(&mut self, shader: &str) -> Result<SourceMap, WGSLError> {
         let defines = rustc_hash::FxHashMap::from_iter([
             ("SCREEN_WIDTH".to_owned(), self.screen_width.to_string()),
             ("SCREEN_HEIGHT".to_owned(), self.screen_height.to_string()),
         ]);
-        pp::Preprocessor::new(defines).run(shader).await
+        pp::Preprocessor::new(defines, self.library.clone()).try_run(shader).await
     }
 }
 
@@ -224,8 +434,54 @@ fn passSampleLevelBilinearRepeat(pass_index: int, uv: float2, lod: float) -> flo
 impl WgpuToyRenderer {
     pub async fn render_to_buffer(&mut self) -> Result<Vec<u8>, String> {
         // Update time for animation
-        self.bindings.time.host.elapsed += 0.016; // ~60fps
-        self.bindings.time.host.frame = self.bindings.time.host.frame.wrapping_add(1);
+        let elapsed = self.bindings.time.host.elapsed + 0.016; // ~60fps
+        self.render_to_buffer_at(elapsed, 0.016).await
+    }
+
+    /// Render a single frame with `Time.elapsed` pinned to `elapsed`, for fixed-rate exports
+    pub async fn render_to_buffer_at(&mut self, elapsed: f32, delta: f32) -> Result<Vec<u8>, String> {
+        let encoder = self.encode_frame(elapsed, delta);
+        let pixels = self.read_screen(encoder).await?;
+        Ok(rgba_f32_to_u8(&pixels))
+    }
+
+    /// Run one frame without reading it back - used to warm up feedback shaders
+    pub fn step_at(&mut self, elapsed: f32, delta: f32) {
+        let encoder = self.encode_frame(elapsed, delta);
+        self.wgpu.queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    /// Render `frames` frames at `elapsed` and average them before quantizing
+    ///
+    /// `Time.frame` still advances every frame, so progressive shaders that seed
+    /// their noise from it converge instead of repeating the same sample.
+    pub async fn render_accumulated_at(
+        &mut self,
+        elapsed: f32,
+        delta: f32,
+        frames: u32,
+    ) -> Result<Vec<u8>, String> {
+        let frames = frames.max(1);
+        let mut sum: Vec<f32> = Vec::new();
+        for _ in 0..frames {
+            let encoder = self.encode_frame(elapsed, delta);
+            let pixels = self.read_screen(encoder).await?;
+            if sum.is_empty() {
+                sum = pixels;
+            } else {
+                sum.iter_mut().zip(&pixels).for_each(|(acc, v)| *acc += v);
+            }
+        }
+
+        let scale = 1.0 / frames as f32;
+        sum.iter_mut().for_each(|v| *v *= scale);
+        Ok(rgba_f32_to_u8(&sum))
+    }
+
+    /// Stage uniforms and record the compute passes of one frame
+    fn encode_frame(&mut self, elapsed: f32, delta: f32) -> wgpu::CommandEncoder {
+        self.bindings.time.host.elapsed = elapsed;
+        self.bindings.time.host.delta = delta;
 
         // Stage uniform data
         self.bindings.stage(&self.wgpu.queue);
@@ -235,28 +491,76 @@ impl WgpuToyRenderer {
             label: Some("Render Encoder"),
         });
 
-        // Dispatch compute shaders
-        for pipeline in &self.compute_pipelines {
-            let mut compute_pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
-                label: Some("Compute Pass"),
-                timestamp_writes: None,
-            });
+        // Assert counters and the debug log are per frame
+        encoder.clear_buffer(self.bindings.assert_counts.buffer(), 0, None);
+        encoder.clear_buffer(self.bindings.debug_buffer.buffer(), 0, Some(DEBUG_HEADER_BYTES as u64));
 
-            let workgroup_count = pipeline.workgroup_count.unwrap_or([
-                self.screen_width.div_ceil(pipeline.workgroup_size[0]),
-                self.screen_height.div_ceil(pipeline.workgroup_size[1]),
-                1,
-            ]);
+        // Dispatch compute shaders, then draw the surface over their output and blur it
+        self.encode_compute_passes(&mut encoder);
+        self.encode_post_passes(&mut encoder);
 
-            compute_pass.set_pipeline(&pipeline.pipeline);
-            compute_pass.set_bind_group(0, &self.compute_bind_group, &[0]);
-            compute_pass.dispatch_workgroups(
-                workgroup_count[0],
-                workgroup_count[1],
-                workgroup_count[2],
-            );
+        // Frame counts from 0, like the surface path, so `#dispatch_once` passes see frame 0
+        self.bindings.time.host.frame = self.bindings.time.host.frame.wrapping_add(1);
+
+        encoder
+    }
+
+    /// `assert` failures counted on frames read back since the last call
+    pub fn take_assert_failures(&mut self) -> Vec<AssertFailure> {
+        let totals = std::mem::take(&mut self.assert_totals);
+        totals
+            .into_iter()
+            .enumerate()
+            .filter(|&(_, invocations)| invocations > 0)
+            .map(|(index, invocations)| AssertFailure {
+                index,
+                line: self.source.assert_map.get(index).copied(),
+                invocations,
+            })
+            .collect()
+    }
+
+    /// Read back `debugLog` records after every frame that is read back
+    pub fn set_shader_debug(&mut self, enabled: bool) {
+        self.shader_debug = enabled;
+    }
+
+    /// `debugLog` records collected since the last call
+    pub fn take_debug_log(&mut self) -> ShaderDebugLog {
+        std::mem::take(&mut self.debug_log)
+    }
+
+    /// Describe an assert failure as a `ShaderError` pointing at its `#assert` line
+    pub fn assert_error(&self, failure: &AssertFailure) -> CodeSkewError {
+        let message = format!(
+            "assertion {} failed in {} invocations",
+            failure.index, failure.invocations
+        );
+        let Some(line) = failure.line else {
+            return CodeSkewError::ShaderError {
+                file: self.source.file.clone(),
+                line: 0,
+                col: 0,
+                message,
+                snippet: String::new(),
+            };
+        };
+
+        let source_line = self.source.original.lines().nth(line.saturating_sub(1)).unwrap_or_default();
+        let col = source_line.len() - source_line.trim_start().len() + 1;
+        CodeSkewError::ShaderError {
+            file: self.source.file.clone(),
+            line,
+            col,
+            message,
+            snippet: caret_snippet(&self.source.file, source_line, line, col, source_line.trim().len()),
         }
+    }
 
+    /// Submit `encoder` with a copy of the screen texture and read it back as RGBA f32
+    ///
+    /// The frame's assert counters are read back alongside and added to the totals.
+    async fn read_screen(&mut self, mut encoder: wgpu::CommandEncoder) -> Result<Vec<f32>, String> {
         // Create staging buffer for readback (rgba16float = 8 bytes per pixel)
         let bytes_per_row = (self.screen_width * 8).next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
         let staging_buffer = self.wgpu.device.create_buffer(&wgpu::BufferDescriptor {
@@ -269,7 +573,7 @@ impl WgpuToyRenderer {
         // Copy texture to staging buffer
         encoder.copy_texture_to_buffer(
             wgpu::TexelCopyTextureInfo {
-                texture: self.bindings.tex_screen.texture(),
+                texture: self.output_texture(),
                 mip_level: 0,
                 origin: wgpu::Origin3d::ZERO,
                 aspect: wgpu::TextureAspect::All,
@@ -289,36 +593,71 @@ impl WgpuToyRenderer {
             },
         );
 
+        // Copy assert counters to their own staging buffer
+        let assert_bytes = (NUM_ASSERT_COUNTERS * size_of::<u32>()) as u64;
+        let assert_staging = self.wgpu.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Assert Staging Buffer"),
+            size: assert_bytes,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+        encoder.copy_buffer_to_buffer(self.bindings.assert_counts.buffer(), 0, &assert_staging, 0, assert_bytes);
+
+        // The debug log is only copied when someone asked for it
+        let debug_staging = self.shader_debug.then(|| {
+            let debug_buffer = self.bindings.debug_buffer.buffer();
+            let staging = self.wgpu.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Debug Staging Buffer"),
+                size: debug_buffer.size(),
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            });
+            encoder.copy_buffer_to_buffer(debug_buffer, 0, &staging, 0, debug_buffer.size());
+            staging
+        });
+
         // Submit commands
         let _submission_index = self.wgpu.queue.submit(std::iter::once(encoder.finish()));
 
-        // Map buffer for reading
-        let buffer_slice = staging_buffer.slice(..);
-        let (sender, receiver) = futures::channel::oneshot::channel();
-
-        buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
-            let _ = sender.send(result);
-        });
+        // Map buffers for reading
+        let screen_mapped = map_for_read(&staging_buffer);
+        let asserts_mapped = map_for_read(&assert_staging);
+        let debug_mapped = debug_staging.as_ref().map(map_for_read);
 
         // Wait for GPU operations to complete
         self.wgpu.device.poll(wgpu::MaintainBase::Wait);
 
-        // Receive mapping result
-        match receiver.await {
-            Ok(Ok(())) => {}
-            Ok(Err(e)) => {
-                return Err(format!("Buffer mapping failed: {e:?}"));
-            }
-            Err(_) => {
-                return Err("Channel error during buffer mapping".to_string());
-            }
+        // Receive mapping results
+        screen_mapped.await?;
+        asserts_mapped.await?;
+        if let Some(debug_mapped) = debug_mapped {
+            debug_mapped.await?;
+        }
+
+        if let Some(debug_staging) = debug_staging {
+            let frame = self.bindings.time.host.frame.wrapping_sub(1);
+            let data = debug_staging.slice(..).get_mapped_range();
+            let (dropped, records) = decode_debug_log(&data, frame);
+            self.debug_log.records.extend(records);
+            self.debug_log.dropped += dropped;
+            drop(data);
+            debug_staging.unmap();
+        }
+
+        let counts = assert_staging.slice(..).get_mapped_range();
+        for (total, count) in self.assert_totals.iter_mut().zip(counts.chunks_exact(4)) {
+            *total += u32::from_le_bytes([count[0], count[1], count[2], count[3]]) as u64;
         }
+        drop(counts);
+        assert_staging.unmap();
+
+        let buffer_slice = staging_buffer.slice(..);
 
         // Read the data and convert from f16 to u8
         let data = buffer_slice.get_mapped_range();
         let f16_data: &[u8] = &data;
 
-        // Convert from rgba16float to rgba8unorm
+        // Convert from rgba16float, dropping the row padding
         let mut result = Vec::with_capacity((self.screen_width * self.screen_height * 4) as usize);
 
         // Process row by row to handle padding
@@ -328,16 +667,11 @@ impl WgpuToyRenderer {
                 let pixel_offset = row_offset + (x * 8) as usize;
                 if pixel_offset + 8 <= f16_data.len() {
                     let chunk = &f16_data[pixel_offset..pixel_offset + 8];
-                    // Convert f16 to f32 then to u8
-                    let r = half::f16::from_le_bytes([chunk[0], chunk[1]]).to_f32();
-                    let g = half::f16::from_le_bytes([chunk[2], chunk[3]]).to_f32();
-                    let b = half::f16::from_le_bytes([chunk[4], chunk[5]]).to_f32();
-                    let a = half::f16::from_le_bytes([chunk[6], chunk[7]]).to_f32();
-
-                    result.push((r.clamp(0.0, 1.0) * 255.0) as u8);
-                    result.push((g.clamp(0.0, 1.0) * 255.0) as u8);
-                    result.push((b.clamp(0.0, 1.0) * 255.0) as u8);
-                    result.push((a.clamp(0.0, 1.0) * 255.0) as u8);
+                    result.extend(
+                        chunk
+                            .chunks_exact(2)
+                            .map(|c| half::f16::from_le_bytes([c[0], c[1]]).to_f32()),
+                    );
                 }
             }
         }
@@ -349,14 +683,142 @@ impl WgpuToyRenderer {
     }
 }
 
+/// Map a compilation message on the prelude-prefixed WGSL back to the user's source
+fn shader_error(
+    source: &pp::SourceMap,
+    prelude_lines: usize,
+    message: &wgpu::CompilationMessage,
+) -> CodeSkewError {
+    // naga appends its own annotated listing of the generated WGSL; keep the summary
+    // and the `= note` lines that explain validation failures
+    let mut lines = message.message.lines().map(str::trim).filter(|l| !l.is_empty());
+    let summary = lines.next().unwrap_or_default();
+    let mut text = summary
+        .split_once("error: ")
+        .map_or(summary, |(_, rest)| rest)
+        .to_string();
+    for note in lines.filter_map(|l| l.strip_prefix("= ")) {
+        text.push_str(": ");
+        text.push_str(note);
+    }
+    let Some(location) = message.location else {
+        return CodeSkewError::ShaderError {
+            file: source.file.clone(),
+            line: 0,
+            col: 0,
+            message: text,
+            snippet: String::new(),
+        };
+    };
+
+    // Lines past the prelude index `map`; anything earlier is in generated bindings
+    let wgsl_line = location.line_number as usize;
+    let Some(line) = wgsl_line
+        .checked_sub(prelude_lines + 1)
+        .and_then(|i| source.map.get(i).copied())
+    else {
+        return CodeSkewError::ShaderError {
+            file: "<prelude>".to_string(),
+            line: wgsl_line,
+            col: location.line_position as usize,
+            message: text,
+            snippet: String::new(),
+        };
+    };
+
+    let col = location.line_position as usize;
+    let snippet = caret_snippet(
+        &source.file,
+        source.original.lines().nth(line.saturating_sub(1)).unwrap_or_default(),
+        line,
+        col,
+        location.length as usize,
+    );
+
+    CodeSkewError::ShaderError {
+        file: source.file.clone(),
+        line,
+        col,
+        message: text,
+        snippet,
+    }
+}
+
+/// Render `source_line` rustc-style with a caret under the `len` bytes at `col` (1-based)
+fn caret_snippet(file: &str, source_line: &str, line: usize, col: usize, len: usize) -> String {
+    let gutter = " ".repeat(line.to_string().len());
+    let start = col.saturating_sub(1).min(source_line.len());
+    let prefix: String = source_line
+        .get(..start)
+        .unwrap_or_default()
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    let width = source_line
+        .get(start..(start + len).min(source_line.len()))
+        .map_or(0, |s| s.chars().count())
+        .max(1);
+
+    format!(
+        "{gutter}--> {file}:{line}:{col}\n{gutter} |\n{line} | {source_line}\n{gutter} | {prefix}{}",
+        "^".repeat(width)
+    )
+}
+
+/// Request a read mapping of `buffer`; resolves once the device has been polled
+fn map_for_read(buffer: &wgpu::Buffer) -> impl Future<Output = Result<(), String>> + use<> {
+    let (sender, receiver) = futures::channel::oneshot::channel();
+    buffer.slice(..).map_async(wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+
+    async move {
+        match receiver.await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(format!("Buffer mapping failed: {e:?}")),
+            Err(_) => Err("Channel error during buffer mapping".to_string()),
+        }
+    }
+}
+
+/// Decode a `DebugLog` buffer into its records and the number of calls that overflowed it
+fn decode_debug_log(data: &[u8], frame: u32) -> (u64, Vec<DebugRecord>) {
+    let word = |offset: usize| [data[offset], data[offset + 1], data[offset + 2], data[offset + 3]];
+    let len = u32::from_le_bytes(word(0)) as usize;
+    let kept = len.min(MAX_DEBUG_RECORDS);
+
+    let records = (0..kept)
+        .map(|i| {
+            // `id` sits at offset 0 and `value` at offset 16 (vec4 alignment)
+            let record = DEBUG_HEADER_BYTES + i * DEBUG_RECORD_BYTES;
+            DebugRecord {
+                frame,
+                id: u32::from_le_bytes(word(record)),
+                value: std::array::from_fn(|c| f32::from_le_bytes(word(record + 16 + c * 4))),
+            }
+        })
+        .collect();
+
+    ((len - kept) as u64, records)
+}
+
+/// Quantize linear RGBA floats from the screen texture to 8-bit
+fn rgba_f32_to_u8(pixels: &[f32]) -> Vec<u8> {
+    pixels
+        .iter()
+        .map(|v| (v.clamp(0.0, 1.0) * 255.0) as u8)
+        .collect()
+}
+
 // Add essential async and sync rendering methods
 impl WgpuToyRenderer {
     pub async fn create_renderer(
         width: u32,
         height: u32,
         bind_id: String,
+        backend: crate::cli::GpuBackend,
     ) -> Result<WgpuToyRenderer, String> {
-        let wgpu = init_wgpu(width, height, &bind_id).await?;
+        let wgpu = init_wgpu(width, height, &bind_id, backend).await?;
         Ok(WgpuToyRenderer::new(wgpu))
     }
 
@@ -518,11 +980,85 @@ impl WgpuToyRenderer {
         std::mem::swap(&mut self.bindings, &mut bindings);
         self.bindings.custom.host = bindings.custom.host.clone();
         self.bindings.user_data.host = bindings.user_data.host.clone();
+        self.bindings.camera.host = bindings.camera.host;
+        std::mem::swap(&mut self.bindings.background, &mut bindings.background);
         // self.bindings.channels = std::mem::take(&mut bindings.channels);
         let layout = self.bindings.create_bind_group_layout(&self.wgpu);
         self.compute_pipeline_layout = self.bindings.create_pipeline_layout(&self.wgpu, &layout);
         self.compute_bind_group = self.bindings.create_bind_group(&self.wgpu, &layout);
         self.compute_bind_group_layout = layout;
+
+        // The surface and blur passes read the old screen texture
+        if let Some(surface) = self.surface.take() {
+            self.set_surface(surface.surface(), surface.text().clone());
+        } else if let Some(blur) = &self.blur {
+            self.set_depth_of_field(Some(blur.settings()));
+        }
+    }
+
+    /// Set the `custom` uniform fields and values - copied from wgpu-compute-toy
+    ///
+    /// The names become the fields of `struct Custom` in the prelude, so call this before `compile`.
+    pub fn set_custom_floats(&mut self, names: Vec<String>, values: Vec<f32>) -> Result<(), String> {
+        if names.len() != values.len() {
+            return Err(format!("{} custom names but {} values", names.len(), values.len()));
+        }
+        if names.len() > MAX_CUSTOM_PARAMS {
+            return Err(format!(
+                "At most {MAX_CUSTOM_PARAMS} custom parameters are supported, got {}",
+                names.len()
+            ));
+        }
+        if names.is_empty() {
+            // Keep the placeholder field, WGSL has no empty structs
+            self.bindings.custom.host = (vec!["_dummy".into()], vec![0.]);
+        } else {
+            self.bindings.custom.host = (names, values);
+        }
+        Ok(())
+    }
+
+    /// Names and values of the `custom` uniform, as `set_custom_floats` left them
+    pub fn custom_floats(&self) -> (Vec<String>, Vec<f32>) {
+        self.bindings.custom.host.clone()
+    }
+
+    /// Set the `codeskew_camera` uniform, the text warp every shader can read
+    pub fn set_camera(&mut self, camera: Camera) {
+        self.bindings.camera.host = camera;
+    }
+
+    /// Upload the `codeskew_background` texture shaders blend their own backgrounds with
+    ///
+    /// Stored as `Rgba8Unorm`, so shaders read the sRGB-encoded values `screen` holds.
+    pub fn set_background(&mut self, image: &image::RgbaImage) {
+        let size = wgpu::Extent3d {
+            width: image.width(),
+            height: image.height(),
+            depth_or_array_layers: 1,
+        };
+        let texture = self.wgpu.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("background"),
+            size,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        self.wgpu.queue.write_texture(
+            texture.as_image_copy(),
+            image,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(4 * image.width()),
+                rows_per_image: Some(image.height()),
+            },
+            size,
+        );
+        self.bindings.background.set_texture(texture);
+        self.recreate_bind_group();
     }
 
     /// Set time elapsed - copied from wgpu-compute-toy
@@ -540,6 +1076,20 @@ impl WgpuToyRenderer {
         }
     }
 
+    /// Set one key of the `keyDown` bitmap - copied from wgpu-compute-toy
+    ///
+    /// `keycode` is a browser keycode, see [`keys::keycode`].
+    pub fn set_keydown(&mut self, keycode: usize, keydown: bool) {
+        if keycode < self.bindings.keys.host.len() {
+            self.bindings.keys.host.set(keycode, keydown);
+        }
+    }
+
+    /// Release every key, e.g. when the window loses focus and releases go unseen
+    pub fn release_all_keys(&mut self) {
+        self.bindings.keys.host.fill(false);
+    }
+
     /// Set mouse click state - copied from wgpu-compute-toy
     pub fn set_mouse_click(&mut self, click: bool) {
         self.bindings.mouse.host.click = if click { 1 } else { 0 };
@@ -551,9 +1101,9 @@ impl WgpuToyRenderer {
         self.screen_height = (height as f32 * scale) as u32;
         self.wgpu.surface_config.width = self.screen_width;
         self.wgpu.surface_config.height = self.screen_height;
-        self.wgpu
-            .surface
-            .configure(&self.wgpu.device, &self.wgpu.surface_config);
+        if let Some(surface) = &self.wgpu.surface {
+            surface.configure(&self.wgpu.device, &self.wgpu.surface_config);
+        }
         self.reset();
     }
 
@@ -561,13 +1111,20 @@ impl WgpuToyRenderer {
     pub async fn render_async(&mut self) {
         use wgpu::SurfaceError;
 
-        match self.wgpu.surface.get_current_texture() {
+        let Some(surface) = &self.wgpu.surface else {
+            log::warn!("render_async called on a headless context, use render_to_buffer instead");
+            return;
+        };
+
+        match surface.get_current_texture() {
             Err(err) => match err {
                 SurfaceError::Lost | SurfaceError::Outdated => {
                     log::error!("Unable to get framebuffer: {err}");
-                    self.wgpu.surface.configure(&self.wgpu.device, &self.wgpu.surface_config);
+                    surface.configure(&self.wgpu.device, &self.wgpu.surface_config);
                     #[cfg(feature = "winit")]
-                    self.wgpu.window.request_redraw();
+                    if let Some(window) = &self.wgpu.window {
+                        window.request_redraw();
+                    }
                 }
                 SurfaceError::OutOfMemory => log::error!("Out of GPU Memory!"),
                 SurfaceError::Timeout => log::warn!("Surface Timeout"),
@@ -587,30 +1144,15 @@ impl WgpuToyRenderer {
         // Stage uniform data
         self.bindings.stage(&self.wgpu.queue);
 
-        // Dispatch compute shaders
-        for pipeline in &self.compute_pipelines {
-            let mut compute_pass = encoder.begin_compute_pass(&Default::default());
-
-            let workgroup_count = pipeline.workgroup_count.unwrap_or([
-                self.screen_width.div_ceil(pipeline.workgroup_size[0]),
-                self.screen_height.div_ceil(pipeline.workgroup_size[1]),
-                1,
-            ]);
-
-            compute_pass.set_pipeline(&pipeline.pipeline);
-            compute_pass.set_bind_group(0, &self.compute_bind_group, &[0]);
-            compute_pass.dispatch_workgroups(
-                workgroup_count[0],
-                workgroup_count[1],
-                workgroup_count[2],
-            );
-        }
+        // Dispatch compute shaders, then draw the surface over their output and blur it
+        self.encode_compute_passes(&mut encoder);
+        self.encode_post_passes(&mut encoder);
 
         // Use the blitter to copy from compute texture to surface
         // Recreate blitter if texture view is invalid (surgical fix for texture destruction)
         self._screen_blitter = blit::Blitter::new(
             &self.wgpu,
-            self.bindings.tex_screen.view(),
+            &self.frame_view(true),
             blit::ColourSpace::Linear,
             self.wgpu.surface_config.format,
             wgpu::FilterMode::Nearest,
@@ -624,3 +1166,604 @@ impl WgpuToyRenderer {
         self.bindings.time.host.frame = self.bindings.time.host.frame.wrapping_add(1);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::GpuBackend;
+    use crate::transform::{TextSheet, ViewCamera};
+
+    /// A headless context on the software adapter, or `None` to skip where there is none
+    async fn headless_or_skip(width: u32, height: u32) -> Option<WgpuContext> {
+        match init_wgpu_headless(width, height, GpuBackend::Cpu).await {
+            Ok(wgpu) => Some(wgpu),
+            Err(e) => {
+                eprintln!("skipping: {e}");
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn render_to_buffer_on_software_adapter() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(64, 32).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let source = toy
+                .preprocess_async(
+                    "@compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                         textureStore(screen, id.xy, vec4f(1.0, 0.5, 0.0, 1.0));\n\
+                     }\n",
+                )
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+
+            let pixels = toy.render_to_buffer().await.expect("render");
+            assert_eq!(pixels.len(), 64 * 32 * 4);
+            for px in pixels.chunks_exact(4) {
+                assert_eq!(px[0], 255);
+                assert!((126..=128).contains(&px[1]), "green = {}", px[1]);
+                assert_eq!(px[2], 0);
+                assert_eq!(px[3], 255);
+            }
+        });
+    }
+
+    #[test]
+    fn dispatch_count_repeats_entry_point_with_dispatch_id() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(48, 16).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let source = toy
+                .preprocess_async(include_str!("../../wgsl/dispatch_count.wgsl"))
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+
+            // One band per dispatch: red, green, blue from left to right
+            let pixels = toy.render_to_buffer().await.expect("render");
+            for (x, channel) in [(8, 0), (24, 1), (40, 2)] {
+                let px = &pixels[x * 4..x * 4 + 4];
+                for c in 0..3 {
+                    let expected = if c == channel { 255 } else { 0 };
+                    assert_eq!(px[c], expected, "pixel {x} channel {c}: {px:?}");
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn template_lines_map_back_to_the_template() {
+        let template = "a\n{% for i in range(2) %}\nb{{ i }}\n{% endfor %}\nc\n";
+        let rendered = "a\nb0\nb1\nc\n";
+        assert_eq!(pp::template_line_map(template, rendered), vec![1, 2, 2, 5]);
+    }
+
+    #[test]
+    fn includes_are_recorded_for_hot_reload() {
+        let source = pollster::block_on(
+            pp::Preprocessor::new(Default::default(), ShaderLibrary::default())
+                .run("#include <math>\nfn f() {}\n"),
+        )
+        .expect("preprocess");
+        assert_eq!(source.includes, vec!["std/math".to_string()]);
+
+        // With no directories only the embedded copy exists, which has no file to watch
+        let library = ShaderLibrary::default();
+        assert!(library.read_include("std/math").is_some_and(|file| file.path.is_none()));
+        assert_eq!(library.find("include/std/math.wgsl"), None);
+
+        // Quoted includes are embedded too, wherever codeskew runs from
+        assert!(library.read_include("Dave_Hoskins/hash").is_some());
+    }
+
+    #[test]
+    fn shader_names_resolve_in_the_embedded_library() {
+        let library = ShaderLibrary::default();
+        let shader = library.read_shader("davidar/caustics").expect("embedded shader");
+        assert_eq!(shader.display(), "<embedded>/davidar/caustics.wgsl");
+        assert!(shader.contents.contains("@compute"));
+
+        assert!(library.read_shader("../wgsl/bandwidth").is_err());
+        assert!(library.read_shader("/etc/passwd").is_err());
+        assert!(library.read_shader("no/such/shader").is_err());
+
+        let names = library.shader_names();
+        assert!(names.iter().any(|name| name == "davidar/caustics"));
+        assert!(names.iter().all(|name| !name.starts_with("include/")));
+    }
+
+    #[test]
+    fn compile_errors_point_at_the_user_source() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(16, 16).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let source = toy
+                .preprocess_async(
+                    "#define RED 1.0\n\
+                     @compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                         textureStore(screen, id.xy, vec4f(RED, missing, 0.0, 1.0));\n\
+                     }\n",
+                )
+                .await
+                .expect("preprocess");
+
+            match toy.compile(source).await {
+                Err(CodeSkewError::ShaderError { line, message, snippet, .. }) => {
+                    assert_eq!(line, 4);
+                    assert!(message.contains("missing"), "{message}");
+                    assert!(snippet.contains("^^^^^^^"), "{snippet}");
+                }
+                other => panic!("expected a shader error, got {other:?}"),
+            }
+        });
+    }
+
+    #[test]
+    fn failed_asserts_are_counted_per_line() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(16, 16).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let source = toy
+                .preprocess_async(
+                    "@compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                         #assert id.y < 16u\n\
+                         #assert id.x < 4u\n\
+                     }\n",
+                )
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+
+            toy.render_to_buffer().await.expect("render");
+            toy.render_to_buffer().await.expect("render");
+            let failures = toy.take_assert_failures();
+            assert_eq!(
+                failures,
+                vec![AssertFailure { index: 1, line: Some(4), invocations: 2 * 12 * 16 }]
+            );
+            assert!(toy.take_assert_failures().is_empty());
+        });
+    }
+
+    #[test]
+    fn debug_log_records_are_read_back() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(16, 16).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            toy.set_shader_debug(true);
+            let source = toy
+                .preprocess_async(
+                    "@compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                         if (id.x == 3u && id.y == 4u) { debugLog(7u, vec4f(1.0, 2.0, 3.0, 4.0)); }\n\
+                     }\n",
+                )
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+
+            toy.render_to_buffer().await.expect("render");
+            toy.render_to_buffer().await.expect("render");
+            let log = toy.take_debug_log();
+            let record = |frame| DebugRecord { frame, id: 7, value: [1.0, 2.0, 3.0, 4.0] };
+            assert_eq!(log.records, vec![record(0), record(1)]);
+            assert_eq!(log.dropped, 0);
+        });
+    }
+
+    #[test]
+    fn custom_floats_become_uniform_fields() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(16, 16).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            toy.set_custom_floats(vec!["red".into(), "blue".into()], vec![1.0, 0.0])
+                .expect("custom floats");
+            let source = toy
+                .preprocess_async(
+                    "@compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                         textureStore(screen, id.xy, vec4f(custom.red, 0.0, custom.blue, 1.0));\n\
+                     }\n",
+                )
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+
+            let pixels = toy.render_to_buffer().await.expect("render");
+            assert_eq!(&pixels[..4], &[255, 0, 0, 255]);
+
+            let too_many = (0..=MAX_CUSTOM_PARAMS).map(|i| format!("p{i}")).collect::<Vec<_>>();
+            let values = vec![0.0; too_many.len()];
+            assert!(toy.set_custom_floats(too_many, values).is_err());
+        });
+    }
+
+    #[test]
+    fn keydown_reads_the_key_bitmap() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(16, 16).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let source = toy
+                .preprocess_async(
+                    "@compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                         let left = f32(keyDown(37u));\n\
+                         let quote = f32(keyDown(222u));\n\
+                         textureStore(screen, id.xy, vec4f(left, quote, 0.0, 1.0));\n\
+                     }\n",
+                )
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+
+            let left = keys::keycode(winit::keyboard::KeyCode::ArrowLeft).expect("keycode");
+            let quote = keys::keycode(winit::keyboard::KeyCode::Quote).expect("keycode");
+            toy.set_keydown(left, true);
+            toy.set_keydown(quote, true);
+            let pixels = toy.render_to_buffer().await.expect("render");
+            assert_eq!(&pixels[..4], &[255, 255, 0, 255]);
+
+            toy.set_keydown(left, false);
+            let pixels = toy.render_to_buffer().await.expect("render");
+            assert_eq!(&pixels[..4], &[0, 255, 0, 255]);
+        });
+    }
+
+    #[test]
+    fn camera_flags_change_the_unified_render() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(64, 64).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+
+            // Checkerboard standing in for the glyphon text texture
+            let text = image::RgbaImage::from_fn(64, 64, |x, y| {
+                if (x / 8 + y / 8) % 2 == 0 {
+                    image::Rgba([255, 255, 255, 255])
+                } else {
+                    image::Rgba([0, 0, 0, 0])
+                }
+            });
+            let mut png = std::io::Cursor::new(Vec::new());
+            text.write_to(&mut png, image::ImageFormat::Png).expect("encode text texture");
+            toy.load_channel(1, png.get_ref()).expect("text texture");
+            toy.recreate_bind_group();
+
+            let shader = ShaderLibrary::default()
+                .read_shader("codeskew_unified")
+                .expect("embedded shader");
+            let source = toy.preprocess_async(&shader.contents).await.expect("preprocess");
+            toy.compile(source).await.expect("compile");
+
+            let mut render = async |camera: Camera| {
+                toy.set_camera(camera);
+                toy.render_to_buffer_at(0.0, 0.0).await.expect("render")
+            };
+            let default = render(Camera::default()).await;
+            assert_eq!(default, render(Camera::default()).await);
+            for camera in [
+                Camera { fold: 0.0, ..Camera::default() },
+                Camera { fold_point: 0.3, ..Camera::default() },
+                Camera { skew_angle: 0.4, ..Camera::default() },
+                Camera { scale: 0.0, ..Camera::default() },
+                Camera { depth: 2.0, ..Camera::default() },
+                Camera { skew: 0.7, ..Camera::default() },
+                Camera { perspective: 300.0, ..Camera::default() },
+                Camera::default().with_view(&ViewCamera { yaw: 0.5, ..ViewCamera::new(64, 64, 1000.0) }),
+                Camera::default().with_view(&ViewCamera { roll: 0.3, ..ViewCamera::new(64, 64, 1000.0) }),
+            ] {
+                assert_ne!(default, render(camera).await, "{camera:?}");
+            }
+        });
+    }
+
+    #[test]
+    fn camera_flags_change_the_default_render() {
+        use crate::cli::Cli;
+        use crate::config::Config;
+        use crate::output::OutputGenerator;
+        use clap::Parser;
+
+        // Like main: a Tokio runtime for texture downloads, on a thread with the main thread's
+        // stack size rather than a test thread's
+        let test = || {
+            let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().expect("runtime");
+            runtime.block_on(async {
+                if headless_or_skip(1, 1).await.is_none() {
+                    return;
+                }
+                let render = async |flags: &[&str]| {
+                    let args = ["codeskew", "sample.rs", "--backend", "cpu", "-w", "96", "-H", "64"];
+                    let cli = Cli::try_parse_from(args.iter().chain(flags)).expect("flags");
+                    let mut generator = OutputGenerator::new(Config::from_cli(&cli).expect("config")).expect("generator");
+                    generator
+                        .render_shader_preview("bandwidth", "fn main() {\n    println!(\"skewed\");\n}\n")
+                        .await
+                        .expect("render")
+                };
+                let default = render(&[]).await;
+                for flags in [
+                    ["--skew", "40"],
+                    ["--perspective", "300"],
+                    ["--depth", "2"],
+                    ["--fold", "0.9"],
+                    ["--fold-point", "0.3"],
+                    ["--skew-angle", "0.5"],
+                    ["--scale", "0"],
+                    ["--yaw", "30"],
+                ] {
+                    assert_ne!(default, render(&flags).await, "{flags:?}");
+                }
+            })
+        };
+        std::thread::Builder::new().stack_size(8 << 20).spawn(test).unwrap().join().unwrap();
+    }
+
+    #[test]
+    fn shaders_with_their_own_camera_compile() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(64, 64).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let library = ShaderLibrary::default();
+
+            // Declares `struct Camera` and `var<private> camera`, clear of the prelude's names
+            let name = "michael0884/stardust";
+            let metadata = library.read_metadata(name).expect("embedded metadata");
+            let uniforms = crate::shader_library::ShaderMetadata::parse(&metadata).expect("metadata").uniforms;
+            toy.set_custom_floats(
+                uniforms.iter().map(|uniform| uniform.name.clone()).collect(),
+                uniforms.iter().map(|uniform| uniform.value).collect(),
+            )
+            .expect("custom floats");
+
+            let shader = library.read_shader(name).expect("embedded shader");
+            let source = toy.preprocess_async(&shader.contents).await.expect("preprocess");
+            toy.compile(source).await.expect("compile");
+        });
+    }
+
+    #[test]
+    fn shaders_including_the_hash_library_compile() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(64, 64).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let library = ShaderLibrary::default();
+
+            // The simulations' storage buffers are too big to build on a software adapter
+            for name in ["davidar/neighbourhood-grid", "seblague/slime-simulation"] {
+                let shader = library.read_shader(name).expect("embedded shader");
+                let source = toy.preprocess_async(&shader.contents).await;
+                assert!(source.is_some_and(|source| source.source.contains("fn hash42(")), "{name}");
+            }
+
+            let shader = library.read_shader("hash").expect("embedded shader");
+            let source = toy.preprocess_async(&shader.contents).await.expect("preprocess");
+            toy.compile(source).await.expect("compile");
+        });
+    }
+
+    #[test]
+    fn view_camera_projects_the_text_plane() {
+        let straight = ViewCamera::new(800, 600, 1000.0);
+        for (x, y) in [(0.0, 0.0), (400.0, 300.0), (800.0, 600.0), (120.0, 510.0)] {
+            let p = straight.project(x, y).expect("in front");
+            assert!((p.x - x).abs() < 0.01 && (p.y - y).abs() < 0.01, "({x}, {y}) -> {p}");
+        }
+        let identity = straight.text_from_screen_uv();
+        assert!((identity - nalgebra::Matrix3::identity()).abs().max() < 1e-4);
+
+        // Turning the right edge away shrinks it and keeps the look-at point centered
+        let yawed = ViewCamera { yaw: 30f32.to_radians(), ..straight };
+        let center = yawed.project(400.0, 300.0).expect("in front");
+        assert!((center.x - 400.0).abs() < 0.01 && (center.y - 300.0).abs() < 0.01);
+        let height = |x| yawed.project(x, 600.0).unwrap().y - yawed.project(x, 0.0).unwrap().y;
+        assert!(height(800.0) < 600.0 && height(0.0) > 600.0);
+
+        // Moving the look-at point brings it to the middle of the screen
+        let looking = ViewCamera { look_at: nalgebra::Point2::new(200.0, 450.0), ..yawed };
+        let center = looking.project(200.0, 450.0).expect("in front");
+        assert!((center.x - 400.0).abs() < 0.01 && (center.y - 300.0).abs() < 0.01);
+
+        // The shader's inverse lands back on the text pixel
+        let uv = yawed.text_from_screen_uv();
+        let screen = yawed.project(700.0, 100.0).unwrap();
+        let back = uv * nalgebra::Vector3::new(screen.x / 800.0, screen.y / 600.0, 1.0);
+        assert!((back.x / back.z * 800.0 - 700.0).abs() < 0.1);
+        assert!((back.y / back.z * 600.0 - 100.0).abs() < 0.1);
+    }
+
+    /// Text uvs spread over the sheet, either side of the default fold point
+    const TEXT_ANCHORS: [(f32, f32); 6] = [(0.1, 0.1), (0.5, 0.5), (0.9, 0.2), (0.3, 0.8), (0.7, 0.67), (0.2, 0.95)];
+
+    fn yawed_sheet() -> (Camera, TextSheet) {
+        let view = ViewCamera { yaw: 20f32.to_radians(), ..ViewCamera::new(800, 600, 1000.0) };
+        let camera = Camera { height: 600.0, ..Camera::default() }.with_view(&view);
+        (camera, TextSheet::new(camera, 800, 600))
+    }
+
+    #[test]
+    fn text_sheet_inverts_its_warp() {
+        let (_, sheet) = yawed_sheet();
+        for (x, y) in TEXT_ANCHORS {
+            let screen = sheet.screen_uv(nalgebra::Point2::new(x, y)).expect("visible");
+            let back = sheet.text_uv(screen).expect("on the sheet");
+            assert!((back.x - x).abs() < 1e-4 && (back.y - y).abs() < 1e-4, "({x}, {y}) -> {back}");
+        }
+
+        // SVG lines are the tangent of the sheet at their anchor, within half a pixel nearby
+        let pixels = |uv: nalgebra::Point2<f32>| nalgebra::Vector2::new(uv.x * 800.0, uv.y * 600.0);
+        let affine = sheet.linearize(240.0, 480.0).expect("visible");
+        for (dx, dy) in [(0.0, 0.0), (12.0, 0.0), (0.0, -12.0)] {
+            let (x, y) = (240.0 + dx, 480.0 + dy);
+            let drawn = affine * nalgebra::Vector3::new(x, y, 1.0);
+            let exact = pixels(sheet.screen_uv(nalgebra::Point2::new(x / 800.0, y / 600.0)).unwrap());
+            assert!((drawn.xy() - exact).abs().max() < 0.5, "({x}, {y}): {drawn} vs {exact}");
+        }
+    }
+
+    #[test]
+    fn text_sheet_matches_the_shader_text_uv() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(64, 64).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let (camera, sheet) = yawed_sheet();
+            toy.set_camera(camera);
+
+            // Where the Rust sheet puts each anchor, fed back through codeskewTextUv
+            let screen: Vec<String> = TEXT_ANCHORS
+                .iter()
+                .map(|&(x, y)| {
+                    let uv = sheet.screen_uv(nalgebra::Point2::new(x, y)).expect("visible");
+                    format!("vec2f({:?}, {:?})", uv.x, uv.y)
+                })
+                .collect();
+            let source = toy
+                .preprocess_async(&format!(
+                    "@compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {{\n\
+                         var anchors = array<vec2f, {}>({});\n\
+                         if (id.x < {}u && id.y == 0u) {{\n\
+                             textureStore(screen, id.xy, vec4f(codeskewTextUv(anchors[id.x]), 0.0, 1.0));\n\
+                         }}\n\
+                     }}\n",
+                    screen.len(),
+                    screen.join(", "),
+                    screen.len(),
+                ))
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+            let encoder = toy.encode_frame(0.0, 0.0);
+            let pixels = toy.read_screen(encoder).await.expect("render");
+
+            for (i, (x, y)) in TEXT_ANCHORS.into_iter().enumerate() {
+                let (u, v) = (pixels[i * 4], pixels[i * 4 + 1]);
+                assert!((u - x).abs() < 2e-3 && (v - y).abs() < 2e-3, "({x}, {y}) -> ({u}, {v})");
+            }
+        });
+    }
+
+    #[test]
+    fn surfaces_draw_the_text_on_geometry() {
+        use crate::cli::Surface;
+
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(64, 64).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let source = toy
+                .preprocess_async(
+                    "@compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                         textureStore(screen, id.xy, vec4f(0.0, 0.0, 1.0, 1.0));\n\
+                     }\n",
+                )
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+            let backdrop = toy.render_to_buffer_at(0.0, 0.0).await.expect("render");
+
+            // Opaque white text texture
+            let text = toy.wgpu.device.create_texture(&wgpu::TextureDescriptor {
+                label: None,
+                size: wgpu::Extent3d { width: 64, height: 64, depth_or_array_layers: 1 },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+                view_formats: &[],
+            });
+            toy.wgpu.queue.write_texture(
+                text.as_image_copy(),
+                &[255; 64 * 64 * 4],
+                wgpu::TexelCopyBufferLayout { offset: 0, bytes_per_row: Some(64 * 4), rows_per_image: None },
+                text.size(),
+            );
+            toy.set_camera(Camera::default().with_view(&ViewCamera::new(64, 64, 1000.0)));
+
+            let mut renders = Vec::new();
+            for surface in [Surface::Plane, Surface::Cylinder, Surface::Curl, Surface::Wave] {
+                toy.set_surface(surface, text.clone());
+                let pixels = toy.render_to_buffer_at(1.0, 0.0).await.expect("render");
+                // Text in the middle, the backdrop where the plane tilts away from the top edge
+                let center = (32 * 64 + 32) * 4;
+                let (r, g, b) = (pixels[center], pixels[center + 1], pixels[center + 2]);
+                assert!(r > 200 && r == g && g == b, "{surface:?}: {r} {g} {b}");
+                if surface == Surface::Plane {
+                    assert_eq!(&pixels[32 * 4..32 * 4 + 4], &backdrop[32 * 4..32 * 4 + 4]);
+                }
+                assert!(renders.iter().all(|other| *other != pixels), "{surface:?}");
+                renders.push(pixels);
+            }
+        });
+    }
+
+    #[test]
+    fn blur_follows_the_warp_depth() {
+        pollster::block_on(async {
+            let Some(wgpu) = headless_or_skip(64, 64).await else {
+                return;
+            };
+            let mut toy = WgpuToyRenderer::new(wgpu);
+            let source = toy
+                .preprocess_async(
+                    "@compute @workgroup_size(16, 16)\n\
+                     fn main_image(@builtin(global_invocation_id) id: vec3u) {\n\
+                         textureStore(screen, id.xy, vec4f(vec3f(f32(id.x % 2u)), 1.0));\n\
+                     }\n",
+                )
+                .await
+                .expect("preprocess");
+            toy.compile(source).await.expect("compile");
+            let stripes = toy.render_to_buffer_at(0.0, 0.0).await.expect("render");
+
+            // The default warp is largest, so nearest, in the middle of the fold point row
+            let fold_row = (43 * 64 + 32) * 4;
+            let corner = 0;
+            let dof = DepthOfField { radius: 4.0, focus_depth: None, focus_band: None };
+            toy.set_depth_of_field(Some(dof));
+            let blurred = toy.render_to_buffer_at(0.0, 0.0).await.expect("render");
+            assert_eq!(&blurred[fold_row..fold_row + 8], &stripes[fold_row..fold_row + 8]);
+            let (sharp, soft) = (stripes[corner + 4], blurred[corner + 4]);
+            assert!(soft > 0 && soft < sharp, "{soft} {sharp}");
+
+            // A focus band over the top rows keeps the corner sharp, where the sheet does not
+            // lean back past them
+            toy.set_camera(Camera { skew: 0.0, ..Camera::default() });
+            toy.set_depth_of_field(Some(DepthOfField { focus_band: Some([0.0, 0.1]), ..dof }));
+            let banded = toy.render_to_buffer_at(0.0, 0.0).await.expect("render");
+            assert_eq!(&banded[corner..corner + 8], &stripes[corner..corner + 8]);
+
+            toy.set_depth_of_field(None);
+            assert_eq!(toy.render_to_buffer_at(0.0, 0.0).await.expect("render"), stripes);
+        });
+    }
+}
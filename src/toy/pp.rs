@@ -6,6 +6,7 @@ use async_recursion::async_recursion;
 
 use lazy_regex::*;
 use rustc_hash::FxHashMap;
+use crate::shader_library::ShaderLibrary;
 use std::borrow::Cow;
 use wasm_bindgen::prelude::*;
 use minijinja::{Environment, Value};
@@ -42,6 +43,15 @@ impl WGSLError {
     pub fn submit(&self) {
         Self::handler(&self.summary, self.line, 0)
     }
+
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// 1-based line of the preprocessor input the error is on
+    pub fn line(&self) -> usize {
+        self.line
+    }
 }
 
 #[derive(Clone)]
@@ -63,6 +73,15 @@ pub struct SourceMap {
     pub assert_map: Vec<usize>,
     #[wasm_bindgen(skip)]
     pub user_data: indexmap::IndexMap<String, Vec<u32>>,
+    /// Name of the shader file, used when reporting compile errors
+    #[wasm_bindgen(skip)]
+    pub file: String,
+    /// The text `map` line numbers point into, used for error snippets
+    #[wasm_bindgen(skip)]
+    pub original: String,
+    /// Names of the `#include`s pulled in, in the order they were expanded
+    #[wasm_bindgen(skip)]
+    pub includes: Vec<String>,
 }
 
 impl SourceMap {
@@ -77,9 +96,25 @@ impl SourceMap {
             dispatch_count: FxHashMap::with_capacity_and_hasher(16, Default::default()),
             assert_map: Vec::with_capacity(NUM_ASSERT_COUNTERS),
             user_data: indexmap::IndexMap::with_capacity(16),
+            file: "shader".to_string(),
+            original: String::new(),
+            includes: Vec::new(),
         }
     }
 
+    /// Point `map` at the lines of `template`, given that it rendered to `rendered`
+    ///
+    /// MiniJinja keeps no line information, so lines are matched up by content.
+    pub fn remap_through_template(&mut self, template: &str, rendered: &str) {
+        let template_map = template_line_map(template, rendered);
+        for line in self.map.iter_mut().chain(self.assert_map.iter_mut()) {
+            if let Some(&original) = line.checked_sub(1).and_then(|i| template_map.get(i)) {
+                *line = original;
+            }
+        }
+        self.original = template.to_string();
+    }
+
     #[inline]
     fn push_line(&mut self, s: &str, n: usize) {
         self.source.push_str(s);
@@ -94,6 +129,33 @@ impl SourceMap {
     }
 }
 
+/// For each line of `rendered`, the 1-based line of `template` it came from
+///
+/// Lines without template syntax render verbatim, so they are matched to the next
+/// identical template line. Anything else (loop bodies, substituted expressions)
+/// is attributed to the template line after the last match.
+pub fn template_line_map(template: &str, rendered: &str) -> Vec<usize> {
+    let template_lines: Vec<&str> = template.lines().collect();
+    let mut map = Vec::new();
+    let mut cursor = 0;
+
+    for line in rendered.lines() {
+        let found = template_lines[cursor..]
+            .iter()
+            .position(|t| *t == line && !t.contains("{{") && !t.contains("{%"));
+        match found {
+            Some(offset) => {
+                cursor += offset;
+                map.push(cursor + 1);
+                cursor += 1;
+            }
+            None => map.push((cursor + 1).min(template_lines.len().max(1))),
+        }
+    }
+
+    map
+}
+
 impl Default for SourceMap {
     #[inline]
     fn default() -> Self {
@@ -103,10 +165,13 @@ impl Default for SourceMap {
 
 pub struct Preprocessor {
     defines: FxHashMap<String, String>,
+    library: ShaderLibrary,
     source: SourceMap,
     storage_count: usize,
     assert_count: usize,
     special_strings: bool,
+    /// Includes being expanded, innermost last, to reject an include of itself
+    include_stack: Vec<String>,
     temp_string: String,
     temp_chars: Vec<u32>,
 }
@@ -124,14 +189,16 @@ pub fn strip_comments(s: &str) -> Cow<str> {
 }
 
 impl Preprocessor {
-    pub fn new(mut defines: FxHashMap<String, String>) -> Self {
+    pub fn new(mut defines: FxHashMap<String, String>, library: ShaderLibrary) -> Self {
         defines.insert("STRING_MAX_LEN".to_string(), STRING_MAX_LEN.to_string());
         Self {
             defines,
+            library,
             source: SourceMap::new(),
             storage_count: 0,
             assert_count: 0,
             special_strings: false,
+            include_stack: Vec::new(),
             temp_string: String::with_capacity(1024),
             temp_chars: Vec::with_capacity(STRING_MAX_LEN),
         }
@@ -248,14 +315,14 @@ impl Preprocessor {
                 }
 
                 let name = tokens[1];
-                let include_result = if let Some(quotes_cap) = RE_QUOTES.captures(name) {
-                    fetch_include(quotes_cap[1].to_string()).await
+                let include_name = if let Some(quotes_cap) = RE_QUOTES.captures(name) {
+                    quotes_cap[1].to_string()
                 } else if let Some(chevrons_cap) = RE_CHEVRONS.captures(name) {
                     let path = &chevrons_cap[1];
                     if path == "string" {
                         self.special_strings = true;
                     }
-                    fetch_include(format!("std/{path}")).await
+                    format!("std/{path}")
                 } else {
                     return Err(WGSLError::new(
                         Cow::Borrowed("Path must be enclosed in quotes or chevrons"),
@@ -263,10 +330,19 @@ impl Preprocessor {
                     ));
                 };
 
-                if let Some(code) = include_result {
+                if self.include_stack.contains(&include_name) {
+                    return Err(WGSLError::new(
+                        Cow::Owned(format!("Recursive include {name}")),
+                        n,
+                    ));
+                }
+                if let Some(code) = fetch_include(&self.library, include_name.clone()).await {
+                    self.source.includes.push(include_name.clone());
+                    self.include_stack.push(include_name);
                     for line in code.lines() {
                         self.process_line(line, n).await?;
                     }
+                    self.include_stack.pop();
                 } else {
                     return Err(WGSLError::new(
                         Cow::Owned(format!("Cannot find include {name}")),
@@ -311,6 +387,15 @@ impl Preprocessor {
 
                 let name = tokens[1].to_string();
                 let count = parse_u32(tokens[2], n)?;
+                if count == 0 || count > super::bind::MAX_DISPATCH_COUNT {
+                    return Err(WGSLError::new(
+                        Cow::Owned(format!(
+                            "Dispatch count must be between 1 and {}",
+                            super::bind::MAX_DISPATCH_COUNT
+                        )),
+                        n,
+                    ));
+                }
 
                 self.source.dispatch_count.insert(name, count);
             }
@@ -443,26 +528,27 @@ impl Preprocessor {
     }
 
     pub async fn run(&mut self, shader: &str) -> Option<SourceMap> {
+        self.try_run(shader).await.map_err(|e| e.submit()).ok()
+    }
+
+    /// Like `run`, but hands the error back instead of reporting it
+    pub async fn try_run(&mut self, shader: &str) -> Result<SourceMap, WGSLError> {
         // Process MiniJinja templates first if shader contains template syntax
         let processed_shader = if shader.contains("{%") || shader.contains("{{") {
-            match self.process_jinja_template(shader) {
-                Ok(rendered) => rendered,
-                Err(e) => {
-                    WGSLError::new(format!("Jinja template error: {}", e), 1).submit();
-                    return None;
-                }
-            }
+            self.process_jinja_template(shader)
+                .map_err(|e| WGSLError::new(format!("Jinja template error: {}", e), 1))?
         } else {
             shader.to_string()
         };
 
-        match self.preprocess(&processed_shader).await {
-            Ok(()) => Some(std::mem::take(&mut self.source)),
-            Err(e) => {
-                e.submit();
-                None
-            }
+        self.preprocess(&processed_shader).await?;
+        let mut source = std::mem::take(&mut self.source);
+        if processed_shader == shader {
+            source.original = processed_shader;
+        } else {
+            source.remap_through_template(shader, &processed_shader);
         }
+        Ok(source)
     }
 
     /// Process MiniJinja template with WGSL-specific context
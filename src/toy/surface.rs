@@ -0,0 +1,310 @@
+use super::context::WgpuContext;
+use crate::cli::Surface;
+use wgpu::util::DeviceExt;
+
+/// Samples per pixel of the surface pass
+const SAMPLE_COUNT: u32 = 4;
+/// Quads along each side of the surface grid
+const GRID_QUADS: u32 = 96;
+const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+/// Same format as `tex_screen`, so frames read back the same way
+const OUTPUT_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+
+#[derive(Clone, Copy, bytemuck::Zeroable, bytemuck::Pod)]
+#[repr(C)]
+struct SurfaceUniforms {
+    view_proj: [[f32; 4]; 4],
+    size: [f32; 2],
+    time: f32,
+    kind: u32,
+}
+
+/// Raster pass drawing the text texture on 3D geometry over the compute shader's screen
+///
+/// The screen is drawn first as a backdrop, then the surface with a depth buffer and
+/// 4x MSAA, resolved into `output`, which takes the place of the screen for readback
+/// and presentation.
+pub struct SurfaceRenderer {
+    surface: Surface,
+    text: wgpu::Texture,
+    uniforms: wgpu::Buffer,
+    vertices: wgpu::Buffer,
+    indices: wgpu::Buffer,
+    index_count: u32,
+    bind_group: wgpu::BindGroup,
+    backdrop_pipeline: wgpu::RenderPipeline,
+    surface_pipeline: wgpu::RenderPipeline,
+    msaa_view: wgpu::TextureView,
+    depth_view: wgpu::TextureView,
+    output: wgpu::Texture,
+    output_view: wgpu::TextureView,
+    size: [f32; 2],
+}
+
+impl SurfaceRenderer {
+    pub fn new(
+        wgpu: &WgpuContext,
+        surface: Surface,
+        text: wgpu::Texture,
+        backdrop: &wgpu::TextureView,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        let device = &wgpu.device;
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("surface"),
+            source: wgpu::ShaderSource::Wgsl(
+                format!("{}{}", include_str!("surface.wgsl"), include_str!("text.wgsl")).into(),
+            ),
+        });
+
+        let texture = |label, format, sample_count, usage| {
+            device.create_texture(&wgpu::TextureDescriptor {
+                label: Some(label),
+                size: wgpu::Extent3d {
+                    width,
+                    height,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count,
+                dimension: wgpu::TextureDimension::D2,
+                format,
+                usage,
+                view_formats: &[],
+            })
+        };
+        let msaa = texture("surface_msaa", OUTPUT_FORMAT, SAMPLE_COUNT, wgpu::TextureUsages::RENDER_ATTACHMENT);
+        let depth = texture("surface_depth", DEPTH_FORMAT, SAMPLE_COUNT, wgpu::TextureUsages::RENDER_ATTACHMENT);
+        let output = texture(
+            "surface_output",
+            OUTPUT_FORMAT,
+            1,
+            wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_SRC,
+        );
+
+        let uniforms = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("surface_uniforms"),
+            size: size_of::<SurfaceUniforms>() as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::UNIFORM,
+            mapped_at_creation: false,
+        });
+
+        // Grid of uv positions, bent into shape by the vertex shader
+        let vertices: Vec<[f32; 2]> = (0..=GRID_QUADS)
+            .flat_map(|y| (0..=GRID_QUADS).map(move |x| [x as f32 / GRID_QUADS as f32, y as f32 / GRID_QUADS as f32]))
+            .collect();
+        let indices: Vec<u32> = (0..GRID_QUADS)
+            .flat_map(|y| (0..GRID_QUADS).map(move |x| (x, y)))
+            .flat_map(|(x, y)| {
+                let i = y * (GRID_QUADS + 1) + x;
+                let below = i + GRID_QUADS + 1;
+                // Counterclockwise on screen when the text faces the viewer
+                [i, below, i + 1, i + 1, below, below + 1]
+            })
+            .collect();
+        let vertices = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("surface_vertices"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let index_count = indices.len() as u32;
+        let indices = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("surface_indices"),
+            contents: bytemuck::cast_slice(&indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        let float_texture = wgpu::BindingType::Texture {
+            multisampled: false,
+            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+            view_dimension: wgpu::TextureViewDimension::D2,
+        };
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("surface"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: float_texture,
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: float_texture,
+                    count: None,
+                },
+            ],
+        });
+        let text_view = text.create_view(&Default::default());
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("surface"),
+            layout: &layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: uniforms.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&text_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: wgpu::BindingResource::Sampler(&sampler),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: wgpu::BindingResource::TextureView(backdrop),
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("surface"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = |vertex_entry, fragment_entry, buffers: &[wgpu::VertexBufferLayout], depth_write, depth_compare| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(fragment_entry),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some(vertex_entry),
+                    buffers,
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some(fragment_entry),
+                    targets: &[Some(OUTPUT_FORMAT.into())],
+                    compilation_options: Default::default(),
+                }),
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: DEPTH_FORMAT,
+                    depth_write_enabled: depth_write,
+                    depth_compare,
+                    stencil: Default::default(),
+                    bias: Default::default(),
+                }),
+                multisample: wgpu::MultisampleState {
+                    count: SAMPLE_COUNT,
+                    ..Default::default()
+                },
+                multiview: None,
+                cache: None,
+            })
+        };
+        let backdrop_pipeline = pipeline("vs_backdrop", "fs_backdrop", &[], false, wgpu::CompareFunction::Always);
+        let surface_pipeline = pipeline(
+            "vs_surface",
+            "fs_surface",
+            &[wgpu::VertexBufferLayout {
+                array_stride: size_of::<[f32; 2]>() as u64,
+                step_mode: wgpu::VertexStepMode::Vertex,
+                attributes: &wgpu::vertex_attr_array![0 => Float32x2],
+            }],
+            true,
+            wgpu::CompareFunction::Less,
+        );
+
+        SurfaceRenderer {
+            surface,
+            text,
+            uniforms,
+            vertices,
+            indices,
+            index_count,
+            bind_group,
+            backdrop_pipeline,
+            surface_pipeline,
+            msaa_view: msaa.create_view(&Default::default()),
+            depth_view: depth.create_view(&Default::default()),
+            output_view: output.create_view(&Default::default()),
+            output,
+            size: [width as f32, height as f32],
+        }
+    }
+
+    pub fn surface(&self) -> Surface {
+        self.surface
+    }
+
+    /// The text texture, kept to rebuild the renderer when the screen is recreated
+    pub fn text(&self) -> &wgpu::Texture {
+        &self.text
+    }
+
+    /// The composited frame, in the screen's format
+    pub fn output(&self) -> &wgpu::Texture {
+        &self.output
+    }
+
+    pub fn output_view(&self) -> &wgpu::TextureView {
+        &self.output_view
+    }
+
+    /// Stage the camera and time, then record the backdrop and surface into `output`
+    pub fn encode(&self, queue: &wgpu::Queue, encoder: &mut wgpu::CommandEncoder, view_proj: [[f32; 4]; 4], time: f32) {
+        let uniforms = SurfaceUniforms {
+            view_proj,
+            size: self.size,
+            time,
+            kind: self.surface as u32,
+        };
+        queue.write_buffer(&self.uniforms, 0, bytemuck::bytes_of(&uniforms));
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("surface"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: &self.msaa_view,
+                resolve_target: Some(&self.output_view),
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    store: wgpu::StoreOp::Discard,
+                },
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &self.depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu::StoreOp::Discard,
+                }),
+                stencil_ops: None,
+            }),
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        pass.set_bind_group(0, &self.bind_group, &[]);
+        pass.set_pipeline(&self.backdrop_pipeline);
+        pass.draw(0..3, 0..1);
+        pass.set_pipeline(&self.surface_pipeline);
+        pass.set_vertex_buffer(0, self.vertices.slice(..));
+        pass.set_index_buffer(self.indices.slice(..), wgpu::IndexFormat::Uint32);
+        pass.draw_indexed(0..self.index_count, 0, 0..1);
+    }
+}
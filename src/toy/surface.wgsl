@@ -0,0 +1,112 @@
+// Code texture mapped onto a 3D surface over the compute shader's screen
+//
+// Vertices are uv positions on a grid; the vertex shader bends the grid into the selected
+// surface in text pixel coordinates (x right, y down, z toward the viewer), and the
+// camera's view-projection takes it to clip space.
+
+struct Surface {
+    view_proj: mat4x4<f32>,
+    size: vec2<f32>,
+    time: f32,
+    kind: u32,
+}
+
+@group(0) @binding(0) var<uniform> surface: Surface;
+@group(0) @binding(1) var text: texture_2d<f32>;
+@group(0) @binding(2) var text_sampler: sampler;
+@group(0) @binding(3) var backdrop: texture_2d<f32>;
+
+const PI = 3.14159265;
+const KIND_PLANE = 0u;
+const KIND_CYLINDER = 1u;
+const KIND_CURL = 2u;
+const KIND_WAVE = 3u;
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+    @location(1) world: vec3<f32>,
+};
+
+// Backdrop: a fullscreen triangle behind everything
+@vertex
+fn vs_backdrop(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let tc = vec2<f32>(f32(i32(vertex_index) / 2), f32(i32(vertex_index) & 1)) * 2.0;
+    return vec4<f32>(tc.x * 2.0 - 1.0, 1.0 - tc.y * 2.0, 1.0, 1.0);
+}
+
+@fragment
+fn fs_backdrop(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    return textureLoad(backdrop, vec2<i32>(position.xy), 0);
+}
+
+// Point of the surface at a grid uv, in text pixels
+fn bend(uv: vec2<f32>) -> vec3<f32> {
+    let size = surface.size;
+    let flat_pos = vec3<f32>(uv * size, 0.0);
+    switch surface.kind {
+        case KIND_PLANE: {
+            // Tilted back 30 degrees about the horizontal center line
+            let tilt = radians(30.0);
+            let y = (uv.y - 0.5) * size.y;
+            return vec3<f32>(flat_pos.x, size.y * 0.5 + y * cos(tilt), y * sin(tilt));
+        }
+        case KIND_CYLINDER: {
+            // Rolled around a horizontal axis like a scroll, nearest in the middle
+            let arc = 1.6;
+            let radius = size.y / arc;
+            let angle = (uv.y - 0.5) * arc;
+            return vec3<f32>(flat_pos.x, size.y * 0.5 + radius * sin(angle), radius * (cos(angle) - 1.0));
+        }
+        case KIND_CURL: {
+            // Bottom right corner curled up toward the viewer, folding along a 45 degree
+            // line that cuts the edges `cut` pixels from the corner
+            let cut = min(size.x, size.y) * 0.4;
+            let direction = vec2<f32>(1.0, 1.0) / sqrt(2.0);
+            let along = dot(flat_pos.xy, direction) - (size.x + size.y - cut) / sqrt(2.0);
+            if (along <= 0.0) {
+                return flat_pos;
+            }
+            let radius = cut * 0.25;
+            let angle = min(along / radius, PI);
+            let rolled = radius * sin(angle) + max(along - radius * PI, 0.0) * cos(angle);
+            let offset = direction * (rolled - along);
+            return vec3<f32>(flat_pos.xy + offset, radius * (1.0 - cos(angle)));
+        }
+        default: {
+            // Sheet rippling in both directions, drifting with time
+            let amplitude = min(size.x, size.y) * 0.05;
+            let z = sin(uv.x * 2.0 * PI * 1.5 + surface.time) * cos(uv.y * PI * 1.5 - surface.time * 0.7);
+            return vec3<f32>(flat_pos.xy, amplitude * z);
+        }
+    }
+}
+
+@vertex
+fn vs_surface(@location(0) uv: vec2<f32>) -> VertexOutput {
+    var out: VertexOutput;
+    out.world = bend(uv);
+    out.position = surface.view_proj * vec4<f32>(out.world, 1.0);
+    out.uv = uv;
+    return out;
+}
+
+@fragment
+fn fs_surface(in: VertexOutput, @builtin(front_facing) front: bool) -> @location(0) vec4<f32> {
+    // Shade by how squarely the surface faces the viewer
+    let normal = normalize(cross(dpdx(in.world), dpdy(in.world)));
+    let light = 0.55 + 0.45 * abs(normal.z);
+
+    // A translucent sheet makes the shape readable between lines of code
+    let behind = textureLoad(backdrop, vec2<i32>(in.position.xy), 0);
+    var col = mix(behind.rgb, vec3<f32>(0.02, 0.03, 0.05), 0.55) * light;
+
+    // `codeskewTextColor` comes from text.wgsl, shared with the prelude
+    let color = codeskewTextColor(textureSample(text, text_sampler, in.uv));
+    if (front) {
+        col = mix(col, color.rgb * light, color.a);
+    } else {
+        col *= 0.6;
+    }
+    return vec4<f32>(col, 1.0);
+}
@@ -0,0 +1,6 @@
+
+// Straight syntax color and coverage of a text texture texel. The texture is sRGB, so this
+// undoes the decode sampling applied, and its antialiased edges are premultiplied.
+fn codeskewTextColor(texel: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(pow(texel.rgb / max(texel.a, 0.001), vec3<f32>(1.0 / 2.2)), texel.a);
+}
@@ -136,7 +136,7 @@ mod winit {
 
         if let Some(source) = wgputoy.preprocess_async(&shader).await {
             println!("{}", source.source);
-            wgputoy.compile(source);
+            wgputoy.compile(source).await?;
         }
         Ok(wgputoy)
     }
@@ -218,8 +218,10 @@ mod winit {
     ) {
         if let Ok(shader) = std::fs::read_to_string(filename) {
             if let Some(source) = runtime.block_on(wgputoy.preprocess_async(&shader)) {
-                wgputoy.compile(source);
-                wgputoy.wgpu.window.request_redraw();
+                match runtime.block_on(wgputoy.compile(source)) {
+                    Ok(()) => wgputoy.wgpu.window.request_redraw(),
+                    Err(e) => eprintln!("{e}"),
+                }
             }
         }
     }
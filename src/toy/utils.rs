@@ -1,4 +1,5 @@
 use super::pp::WGSLError;
+use crate::shader_library::ShaderLibrary;
 
 #[cfg(target_arch = "wasm32")]
 use {cached::proc_macro::cached, std::future::Future, wasm_bindgen::prelude::*};
@@ -26,9 +27,14 @@ pub fn parse_u32(value: &str, line: usize) -> Result<u32, WGSLError> {
     )))
 }
 
+#[cfg(target_arch = "wasm32")]
+pub async fn fetch_include(_library: &ShaderLibrary, name: String) -> Option<String> {
+    fetch_remote_include(name).await
+}
+
 #[cfg(target_arch = "wasm32")]
 #[cached]
-pub async fn fetch_include(name: String) -> Option<String> {
+async fn fetch_remote_include(name: String) -> Option<String> {
     let url = format!("https://compute-toys.github.io/include/{name}.wgsl");
 
     #[cfg(target_arch = "wasm32")]
@@ -44,16 +50,8 @@ pub async fn fetch_include(name: String) -> Option<String> {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-pub async fn fetch_include(name: String) -> Option<String> {
-    // Try local include directory first
-    let local_path = format!("src/toy/include/{name}.wgsl");
-    if let Ok(content) = std::fs::read_to_string(&local_path) {
-        return Some(content);
-    }
-    
-    // Fall back to legacy path
-    let fallback_path = format!("./include/{name}.wgsl");
-    std::fs::read_to_string(fallback_path).ok()
+pub async fn fetch_include(library: &ShaderLibrary, name: String) -> Option<String> {
+    library.read_include(&name).map(|file| file.contents.into_owned())
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -1,6 +1,250 @@
+use crate::config::Config;
 use crate::error::CodeSkewError;
+use crate::toy::Camera;
 use anyhow::Result;
 use image::{Rgba, RgbaImage};
+use nalgebra::{Isometry3, Matrix3, Matrix4, Perspective3, Point2, Point3, Rotation3, Vector2, Vector3};
+
+/// Pinhole camera looking at the text plane, the output image laid at z = 0
+///
+/// The plane turns by yaw, pitch and roll about the look-at point, which the camera sees
+/// from `distance` pixels away. The default field of view shows the plane pixel for pixel,
+/// so a camera without rotation leaves the text where the layout put it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewCamera {
+    pub width: u32,
+    pub height: u32,
+    /// Radians about the vertical axis, positive turns the right edge away
+    pub yaw: f32,
+    /// Radians about the horizontal axis, positive tips the top toward the viewer
+    pub pitch: f32,
+    /// Radians about the view axis, counterclockwise
+    pub roll: f32,
+    /// Vertical field of view in radians
+    pub fov: f32,
+    /// Pixels from the eye to the look-at point
+    pub distance: f32,
+    /// Point of the text plane in the middle of the view, in pixels
+    pub look_at: Point2<f32>,
+}
+
+impl ViewCamera {
+    /// Camera looking straight at the middle of a `width` x `height` text plane
+    pub fn new(width: u32, height: u32, distance: f32) -> Self {
+        Self {
+            width,
+            height,
+            yaw: 0.0,
+            pitch: 0.0,
+            roll: 0.0,
+            fov: 2.0 * (height as f32 / 2.0 / distance).atan(),
+            distance,
+            look_at: Point2::new(width as f32 / 2.0, height as f32 / 2.0),
+        }
+    }
+
+    /// Camera from `--yaw`, `--pitch`, `--roll`, `--fov`, `--look-at` and `--perspective` as the distance
+    pub fn from_config(config: &Config) -> Self {
+        let mut camera = Self::new(config.width, config.height, config.perspective);
+        camera.yaw = config.yaw.to_radians();
+        camera.pitch = config.pitch.to_radians();
+        camera.roll = config.roll.to_radians();
+        camera.look_at = Point2::new(
+            config.look_at.0 * config.width as f32,
+            config.look_at.1 * config.height as f32,
+        );
+        if let Some(fov) = config.fov {
+            camera.fov = fov.to_radians();
+        }
+        camera
+    }
+
+    /// Text plane pixel coordinates, y down, to clip space
+    pub fn view_projection(&self) -> Matrix4<f32> {
+        #[rustfmt::skip]
+        let plane = Matrix4::new(
+            1.0, 0.0, 0.0, -self.look_at.x,
+            0.0, -1.0, 0.0, self.look_at.y,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        );
+        let turn = Rotation3::from_axis_angle(&Vector3::y_axis(), self.yaw)
+            * Rotation3::from_axis_angle(&Vector3::x_axis(), self.pitch)
+            * Rotation3::from_axis_angle(&Vector3::z_axis(), self.roll);
+        let view = Isometry3::look_at_rh(
+            &Point3::new(0.0, 0.0, self.distance),
+            &Point3::origin(),
+            &Vector3::y(),
+        );
+        let aspect = self.width as f32 / self.height as f32;
+        let projection = Perspective3::new(aspect, self.fov, self.distance * 0.01, self.distance * 100.0);
+
+        projection.to_homogeneous() * view.to_homogeneous() * turn.to_homogeneous() * plane
+    }
+
+    /// Text plane pixels to output pixels, as a homography
+    ///
+    /// The third coordinate of a projected point is positive in front of the camera.
+    pub fn homography(&self) -> Matrix3<f32> {
+        const ROWS: [usize; 3] = [0, 1, 3];
+        let clip = self.view_projection();
+        let clip = Matrix3::from_fn(|row, column| clip[(ROWS[row], ROWS[column])]);
+        let (width, height) = (self.width as f32, self.height as f32);
+        #[rustfmt::skip]
+        let ndc_to_pixels = Matrix3::new(
+            width / 2.0, 0.0, width / 2.0,
+            0.0, -height / 2.0, height / 2.0,
+            0.0, 0.0, 1.0,
+        );
+        normalize(ndc_to_pixels * clip)
+    }
+
+    /// Where a text plane pixel lands in the output, `None` behind the camera
+    pub fn project(&self, x: f32, y: f32) -> Option<Point2<f32>> {
+        let p = self.homography() * Vector3::new(x, y, 1.0);
+        (p.z > 0.0).then(|| Point2::new(p.x / p.z, p.y / p.z))
+    }
+
+    /// Output uv to text plane uv, both 0..1, for shaders sampling the text texture
+    pub fn text_from_screen_uv(&self) -> Matrix3<f32> {
+        let size = Matrix3::new_nonuniform_scaling(&Vector2::new(self.width as f32, self.height as f32));
+        let uv_to_uv = size.try_inverse().unwrap_or_else(Matrix3::identity) * self.homography() * size;
+        normalize(uv_to_uv.try_inverse().unwrap_or_else(Matrix3::identity))
+    }
+}
+
+/// The warped text sheet shaders draw with `codeskewTextUv`, ported from `toy/camera.wgsl`
+///
+/// The sheet leans back by `--skew` about the fold row, is magnified by the `--scale` and
+/// `--fold` warp and sheared by `--skew-angle`, all seen through the [`ViewCamera`]. SVG lines
+/// are placed with it so vector text lands where raster text does.
+#[derive(Clone, Copy, Debug)]
+pub struct TextSheet {
+    camera: Camera,
+    width: u32,
+    height: u32,
+    text_from_screen: Matrix3<f32>,
+}
+
+impl TextSheet {
+    /// Sheet for a `width` x `height` output, as shaders see it through `camera`
+    pub fn new(camera: Camera, width: u32, height: u32) -> Self {
+        let m = camera.text_from_screen;
+        Self {
+            camera,
+            width,
+            height,
+            text_from_screen: Matrix3::from_fn(|row, column| m[column][row]),
+        }
+    }
+
+    /// The sheet shaders draw with the camera and warp flags of `config`
+    pub fn from_config(config: &Config) -> Self {
+        Self::new(config.camera(), config.width, config.height)
+    }
+
+    /// Sheet uv and distance relative to the center of the screen, as `codeskewSheetUv`
+    ///
+    /// `None` behind the camera or beyond the horizon.
+    pub fn sheet_uv(&self, uv: Point2<f32>) -> Option<(Point2<f32>, f32)> {
+        let c = &self.camera;
+        let p = self.text_from_screen * Vector3::new(uv.x, uv.y, 1.0);
+        let row = (p.y / p.z - c.fold_point) * c.height;
+        let lean = c.perspective * c.skew.cos() + row * c.skew.sin();
+        if p.z <= 0.0 || lean <= 0.0 {
+            return None;
+        }
+        let center = self.text_from_screen * Vector3::new(0.5, 0.5, 1.0);
+        let depth = c.perspective * c.skew.cos() / lean;
+        let sheet = Point2::new(
+            (p.x / p.z - 0.5) * depth + 0.5,
+            c.fold_point + row * c.perspective / lean / c.height,
+        );
+        Some((sheet, center.z / p.z * depth))
+    }
+
+    /// Horizontal and vertical magnification of the warp at a sheet uv, as `codeskewWarpScale`
+    pub fn warp_scale(&self, sheet: Point2<f32>) -> Vector2<f32> {
+        let c = &self.camera;
+        let vertical = if sheet.y < c.fold_point {
+            1.0 + c.fold * sheet.y / c.fold_point
+        } else {
+            1.0 + c.fold * (1.0 - (sheet.y - c.fold_point) / (1.0 - c.fold_point))
+        };
+        Vector2::new(1.0 + sheet.x * c.scale, vertical)
+    }
+
+    /// Text uv drawn at a screen uv, as `codeskewTextUv`; `None` off the sheet
+    pub fn text_uv(&self, uv: Point2<f32>) -> Option<Point2<f32>> {
+        let (sheet, distance) = self.sheet_uv(uv)?;
+        if distance <= 0.0 {
+            return None;
+        }
+        let warp = self.warp_scale(sheet);
+        let mut text = Point2::new(
+            (sheet.x - 0.5) / warp.x + 0.5,
+            (sheet.y - 0.5) / warp.y + 0.5,
+        );
+        text.x -= (1.0 - text.y) * self.camera.skew_angle;
+        Some(text)
+    }
+
+    /// Screen uv where a text uv is drawn, the inverse of [`Self::text_uv`]
+    pub fn screen_uv(&self, text: Point2<f32>) -> Option<Point2<f32>> {
+        let c = &self.camera;
+
+        // Undo the shear, then the warp, which is linear in the sheet uv on each side of the fold
+        let x = text.x + (1.0 - text.y) * c.skew_angle - 0.5;
+        let y = text.y - 0.5;
+        let sheet_x = (0.5 + x) / (1.0 - x * c.scale);
+        let below = (0.5 + y) / (1.0 - y * c.fold / c.fold_point);
+        let sheet_y = if below < c.fold_point {
+            below
+        } else {
+            let k = y * c.fold / (1.0 - c.fold_point);
+            (0.5 + y + k) / (1.0 + k)
+        };
+
+        // Undo the lean: the row of the flat text plane the sheet row is seen at
+        let depth = 1.0 - (sheet_y - c.fold_point) * c.height / c.perspective * c.skew.sin();
+        if depth <= 0.0 {
+            return None;
+        }
+        let row = (sheet_y - c.fold_point) * c.height * c.skew.cos() / depth;
+        let plane = Vector3::new(
+            (sheet_x - 0.5) / depth + 0.5,
+            c.fold_point + row / c.height,
+            1.0,
+        );
+
+        let screen = self.text_from_screen.try_inverse()? * plane;
+        (screen.z > 0.0).then(|| Point2::new(screen.x / screen.z, screen.y / screen.z))
+    }
+
+    /// Affine approximation of text pixels to output pixels around a text pixel
+    ///
+    /// SVG transforms are affine, so each line of text is drawn through the tangent of the
+    /// sheet at its anchor. `None` where the sheet is not visible.
+    pub fn linearize(&self, x: f32, y: f32) -> Option<Matrix3<f32>> {
+        let size = Vector2::new(self.width as f32, self.height as f32);
+        let project = |x: f32, y: f32| {
+            self.screen_uv(Point2::new(x / size.x, y / size.y))
+                .map(|uv| uv.coords.component_mul(&size))
+        };
+        // Central differences over a pixel
+        let anchor = project(x, y)?;
+        let dx = (project(x + 0.5, y)? - project(x - 0.5, y)?).push(0.0);
+        let dy = (project(x, y + 0.5)? - project(x, y - 0.5)?).push(0.0);
+        let offset = anchor - dx.xy() * x - dy.xy() * y;
+        Some(Matrix3::from_columns(&[dx, dy, offset.push(1.0)]))
+    }
+}
+
+/// Scale a homography to a bottom-right entry of ±1, keeping the sign that tells front from back
+fn normalize(homography: Matrix3<f32>) -> Matrix3<f32> {
+    let scale = homography[(2, 2)].abs();
+    if scale > f32::EPSILON { homography / scale } else { homography }
+}
 
 /// Handles perspective transformation for text rendering
 pub struct PerspectiveTransformer {
@@ -41,9 +285,10 @@ impl PerspectiveTransformer {
     }
     
     /// Get the transformation matrix for the specified parameters
+    ///
+    /// Rows are `[a, b, 0]`, `[c, d, 0]`, `[e, f, 1]`, matching SVG's `matrix(a b c d e f)`.
     pub fn get_transform_matrix(&self, scale: f32, x_offset: f32, y_position: f32) -> [[f32; 3]; 3] {
-        // Calculate the center of the image
-        let center_x = self.width as f32 / 2.0;
+        // Calculate the vertical center of the image
         let center_y = self.height as f32 / 2.0;
         
         // Determine if we're in Telegram circular format
@@ -136,8 +381,9 @@ impl PerspectiveTransformer {
         // the layout engine’s centring so that `x_offset` is correct already.
         let x_pos = x_offset;
         
-        // Create the transformation matrix
-        // This combines scaling, rotation, skew, and translation
+        // Create the transformation matrix (row-vector convention: [x y 1] * M)
+        // This applies a horizontal skew, then rotation, then scaling and translation
+        let skew_tan = skew_sin / skew_cos;
         [
             [
                 final_scale * rot_cos,
@@ -145,8 +391,8 @@ impl PerspectiveTransformer {
                 0.0,
             ],
             [
-                final_scale * -rot_sin + skew_sin * final_scale,
-                final_scale * rot_cos + skew_cos * final_scale,
+                final_scale * (skew_tan * rot_cos - rot_sin),
+                final_scale * (skew_tan * rot_sin + rot_cos),
                 0.0,
             ],
             [
@@ -280,8 +526,8 @@ impl PerspectiveTransformer {
         let matrix = self.get_transform_matrix(scale, x, y);
         
         // Apply the transformation
-        let tx = matrix[0][0] * x + matrix[0][1] * y + matrix[2][0];
-        let ty = matrix[1][0] * x + matrix[1][1] * y + matrix[2][1];
+        let tx = matrix[0][0] * x + matrix[1][0] * y + matrix[2][0];
+        let ty = matrix[0][1] * x + matrix[1][1] * y + matrix[2][1];
         
         (tx, ty)
     }
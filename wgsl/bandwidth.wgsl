@@ -24,11 +24,12 @@ fn main(@builtin(global_invocation_id) id: uint3) {
     let uv = float2(id.xy) / resolution;
     let t = float(time.frame) / 60.;
     
-    // Dark modern background
+    // Dark modern background, or the --gradient
+    let gradient = textureSampleLevel(codeskew_background, bilinear, uv, 0.);
     let bg_grad = mix(
-        float3(0.02, 0.02, 0.03),
-        float3(0.0, 0.01, 0.02),
-        uv.y
+        mix(float3(0.02, 0.02, 0.03), float3(0.0, 0.01, 0.02), uv.y),
+        gradient.rgb,
+        gradient.a
     );
     var col = float4(bg_grad, {% if background_alpha is defined %}{{ background_alpha }}{% else %}1.0{% endif %});
     
@@ -2,48 +2,22 @@
 // Combines animated background effects with SDF text rendering
 // Based on wgpu-compute-toy terminal-overlay example
 
-#define TERMINAL_ROWS 30
-#define TERMINAL_COLS 80
 
 // Advanced 3D curved text rendering with Glyphon integration
 fn terminal_render(pos: uint2) -> float4 {
     let screen_size = uint2(textureDimensions(screen));
 
-    // Normalized screen coordinates
-    var uv = float2(pos) / float2(screen_size);
+    let uv = float2(pos) / float2(screen_size);
 
     // SMOOTH SLOW SCROLLING - text moves down very slowly
     let scroll_speed = 0.01; // Much slower scroll
     let scroll_offset = fract(time.elapsed * scroll_speed);
 
-    // 3D PERSPECTIVE TRANSFORMATION
-    // LEFT LARGER THAN RIGHT (closer to viewer on left side)
-    let perspective_strength = 0.6;
-    let horizontal_depth = 1.0 + uv.x * perspective_strength; // Left side (x=0) larger, right side (x=1) smaller
-
-    // ANGLED SKEW (text leans away from viewer like looking at screen from side)
-    let skew_angle = 0.15; // Perfect subtle lean
-
-    // 3D DIMENSIONALITY - largest at 2/3 down, folding smaller at top/bottom
-    let fold_point = 0.67; // 2/3 down the screen
-    let fold_strength = 0.4; // Subtle dimensionality
-
-    var vertical_scale = 1.0;
-    if (uv.y < fold_point) {
-        // From top to 2/3: gradually larger
-        let progress = uv.y / fold_point;
-        vertical_scale = 1.0 + fold_strength * progress;
-    } else {
-        // From 2/3 to bottom: gradually smaller
-        let progress = (uv.y - fold_point) / (1.0 - fold_point);
-        vertical_scale = 1.0 + fold_strength * (1.0 - progress);
-    }
-
-    // Apply transformations
-    var transformed_uv = uv;
-    transformed_uv.x = (transformed_uv.x - 0.5) / horizontal_depth + 0.5; // Left larger than right perspective
-    transformed_uv.y = (transformed_uv.y - 0.5) / vertical_scale + 0.5; // 3D fold effect
-    transformed_uv.x -= (1.0 - transformed_uv.y) * skew_angle; // Angled skew - top leans toward viewer (correct direction)
+    // 3D PERSPECTIVE TRANSFORMATION from the perspective flags, see `struct CodeskewCamera`:
+    // seen through the camera (--yaw, --pitch, --roll, --fov), leaning back (--skew,
+    // --perspective), LEFT LARGER THAN RIGHT (--scale), largest at 2/3 down (--fold,
+    // --fold-point) and angled (--skew-angle)
+    var transformed_uv = codeskewTextUv(uv);
     transformed_uv.y += scroll_offset; // Smooth scrolling
 
     // Sample the Glyphon-rendered text texture directly
@@ -53,13 +27,11 @@ fn terminal_render(pos: uint2) -> float4 {
         let text_sample = textureSampleLevel(channel1, trilinear, transformed_uv, 0.);
 
         if (text_sample.a > 0.1) { // Text is present
-            var col = float4(0);
-
-            // Create crisp white text from Glyphon
-            col = float4(1.0, 1.0, 1.0, text_sample.a);
+            // Syntax colors from Glyphon
+            var col = codeskewTextColor(text_sample);
 
-            // Dynamic color based on 3D position and depth
-            let depth_brightness = horizontal_depth * vertical_scale;
+            // Dynamic color based on 3D position and depth, the same depth --blur focuses with
+            let depth_brightness = 1.0 / codeskewCameraDepth(uv);
             let position_wave = sin(time.elapsed * 0.3 + transformed_uv.x * 20.0 + transformed_uv.y * 15.0);
             let color_shimmer = mix(0.85, 1.15, position_wave);
 
@@ -68,8 +40,8 @@ fn terminal_render(pos: uint2) -> float4 {
             col.g *= depth_brightness * color_shimmer * 0.95;
             col.b *= depth_brightness * color_shimmer * 1.1; // Slight blue enhancement
 
-            // Depth-based transparency for 3D effect
-            col.a *= mix(0.75, 1.0, depth_brightness);
+            // Depth-based transparency for 3D effect, scaled by --depth
+            col.a *= mix(1.0 - codeskew_camera.depth * 0.5, 1.0, depth_brightness);
 
             return col;
         }
@@ -115,8 +87,10 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
 
     if (id.x >= screen_size.x || id.y >= screen_size.y) { return; }
 
-    // Render animated background
+    // Render animated background, glowing over the --gradient with a screen blend
     var final_color = background_effect(id.xy);
+    let gradient = textureSampleLevel(codeskew_background, bilinear, (float2(id.xy) + 0.5) / float2(screen_size), 0.);
+    final_color = float4(mix(final_color.rgb, 1.0 - (1.0 - final_color.rgb) * (1.0 - gradient.rgb), gradient.a), 1.0);
 
     // Overlay text rendering
     let text_color = terminal_render(id.xy);
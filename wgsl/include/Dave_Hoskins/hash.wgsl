@@ -0,0 +1,92 @@
+// Hash without Sine, by Dave Hoskins
+// https://www.shadertoy.com/view/4djSRW (MIT License)
+//
+// hashNM takes M floats and returns N pseudo-random values in 0..1
+
+const HASH_SCALE3 = vec3<f32>(.1031, .1030, .0973);
+const HASH_SCALE4 = vec4<f32>(.1031, .1030, .0973, .1099);
+
+fn hash11(p: f32) -> f32 {
+    var x = fract(p * .1031);
+    x *= x + 33.33;
+    x *= x + x;
+    return fract(x);
+}
+
+fn hash12(p: vec2<f32>) -> f32 {
+    var p3 = fract(p.xyx * .1031);
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.x + p3.y) * p3.z);
+}
+
+fn hash13(p: vec3<f32>) -> f32 {
+    var p3 = fract(p * .1031);
+    p3 += dot(p3, p3.zyx + 31.32);
+    return fract((p3.x + p3.y) * p3.z);
+}
+
+fn hash14(p: vec4<f32>) -> f32 {
+    var p4 = fract(p * HASH_SCALE4);
+    p4 += dot(p4, p4.wzxy + 33.33);
+    return fract((p4.x + p4.y) * (p4.z + p4.w));
+}
+
+fn hash21(p: f32) -> vec2<f32> {
+    var p3 = fract(vec3<f32>(p) * HASH_SCALE3);
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.xx + p3.yz) * p3.zy);
+}
+
+fn hash22(p: vec2<f32>) -> vec2<f32> {
+    var p3 = fract(p.xyx * HASH_SCALE3);
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.xx + p3.yz) * p3.zy);
+}
+
+fn hash23(p: vec3<f32>) -> vec2<f32> {
+    var p3 = fract(p * HASH_SCALE3);
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.xx + p3.yz) * p3.zy);
+}
+
+fn hash31(p: f32) -> vec3<f32> {
+    var p3 = fract(vec3<f32>(p) * HASH_SCALE3);
+    p3 += dot(p3, p3.yzx + 33.33);
+    return fract((p3.xxy + p3.yzz) * p3.zyx);
+}
+
+fn hash32(p: vec2<f32>) -> vec3<f32> {
+    var p3 = fract(p.xyx * HASH_SCALE3);
+    p3 += dot(p3, p3.yxz + 33.33);
+    return fract((p3.xxy + p3.yzz) * p3.zyx);
+}
+
+fn hash33(p: vec3<f32>) -> vec3<f32> {
+    var p3 = fract(p * HASH_SCALE3);
+    p3 += dot(p3, p3.yxz + 33.33);
+    return fract((p3.xxy + p3.yxx) * p3.zyx);
+}
+
+fn hash41(p: f32) -> vec4<f32> {
+    var p4 = fract(vec4<f32>(p) * HASH_SCALE4);
+    p4 += dot(p4, p4.wzxy + 33.33);
+    return fract((p4.xxyz + p4.yzzw) * p4.zywx);
+}
+
+fn hash42(p: vec2<f32>) -> vec4<f32> {
+    var p4 = fract(p.xyxy * HASH_SCALE4);
+    p4 += dot(p4, p4.wzxy + 33.33);
+    return fract((p4.xxyz + p4.yzzw) * p4.zywx);
+}
+
+fn hash43(p: vec3<f32>) -> vec4<f32> {
+    var p4 = fract(p.xyzx * HASH_SCALE4);
+    p4 += dot(p4, p4.wzxy + 33.33);
+    return fract((p4.xxyz + p4.yzzw) * p4.zywx);
+}
+
+fn hash44(p: vec4<f32>) -> vec4<f32> {
+    var p4 = fract(p * HASH_SCALE4);
+    p4 += dot(p4, p4.wzxy + 33.33);
+    return fract((p4.xxyz + p4.yzzw) * p4.zywx);
+}
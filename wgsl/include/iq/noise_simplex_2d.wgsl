@@ -0,0 +1,23 @@
+// Simplex noise in 2D, by Inigo Quilez
+// https://www.shadertoy.com/view/Msf3WH (MIT License)
+//
+// #define noise_simplex_2d_hash as a vec2 -> vec2 hash in 0..1 before including, e.g.
+// hash22 from "Dave_Hoskins/hash". Returns -1..1.
+
+fn noise_simplex_2d(p: vec2<f32>) -> f32 {
+    let K1 = 0.366025404; // (sqrt(3)-1)/2
+    let K2 = 0.211324865; // (3-sqrt(3))/6
+    let i = floor(p + (p.x + p.y) * K1);
+    let a = p - i + (i.x + i.y) * K2;
+    let m = step(a.y, a.x);
+    let o = vec2<f32>(m, 1.0 - m);
+    let b = a - o + K2;
+    let c = a - 1.0 + 2.0 * K2;
+    let h = max(0.5 - vec3<f32>(dot(a, a), dot(b, b), dot(c, c)), vec3<f32>(0.0));
+    let n = h * h * h * h * vec3<f32>(
+        dot(a, -1.0 + 2.0 * noise_simplex_2d_hash(i)),
+        dot(b, -1.0 + 2.0 * noise_simplex_2d_hash(i + o)),
+        dot(c, -1.0 + 2.0 * noise_simplex_2d_hash(i + 1.0)),
+    );
+    return dot(n, vec3<f32>(70.0));
+}
@@ -0,0 +1,31 @@
+// Simplex noise in 3D, by Nikita Miropolskiy
+// https://www.shadertoy.com/view/XsX3zB (MIT License)
+//
+// #define noise_simplex_3d_hash as a vec3 -> vec3 hash in 0..1 before including, e.g.
+// hash33 from "Dave_Hoskins/hash". Returns roughly -1..1.
+
+fn noise_simplex_3d(p: vec3<f32>) -> f32 {
+    let F3 = 0.3333333;
+    let G3 = 0.1666667;
+    // Skew into the simplex grid and find the cell's corners
+    let s = floor(p + dot(p, vec3<f32>(F3)));
+    let x = p - s + dot(s, vec3<f32>(G3));
+    let e = step(vec3<f32>(0.0), x - x.yzx);
+    let i1 = e * (1.0 - e.zxy);
+    let i2 = 1.0 - e.zxy * (1.0 - e);
+    let x1 = x - i1 + G3;
+    let x2 = x - i2 + 2.0 * G3;
+    let x3 = x - 1.0 + 3.0 * G3;
+    var w = vec4<f32>(dot(x, x), dot(x1, x1), dot(x2, x2), dot(x3, x3));
+    w = max(0.6 - w, vec4<f32>(0.0));
+    var d = vec4<f32>(
+        dot(noise_simplex_3d_hash(s) - 0.5, x),
+        dot(noise_simplex_3d_hash(s + i1) - 0.5, x1),
+        dot(noise_simplex_3d_hash(s + i2) - 0.5, x2),
+        dot(noise_simplex_3d_hash(s + 1.0) - 0.5, x3),
+    );
+    w *= w;
+    w *= w;
+    d *= w;
+    return dot(d, vec4<f32>(52.0));
+}